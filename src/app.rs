@@ -1,12 +1,22 @@
+use std::sync::Arc;
+
 use iced::{
     alignment::Horizontal,
-    widget::{button, column, container},
+    widget::{button, column, container, text, Text},
     Border, Color, Element, Length, Task, Theme,
 };
 
-use crate::frontend::{
-    new_character_page::{self, NewCharacterPage},
-    race::{RaceName, Subrace},
+use crate::{
+    backend::{
+        character::Character,
+        content::{ContentError, ContentRegistry},
+        storage,
+    },
+    frontend::{
+        level_up_page::{self, LevelUpPage},
+        load_character_page::{self, LoadCharacterPage},
+        new_character_page::{self, NewCharacterPage},
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -14,31 +24,35 @@ pub enum Message {
     MainMenuButtonPressed,
     LoadCharacterButtonPressed,
     NewCharacterButtonPressed(new_character_page::Message),
+    LoadCharacterPage(load_character_page::Message),
+    LevelUpPage(level_up_page::Message),
 }
 
 #[derive(Default, Debug)]
 enum Page {
     #[default]
     Main,
-    LoadCharacter,
-    NewCharacter(NewCharacterPage),
+    LoadCharacter(LoadCharacterPage),
+    NewCharacter(Box<NewCharacterPage>),
+    LevelUp(Box<LevelUpPage>),
 }
 
 impl Page {
-    fn view(&self) -> Element<Message> {
-        let main_menu_btn = container(button("Main Menu").on_press(Message::MainMenuButtonPressed))
-            .padding(20)
-            .align_x(Horizontal::Center)
-            .width(Length::Fill)
-            .style(|_| container::Style {
-                border: Border {
-                    color: Color::from_rgb8(0, 0, 0),
-                    width: 2.0,
-                    ..Border::default()
-                },
-                ..Default::default()
-            })
-            .into();
+    fn view(&self) -> Element<'_, Message> {
+        let main_menu_btn: Element<Message> =
+            container(button("Main Menu").on_press(Message::MainMenuButtonPressed))
+                .padding(20)
+                .align_x(Horizontal::Center)
+                .width(Length::Fill)
+                .style(|_| container::Style {
+                    border: Border {
+                        color: Color::from_rgb8(0, 0, 0),
+                        width: 2.0,
+                        ..Border::default()
+                    },
+                    ..Default::default()
+                })
+                .into();
 
         match self {
             Page::Main => container(
@@ -54,7 +68,12 @@ impl Page {
             .center(Length::Fill)
             .into(),
 
-            Page::LoadCharacter => main_menu_btn,
+            Page::LoadCharacter(page) => container(column![
+                page.view().map(Message::LoadCharacterPage),
+                main_menu_btn,
+            ])
+            .padding(0.5)
+            .into(),
 
             Page::NewCharacter(page) => container(column![
                 page.view().map(Message::NewCharacterButtonPressed),
@@ -62,11 +81,18 @@ impl Page {
             ])
             .padding(0.5)
             .into(),
+
+            Page::LevelUp(page) => container(column![
+                page.view().map(Message::LevelUpPage),
+                main_menu_btn,
+            ])
+            .padding(0.5)
+            .into(),
         }
     }
 
     /// Creates a button in the main page.
-    fn main_opts_button(name: &str, on_press: Message) -> Element<Message> {
+    fn main_opts_button(name: &str, on_press: Message) -> Element<'_, Message> {
         container(
             container(button(name).padding(10).on_press(on_press.clone())).center_x(Length::Fill),
         )
@@ -75,15 +101,6 @@ impl Page {
     }
 }
 
-#[derive(Default, Debug)]
-struct RaceProps {
-    /// The selected race for the character.
-    selected_race: Option<RaceName>,
-
-    /// The selected subrace, if one exists.
-    selected_subrace: Option<Subrace>,
-}
-
 #[allow(unused)]
 #[derive(Default, Debug)]
 pub struct App {
@@ -93,18 +110,33 @@ pub struct App {
     /// The current page being displayed.
     page: Page,
 
-    race_props: RaceProps,
+    /// The selections for the character being created, kept while away from the
+    /// `New Character` page.
+    draft: Character,
+
+    /// The id of the save the character being created was loaded from or saved to.
+    ///
+    /// Once the character has been saved, `New Character` on the main page starts a new one
+    /// instead of resuming it, so that saving doesn't overwrite it.
+    save_id: Option<String>,
+
+    /// The content characters are built from.
+    content: Arc<ContentRegistry>,
+
+    /// Errors from content files that failed to load.
+    content_errors: Vec<ContentError>,
 }
 
 impl App {
     pub fn new() -> Self {
+        let (content, content_errors) = ContentRegistry::load();
         Self {
             theme: Theme::default(),
             page: Page::default(),
-            race_props: RaceProps {
-                selected_race: None,
-                selected_subrace: None,
-            },
+            draft: Character::default(),
+            save_id: None,
+            content: Arc::new(content),
+            content_errors,
         }
     }
 
@@ -115,29 +147,97 @@ impl App {
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::MainMenuButtonPressed => {
+                if let Page::NewCharacter(page) = &self.page {
+                    self.draft = page.draft().clone();
+                }
                 self.page = Page::Main;
                 Task::none()
             }
             Message::LoadCharacterButtonPressed => {
-                self.page = Page::LoadCharacter;
+                self.page = Page::LoadCharacter(LoadCharacterPage::new(self.content.clone()));
                 Task::none()
             }
+            Message::LoadCharacterPage(msg) => {
+                let Page::LoadCharacter(page) = &mut self.page else {
+                    return Task::none();
+                };
+                match page.update(msg) {
+                    load_character_page::Command::None => Task::none(),
+                    load_character_page::Command::Open(character) => {
+                        self.draft = character.draft;
+                        self.save_id = Some(character.id);
+                        self.page = Page::NewCharacter(Box::new(NewCharacterPage::new(
+                            self.draft.clone(),
+                            self.content.clone(),
+                        )));
+                        Task::none()
+                    }
+                    load_character_page::Command::Deleted(id) => {
+                        // Saving the character again would bring the deleted file back
+                        if self.save_id.as_ref() == Some(&id) {
+                            self.save_id = None;
+                        }
+                        Task::none()
+                    }
+                    load_character_page::Command::LevelUp(id, level_up) => {
+                        self.page = Page::LevelUp(Box::new(LevelUpPage::new(
+                            id,
+                            *level_up,
+                            self.content.clone(),
+                        )));
+                        Task::none()
+                    }
+                }
+            }
+            Message::LevelUpPage(msg) => {
+                let Page::LevelUp(page) = &mut self.page else {
+                    return Task::none();
+                };
+                match page.update(msg) {
+                    level_up_page::Command::None => Task::none(),
+                    level_up_page::Command::Cancel => {
+                        self.page =
+                            Page::LoadCharacter(LoadCharacterPage::new(self.content.clone()));
+                        Task::none()
+                    }
+                    level_up_page::Command::Finish(id, draft) => {
+                        let saved = storage::save(Some(&id), &draft);
+                        if self.save_id.as_ref() == Some(&id) {
+                            self.draft = *draft;
+                        }
+                        let mut load_page = LoadCharacterPage::new(self.content.clone());
+                        if let Err(err) = saved {
+                            load_page.push_error(err.to_string());
+                        }
+                        self.page = Page::LoadCharacter(load_page);
+                        Task::none()
+                    }
+                }
+            }
             Message::NewCharacterButtonPressed(msg) => {
-                self.page = Page::NewCharacter(NewCharacterPage::new(
-                    self.race_props.selected_race.clone(),
-                    self.race_props.selected_subrace.clone(),
-                ));
+                if !matches!(self.page, Page::NewCharacter(_)) {
+                    if self.save_id.take().is_some() {
+                        self.draft = Character::default();
+                    }
+                    self.page = Page::NewCharacter(Box::new(NewCharacterPage::new(
+                        self.draft.clone(),
+                        self.content.clone(),
+                    )));
+                }
                 match &mut self.page {
                     Page::NewCharacter(new_character_page) => {
                         let command = new_character_page.update(msg);
                         match command {
                             new_character_page::Command::None => Task::none(),
-                            new_character_page::Command::RaceSelected(race_name) => {
-                                self.race_props.selected_race = Some(race_name);
-                                Task::none()
-                            }
-                            new_character_page::Command::SubraceSelected(subrace) => {
-                                self.race_props.selected_subrace = Some(subrace);
+                            new_character_page::Command::Save(draft) => {
+                                match storage::save(self.save_id.as_deref(), &draft) {
+                                    Ok(id) => {
+                                        self.save_id = Some(id);
+                                        new_character_page.set_status("Saved.");
+                                    }
+                                    Err(err) => new_character_page.set_status(err.to_string()),
+                                }
+                                self.draft = *draft;
                                 Task::none()
                             }
                         }
@@ -148,7 +248,16 @@ impl App {
         }
     }
 
-    pub fn view(&self) -> Element<Message> {
-        self.page.view()
+    pub fn view(&self) -> Element<'_, Message> {
+        match self.page {
+            Page::Main if !self.content_errors.is_empty() => {
+                let mut errors = column![Text::new("Some content failed to load:")];
+                for error in &self.content_errors {
+                    errors = errors.push(Text::new(error.to_string()).style(text::danger));
+                }
+                column![container(errors).padding(20), self.page.view()].into()
+            }
+            _ => self.page.view(),
+        }
     }
 }
@@ -0,0 +1,465 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    backend::dice::{Expression, Roller},
+    frontend::utils::Attribute,
+};
+
+/// The six abilities of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Ability {
+    Strength,
+    Dexterity,
+    Constitution,
+    Intelligence,
+    Wisdom,
+    Charisma,
+}
+
+impl Ability {
+    pub const ALL: [Ability; 6] = [
+        Ability::Strength,
+        Ability::Dexterity,
+        Ability::Constitution,
+        Ability::Intelligence,
+        Ability::Wisdom,
+        Ability::Charisma,
+    ];
+
+    /// The three letter abbreviation of the ability.
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Ability::Strength => "STR",
+            Ability::Dexterity => "DEX",
+            Ability::Constitution => "CON",
+            Ability::Intelligence => "INT",
+            Ability::Wisdom => "WIS",
+            Ability::Charisma => "CHA",
+        }
+    }
+}
+
+impl Display for Ability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ability::Strength => f.write_str("Strength"),
+            Ability::Dexterity => f.write_str("Dexterity"),
+            Ability::Constitution => f.write_str("Constitution"),
+            Ability::Intelligence => f.write_str("Intelligence"),
+            Ability::Wisdom => f.write_str("Wisdom"),
+            Ability::Charisma => f.write_str("Charisma"),
+        }
+    }
+}
+
+/// The score of each of the six abilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityScores {
+    pub strength: u8,
+    pub dexterity: u8,
+    pub constitution: u8,
+    pub intelligence: u8,
+    pub wisdom: u8,
+    pub charisma: u8,
+}
+
+impl Default for AbilityScores {
+    fn default() -> Self {
+        Self::splat(10)
+    }
+}
+
+impl AbilityScores {
+    /// The highest score an ability can reach through normal advancement.
+    pub const MAX_SCORE: u8 = 20;
+
+    /// Creates scores with every ability set to `score`.
+    pub const fn splat(score: u8) -> Self {
+        Self {
+            strength: score,
+            dexterity: score,
+            constitution: score,
+            intelligence: score,
+            wisdom: score,
+            charisma: score,
+        }
+    }
+
+    /// Returns the score of the given ability.
+    pub fn get(&self, ability: Ability) -> u8 {
+        match ability {
+            Ability::Strength => self.strength,
+            Ability::Dexterity => self.dexterity,
+            Ability::Constitution => self.constitution,
+            Ability::Intelligence => self.intelligence,
+            Ability::Wisdom => self.wisdom,
+            Ability::Charisma => self.charisma,
+        }
+    }
+
+    /// Returns a mutable reference to the score of the given ability.
+    pub fn get_mut(&mut self, ability: Ability) -> &mut u8 {
+        match ability {
+            Ability::Strength => &mut self.strength,
+            Ability::Dexterity => &mut self.dexterity,
+            Ability::Constitution => &mut self.constitution,
+            Ability::Intelligence => &mut self.intelligence,
+            Ability::Wisdom => &mut self.wisdom,
+            Ability::Charisma => &mut self.charisma,
+        }
+    }
+
+    /// Applies an ability score increase, capping the result at [`Self::MAX_SCORE`].
+    pub fn apply(&mut self, asi: &Attribute) {
+        let score = self.get_mut(asi.ability());
+        *score = score.saturating_add(asi.amount()).min(Self::MAX_SCORE);
+    }
+
+    /// Returns the modifier of the given ability.
+    pub fn modifier(&self, ability: Ability) -> i8 {
+        modifier(self.get(ability))
+    }
+}
+
+/// Returns the modifier for an ability score.
+pub fn modifier(score: u8) -> i8 {
+    (score as i8 - 10).div_euclid(2)
+}
+
+/// What an Ability Score Improvement class feature was spent on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Improvement {
+    /// Each entry increases the ability by 1, so the same ability may be listed twice.
+    Abilities(Vec<Ability>),
+
+    /// A feat taken instead of increasing ability scores.
+    Feat(FeatChoice),
+}
+
+impl Improvement {
+    /// The number of points an improvement spends on abilities.
+    pub const POINTS: usize = 2;
+
+    /// Returns `true` if every point of the improvement has been spent, or a feat has been
+    /// taken.
+    pub fn is_complete(&self) -> bool {
+        match self {
+            Improvement::Abilities(abilities) => abilities.len() == Self::POINTS,
+            Improvement::Feat(_) => true,
+        }
+    }
+
+    /// Applies the improvement, capping each score at [`AbilityScores::MAX_SCORE`].
+    pub fn apply(&self, scores: &mut AbilityScores) {
+        match self {
+            Improvement::Abilities(abilities) => {
+                for &ability in abilities {
+                    let score = scores.get_mut(ability);
+                    *score = (*score + 1).min(AbilityScores::MAX_SCORE);
+                }
+            }
+            Improvement::Feat(feat) => feat.apply(scores),
+        }
+    }
+}
+
+impl Display for Improvement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Improvement::Abilities(abilities) => {
+                let mut increases: Vec<(Ability, usize)> = vec![];
+                for &ability in abilities {
+                    match increases.iter_mut().find(|(a, _)| *a == ability) {
+                        Some((_, amount)) => *amount += 1,
+                        None => increases.push((ability, 1)),
+                    }
+                }
+                let increases = increases
+                    .iter()
+                    .map(|(ability, amount)| format!("+{amount} {ability}"))
+                    .collect::<Vec<_>>();
+                f.write_str(&increases.join(", "))
+            }
+            Improvement::Feat(feat) => feat.fmt(f),
+        }
+    }
+}
+
+/// A feat a character has taken, along with the ability it increased if the feat increases one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatChoice {
+    /// The id of the feat.
+    pub feat: String,
+
+    /// The ability the feat increased by 1, if any.
+    #[serde(default)]
+    pub ability: Option<Ability>,
+}
+
+impl FeatChoice {
+    /// Applies the feat's ability score increase, capping the score at
+    /// [`AbilityScores::MAX_SCORE`].
+    pub fn apply(&self, scores: &mut AbilityScores) {
+        if let Some(ability) = self.ability {
+            let score = scores.get_mut(ability);
+            *score = (*score + 1).min(AbilityScores::MAX_SCORE);
+        }
+    }
+}
+
+impl Display for FeatChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.feat)?;
+        if let Some(ability) = self.ability {
+            f.write_fmt(format_args!(" (+1 {ability})"))?;
+        }
+        Ok(())
+    }
+}
+
+/// The ways ability scores can be generated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GenerationMethod {
+    /// Assign the values of [`STANDARD_ARRAY`].
+    #[default]
+    StandardArray,
+
+    /// Buy scores with a budget of [`POINT_BUY_BUDGET`] points.
+    PointBuy,
+
+    /// Roll 4d6 and drop the lowest die, six times, and assign the totals.
+    Rolled,
+}
+
+impl GenerationMethod {
+    pub const ALL: [GenerationMethod; 3] = [
+        GenerationMethod::StandardArray,
+        GenerationMethod::PointBuy,
+        GenerationMethod::Rolled,
+    ];
+}
+
+impl Display for GenerationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerationMethod::StandardArray => f.write_str("Standard Array"),
+            GenerationMethod::PointBuy => f.write_str("Point Buy"),
+            GenerationMethod::Rolled => f.write_str("Roll (4d6, drop lowest)"),
+        }
+    }
+}
+
+/// The values assigned with the standard array method.
+pub const STANDARD_ARRAY: [u8; 6] = [15, 14, 13, 12, 10, 8];
+
+/// The number of points available with the point buy method.
+pub const POINT_BUY_BUDGET: u8 = 27;
+
+/// The lowest score that can be bought with the point buy method.
+pub const POINT_BUY_MIN: u8 = 8;
+
+/// The highest score that can be bought with the point buy method.
+pub const POINT_BUY_MAX: u8 = 15;
+
+/// Returns the point buy cost of a score, or `None` if it can't be bought.
+pub fn point_buy_cost(score: u8) -> Option<u8> {
+    match score {
+        8..=13 => Some(score - POINT_BUY_MIN),
+        14 => Some(7),
+        15 => Some(9),
+        _ => None,
+    }
+}
+
+/// The result of rolling 4d6 and dropping the lowest die.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityRoll {
+    /// The value of each of the four dice, in the order they were rolled.
+    pub dice: [u8; 4],
+}
+
+impl AbilityRoll {
+    /// The expression rolled for each ability: `4d6kh3`.
+    pub fn expression() -> Expression {
+        "4d6kh3".parse().expect("valid dice expression")
+    }
+
+    /// Rolls 4d6.
+    pub fn roll(roller: &mut Roller) -> Self {
+        let result = roller.roll(&Self::expression());
+        let mut dice = [0; 4];
+        for (die, result) in dice.iter_mut().zip(result.dice()) {
+            *die = result.die.value as u8;
+        }
+        Self { dice }
+    }
+
+    /// The index of the die that is dropped.
+    pub fn dropped(&self) -> usize {
+        (0..self.dice.len())
+            .min_by_key(|&i| self.dice[i])
+            .unwrap_or_default()
+    }
+
+    /// The sum of the three highest dice.
+    pub fn total(&self) -> u8 {
+        let dropped = self.dropped();
+        self.dice
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != dropped)
+            .map(|(_, die)| die)
+            .sum()
+    }
+}
+
+impl Display for AbilityRoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let dice = self
+            .dice
+            .iter()
+            .map(|die| die.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        f.write_fmt(format_args!(
+            "[{}] drop {} = {}",
+            dice,
+            self.dice[self.dropped()],
+            self.total()
+        ))
+    }
+}
+
+/// The selections made while generating a character's base ability scores.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AbilityGeneration {
+    /// The method used to generate the scores.
+    pub method: GenerationMethod,
+
+    /// For the standard array and rolled methods, the index into the pool of values that is
+    /// assigned to each ability.
+    pub assignments: BTreeMap<Ability, usize>,
+
+    /// The scores bought with the point buy method.
+    pub point_buy: AbilityScores,
+
+    /// Every set of rolls made with the rolled method, oldest first. The last six make up the
+    /// current pool.
+    pub rolls: Vec<AbilityRoll>,
+}
+
+impl Default for AbilityGeneration {
+    fn default() -> Self {
+        Self {
+            method: GenerationMethod::default(),
+            assignments: BTreeMap::new(),
+            point_buy: AbilityScores::splat(POINT_BUY_MIN),
+            rolls: vec![],
+        }
+    }
+}
+
+impl AbilityGeneration {
+    /// Changes the generation method, clearing any assignments.
+    pub fn set_method(&mut self, method: GenerationMethod) {
+        if self.method != method {
+            self.method = method;
+            self.assignments.clear();
+        }
+    }
+
+    /// The values that can be assigned with the current method.
+    ///
+    /// This is empty for the point buy method, or if the scores have not been rolled yet.
+    pub fn pool(&self) -> Vec<u8> {
+        match self.method {
+            GenerationMethod::StandardArray => STANDARD_ARRAY.to_vec(),
+            GenerationMethod::PointBuy => vec![],
+            GenerationMethod::Rolled => self
+                .current_rolls()
+                .iter()
+                .map(AbilityRoll::total)
+                .collect(),
+        }
+    }
+
+    /// The rolls that make up the current pool.
+    pub fn current_rolls(&self) -> &[AbilityRoll] {
+        &self.rolls[self.rolls.len().saturating_sub(Ability::ALL.len())..]
+    }
+
+    /// Rolls a new pool of six scores, clearing any assignments.
+    pub fn roll(&mut self, roller: &mut Roller) {
+        for _ in Ability::ALL {
+            self.rolls.push(AbilityRoll::roll(roller));
+        }
+        self.assignments.clear();
+    }
+
+    /// Assigns the value at `index` in the pool to `ability`.
+    ///
+    /// If another ability already has that value, it is unassigned.
+    pub fn assign(&mut self, ability: Ability, index: usize) {
+        self.assignments.retain(|_, assigned| *assigned != index);
+        self.assignments.insert(ability, index);
+    }
+
+    /// The points spent with the point buy method.
+    pub fn points_spent(&self) -> u8 {
+        Ability::ALL
+            .iter()
+            .filter_map(|&ability| point_buy_cost(self.point_buy.get(ability)))
+            .sum()
+    }
+
+    /// Returns `true` if the point buy score of `ability` can be increased.
+    pub fn can_increase(&self, ability: Ability) -> bool {
+        let score = self.point_buy.get(ability);
+        match (point_buy_cost(score), point_buy_cost(score + 1)) {
+            (Some(cost), Some(next)) => self.points_spent() - cost + next <= POINT_BUY_BUDGET,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the point buy score of `ability` can be decreased.
+    pub fn can_decrease(&self, ability: Ability) -> bool {
+        self.point_buy.get(ability) > POINT_BUY_MIN
+    }
+
+    /// Increases the point buy score of `ability`, if the budget allows it.
+    pub fn increase(&mut self, ability: Ability) {
+        if self.can_increase(ability) {
+            *self.point_buy.get_mut(ability) += 1;
+        }
+    }
+
+    /// Decreases the point buy score of `ability`.
+    pub fn decrease(&mut self, ability: Ability) {
+        if self.can_decrease(ability) {
+            *self.point_buy.get_mut(ability) -= 1;
+        }
+    }
+
+    /// The base score of `ability`, or `None` if it hasn't been assigned.
+    pub fn score(&self, ability: Ability) -> Option<u8> {
+        match self.method {
+            GenerationMethod::PointBuy => Some(self.point_buy.get(ability)),
+            GenerationMethod::StandardArray | GenerationMethod::Rolled => {
+                let index = *self.assignments.get(&ability)?;
+                self.pool().get(index).copied()
+            }
+        }
+    }
+
+    /// The base ability scores, or `None` if any ability hasn't been assigned.
+    pub fn scores(&self) -> Option<AbilityScores> {
+        let mut scores = AbilityScores::default();
+        for ability in Ability::ALL {
+            *scores.get_mut(ability) = self.score(ability)?;
+        }
+        Some(scores)
+    }
+}
@@ -0,0 +1,300 @@
+//! Computing a character's Armor Class from the armor and shield it wears, or from alternative
+//! formulas such as Unarmored Defense and Mage Armor while it wears no armor.
+//!
+//! Every way of computing the Armor Class that applies to the character is listed, and the
+//! highest one is used.
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::{
+    item::Armor,
+    utils::{proficiency::ArmorProficiency, ArmorClassFormula},
+};
+
+use super::abilities::{Ability, AbilityScores};
+
+/// The id of the Mage Armor spell, which grants [`MAGE_ARMOR`] while it is active.
+pub const MAGE_ARMOR_SPELL: &str = "mage-armor";
+
+/// The Armor Class granted by the Mage Armor spell to a creature that isn't wearing armor.
+pub static MAGE_ARMOR: ArmorClassFormula = ArmorClassFormula {
+    base: 13,
+    abilities: vec![],
+    shield: true,
+};
+
+/// The Armor Class of a creature that isn't wearing armor and has no other formula.
+static UNARMORED: ArmorClassFormula = ArmorClassFormula {
+    base: 10,
+    abilities: vec![],
+    shield: true,
+};
+
+/// The categories of armor that are worn, as opposed to shields.
+pub const ARMOR: [ArmorProficiency; 3] = [
+    ArmorProficiency::Light,
+    ArmorProficiency::Medium,
+    ArmorProficiency::Heavy,
+];
+
+/// How much walking speed wearing armor without the Strength it requires costs, in feet.
+pub const STRENGTH_SPEED_PENALTY: u16 = 10;
+
+/// The armor and shield a character wears.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WornArmor {
+    /// The id of the suit of armor worn, if any.
+    pub armor: Option<String>,
+
+    /// The id of the shield wielded, if any.
+    pub shield: Option<String>,
+
+    /// Whether the character is under the effect of the Mage Armor spell.
+    pub mage_armor: bool,
+}
+
+/// One way of computing a character's Armor Class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArmorClassOption {
+    /// What the base comes from, such as `Chain Mail` or `Unarmored Defense`.
+    pub source: String,
+
+    /// The base Armor Class.
+    pub base: u8,
+
+    /// The ability modifiers added to the base, with the Dexterity modifier already capped by
+    /// the armor.
+    pub modifiers: Vec<(Ability, i8)>,
+
+    /// The bonus of the shield, or 0 without one.
+    pub shield: u8,
+
+    /// Other bonuses, along with where they come from, such as the Defense fighting style.
+    pub bonuses: Vec<(String, i8)>,
+}
+
+impl ArmorClassOption {
+    /// The Armor Class given by the option.
+    pub fn total(&self) -> i16 {
+        self.base as i16
+            + self.shield as i16
+            + (self.modifiers.iter().map(|(_, modifier)| *modifier as i16))
+                .chain(self.bonuses.iter().map(|(_, bonus)| *bonus as i16))
+                .sum::<i16>()
+    }
+
+    /// Describes how the Armor Class adds up, such as `14 (Scale Mail) + 2 (DEX) + 2 (Shield)`.
+    pub fn breakdown(&self) -> String {
+        let term = |modifier: i8, source: &str| {
+            let sign = if modifier < 0 { '-' } else { '+' };
+            format!("{sign} {} ({source})", modifier.unsigned_abs())
+        };
+        let mut parts = vec![format!("{} ({})", self.base, self.source)];
+        for (ability, modifier) in &self.modifiers {
+            parts.push(term(*modifier, ability.abbreviation()));
+        }
+        if self.shield > 0 {
+            parts.push(format!("+ {} (Shield)", self.shield));
+        }
+        for (source, bonus) in &self.bonuses {
+            parts.push(term(*bonus, source));
+        }
+        parts.join(" ")
+    }
+}
+
+/// Returns every way of computing the Armor Class that applies to a character with `scores`,
+/// wearing `armor` and wielding `shield`.
+///
+/// Armor replaces every other formula. Without armor, the character can use 10 + its Dexterity
+/// modifier or any of `formulas`, each named after where it comes from, as long as the formula
+/// allows a shield if one is wielded.
+pub fn options(
+    scores: &AbilityScores,
+    armor: Option<(&str, &Armor)>,
+    shield: Option<&Armor>,
+    formulas: &[(&str, &ArmorClassFormula)],
+) -> Vec<ArmorClassOption> {
+    let shield_bonus = shield.map_or(0, |shield| shield.base_ac);
+    if let Some((name, armor)) = armor {
+        let dexterity = scores.modifier(Ability::Dexterity);
+        let dexterity = match armor.dex_cap {
+            Some(cap) => dexterity.min(cap as i8),
+            None => dexterity,
+        };
+        let modifiers = match armor.dex_cap {
+            Some(0) => vec![],
+            _ => vec![(Ability::Dexterity, dexterity)],
+        };
+        return vec![ArmorClassOption {
+            source: name.to_string(),
+            base: armor.base_ac,
+            modifiers,
+            shield: shield_bonus,
+            bonuses: vec![],
+        }];
+    }
+
+    std::iter::once(("Unarmored", &UNARMORED))
+        .chain(formulas.iter().copied())
+        .filter(|(_, formula)| shield.is_none() || formula.shield)
+        .map(|(source, formula)| ArmorClassOption {
+            source: source.to_string(),
+            base: formula.base,
+            modifiers: std::iter::once(Ability::Dexterity)
+                .chain(formula.abilities.iter().copied())
+                .map(|ability| (ability, scores.modifier(ability)))
+                .collect(),
+            shield: shield_bonus,
+            bonuses: vec![],
+        })
+        .collect()
+}
+
+/// Returns the option that gives the highest Armor Class, preferring the earliest one on ties.
+pub fn best(options: &[ArmorClassOption]) -> Option<&ArmorClassOption> {
+    options.iter().rev().max_by_key(|option| option.total())
+}
+
+/// Returns `true` if `armor` slows a character with `scores` down, because it doesn't have
+/// the Strength the armor requires.
+pub fn lacks_strength(scores: &AbilityScores, armor: &Armor) -> bool {
+    armor
+        .strength
+        .is_some_and(|strength| scores.get(Ability::Strength) < strength)
+}
+
+/// Describes what wearing `name`, armor of `category`, without proficiency does.
+pub fn non_proficiency_warning(name: &str, category: ArmorProficiency) -> String {
+    format!(
+        "You aren't proficient with {} ({name}): you have disadvantage on ability checks, saving \
+         throws, and attack rolls that involve Strength or Dexterity, and you can't cast spells.",
+        category.to_string().to_lowercase()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn armor(category: ArmorProficiency, base_ac: u8, dex_cap: Option<u8>) -> Armor {
+        Armor {
+            category,
+            base_ac,
+            dex_cap,
+            strength: None,
+            stealth_disadvantage: false,
+        }
+    }
+
+    fn scores(dexterity: u8, constitution: u8, wisdom: u8) -> AbilityScores {
+        AbilityScores {
+            dexterity,
+            constitution,
+            wisdom,
+            ..AbilityScores::default()
+        }
+    }
+
+    #[test]
+    fn armor_caps_the_dexterity_modifier() {
+        let scores = scores(18, 10, 10);
+        let shield = armor(ArmorProficiency::Shields, 2, None);
+
+        let leather = armor(ArmorProficiency::Light, 11, None);
+        let worn = options(&scores, Some(("Leather", &leather)), None, &[]);
+        assert_eq!(worn.len(), 1);
+        assert_eq!(worn[0].total(), 15);
+
+        let scale = armor(ArmorProficiency::Medium, 14, Some(2));
+        let worn = options(&scores, Some(("Scale Mail", &scale)), Some(&shield), &[]);
+        assert_eq!(worn[0].total(), 18);
+        assert_eq!(
+            worn[0].breakdown(),
+            "14 (Scale Mail) + 2 (DEX) + 2 (Shield)"
+        );
+
+        let plate = armor(ArmorProficiency::Heavy, 18, Some(0));
+        let worn = options(&scores, Some(("Plate", &plate)), None, &[]);
+        assert_eq!(worn[0].total(), 18);
+        assert_eq!(worn[0].breakdown(), "18 (Plate)");
+    }
+
+    #[test]
+    fn armor_replaces_every_other_formula() {
+        let unarmored_defense = ArmorClassFormula {
+            base: 10,
+            abilities: vec![Ability::Constitution],
+            shield: true,
+        };
+        let chain_mail = armor(ArmorProficiency::Heavy, 16, Some(0));
+        let worn = options(
+            &scores(14, 16, 10),
+            Some(("Chain Mail", &chain_mail)),
+            None,
+            &[("Unarmored Defense", &unarmored_defense)],
+        );
+        assert_eq!(worn.len(), 1);
+        assert_eq!(worn[0].source, "Chain Mail");
+    }
+
+    #[test]
+    fn picks_the_best_unarmored_formula() {
+        let scores = scores(16, 14, 16);
+        let barbarian = ArmorClassFormula {
+            base: 10,
+            abilities: vec![Ability::Constitution],
+            shield: true,
+        };
+        let monk = ArmorClassFormula {
+            base: 10,
+            abilities: vec![Ability::Wisdom],
+            shield: false,
+        };
+        let formulas = [
+            ("Unarmored Defense (Barbarian)", &barbarian),
+            ("Unarmored Defense (Monk)", &monk),
+            ("Mage Armor", &MAGE_ARMOR),
+        ];
+
+        let all = options(&scores, None, None, &formulas);
+        let totals = all.iter().map(ArmorClassOption::total).collect::<Vec<_>>();
+        assert_eq!(totals, [13, 15, 16, 16]);
+        assert_eq!(best(&all).unwrap().source, "Unarmored Defense (Monk)");
+        assert_eq!(
+            best(&all).unwrap().breakdown(),
+            "10 (Unarmored Defense (Monk)) + 3 (DEX) + 3 (WIS)"
+        );
+
+        // The Monk's Unarmored Defense doesn't allow a shield
+        let shield = armor(ArmorProficiency::Shields, 2, None);
+        let with_shield = options(&scores, None, Some(&shield), &formulas);
+        let sources = (with_shield.iter())
+            .map(|option| option.source.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            ["Unarmored", "Unarmored Defense (Barbarian)", "Mage Armor"]
+        );
+        assert_eq!(best(&with_shield).unwrap().total(), 18);
+        assert_eq!(best(&with_shield).unwrap().source, "Mage Armor");
+    }
+
+    #[test]
+    fn heavy_armor_requires_strength() {
+        let mut splint = armor(ArmorProficiency::Heavy, 17, Some(0));
+        splint.strength = Some(15);
+        let mut scores = AbilityScores {
+            strength: 14,
+            ..AbilityScores::default()
+        };
+        assert!(lacks_strength(&scores, &splint));
+        scores.strength = 15;
+        assert!(!lacks_strength(&scores, &splint));
+        assert!(!lacks_strength(
+            &scores,
+            &armor(ArmorProficiency::Light, 11, None)
+        ));
+    }
+}
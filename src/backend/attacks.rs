@@ -0,0 +1,458 @@
+//! Computing the attack rolls and damage of the weapons a character wields.
+//!
+//! The to-hit bonus adds the modifier of the ability the weapon uses, the proficiency bonus if
+//! the character is proficient with it, and its magic bonus. The damage adds the same modifier
+//! and magic bonus to the weapon's damage dice. Fighting styles and two-weapon fighting adjust
+//! both, as described on [`attack`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::{
+    item::{Item, Weapon, WeaponProperty, WeaponRange},
+    utils::{DamageType, SizeCategory},
+};
+
+use super::abilities::{Ability, AbilityScores};
+
+/// The id of the Archery fighting style: +2 to attack rolls with ranged weapons.
+pub const ARCHERY: &str = "archery";
+
+/// The id of the Defense fighting style: +1 to AC while wearing armor.
+pub const DEFENSE: &str = "defense";
+
+/// The id of the Dueling fighting style: +2 to damage with a melee weapon held in one hand and
+/// no other weapons.
+pub const DUELING: &str = "dueling";
+
+/// The id of the Great Weapon Fighting fighting style: reroll 1s and 2s on the damage dice of
+/// melee weapons held in two hands.
+pub const GREAT_WEAPON_FIGHTING: &str = "great-weapon-fighting";
+
+/// The id of the Two-Weapon Fighting fighting style: add the ability modifier to the damage of
+/// the off-hand attack.
+pub const TWO_WEAPON_FIGHTING: &str = "two-weapon-fighting";
+
+/// The highest bonus a magic weapon can have.
+pub const MAX_MAGIC_BONUS: u8 = 3;
+
+/// A weapon a character wields.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WieldedWeapon {
+    /// The id of the weapon.
+    pub item: String,
+
+    /// The bonus to attack and damage rolls of a magic weapon, such as 1 for a +1 longsword.
+    #[serde(default)]
+    pub magic_bonus: u8,
+
+    /// Whether a versatile weapon is held in two hands.
+    #[serde(default)]
+    pub two_handed: bool,
+
+    /// Whether the weapon is held in the off hand, to attack with it as a bonus action when
+    /// fighting with two weapons.
+    #[serde(default)]
+    pub off_hand: bool,
+
+    /// The ability picked for a finesse weapon, or `None` to use the better of Strength and
+    /// Dexterity.
+    #[serde(default)]
+    pub finesse: Option<Ability>,
+}
+
+impl WieldedWeapon {
+    /// Creates a mundane weapon held in the main hand.
+    pub fn new(item: String) -> Self {
+        Self {
+            item,
+            magic_bonus: 0,
+            two_handed: false,
+            off_hand: false,
+            finesse: None,
+        }
+    }
+}
+
+/// What the attacks of a character depend on besides the weapon.
+#[derive(Debug, Clone)]
+pub struct Attacker<'a> {
+    /// The final ability scores of the character.
+    pub scores: AbilityScores,
+
+    /// The proficiency bonus of the character.
+    pub proficiency_bonus: u8,
+
+    /// The ids of the fighting styles the character has picked.
+    pub fighting_styles: Vec<&'a str>,
+
+    /// The size of the character.
+    pub size: SizeCategory,
+
+    /// The number of weapons the character wields.
+    pub weapons: usize,
+
+    /// Whether the character wields a shield.
+    pub shield: bool,
+}
+
+impl Attacker<'_> {
+    fn has_style(&self, style: &str) -> bool {
+        self.fighting_styles.contains(&style)
+    }
+}
+
+/// An attack with a wielded weapon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attack {
+    /// The name of the weapon, with its magic bonus, such as `+1 Longsword`.
+    pub name: String,
+
+    /// The ability the attack uses.
+    pub ability: Ability,
+
+    /// The bonus to the attack roll.
+    pub to_hit: i16,
+
+    /// The dice rolled for damage, such as `1d10r2` with Great Weapon Fighting.
+    pub dice: String,
+
+    /// The bonus added to the damage roll.
+    pub damage_bonus: i16,
+
+    /// The type of damage dealt.
+    pub damage_type: DamageType,
+
+    /// Details of the attack, such as its range and the rules that apply to it.
+    pub notes: Vec<String>,
+}
+
+impl Attack {
+    /// The dice expression of the attack roll, such as `1d20+5`.
+    pub fn attack_roll(&self) -> String {
+        format!("1d20{:+}", self.to_hit)
+    }
+
+    /// The dice expression of the damage roll, such as `1d8+3`.
+    pub fn damage_roll(&self) -> String {
+        with_bonus(&self.dice, self.damage_bonus)
+    }
+}
+
+/// Returns the attack made with `weapon`, the statistics of `item`, wielded as `wielded` by
+/// `attacker`, or `None` if the weapon deals no damage, such as a net.
+///
+/// - A finesse weapon uses the picked ability, or the better of Strength and Dexterity; other
+///   melee weapons use Strength and ranged weapons use Dexterity.
+/// - A versatile weapon held in two hands uses its versatile damage.
+/// - The off-hand attack doesn't add a positive ability modifier to the damage, unless the
+///   character has the Two-Weapon Fighting style.
+/// - Archery adds 2 to the attack rolls of ranged weapons, Dueling adds 2 to the damage of a
+///   melee weapon held in one hand without another weapon, and Great Weapon Fighting rerolls
+///   1s and 2s on the damage dice of melee weapons held in two hands.
+pub fn attack(
+    item: &Item,
+    weapon: &Weapon,
+    wielded: &WieldedWeapon,
+    proficient: bool,
+    attacker: &Attacker,
+) -> Option<Attack> {
+    let damage = weapon.damage.as_ref()?;
+    let has = |property: &WeaponProperty| weapon.properties.contains(property);
+    let versatile = weapon
+        .properties
+        .iter()
+        .find_map(|property| match property {
+            WeaponProperty::Versatile(dice) => Some(dice),
+            _ => None,
+        });
+    let melee = weapon.range == WeaponRange::Melee;
+    let two_handed = has(&WeaponProperty::TwoHanded) || (wielded.two_handed && versatile.is_some());
+
+    let modifier = |ability| attacker.scores.modifier(ability) as i16;
+    let ability = if has(&WeaponProperty::Finesse) {
+        wielded.finesse.unwrap_or(
+            if modifier(Ability::Dexterity) > modifier(Ability::Strength) {
+                Ability::Dexterity
+            } else {
+                Ability::Strength
+            },
+        )
+    } else if melee {
+        Ability::Strength
+    } else {
+        Ability::Dexterity
+    };
+
+    let mut notes = vec![];
+    let magic = wielded.magic_bonus as i16;
+    let mut to_hit = modifier(ability) + magic;
+    if proficient {
+        to_hit += attacker.proficiency_bonus as i16;
+    } else {
+        notes.push("Not proficient".to_string());
+    }
+    if !melee && attacker.has_style(ARCHERY) {
+        to_hit += 2;
+        notes.push("Archery".to_string());
+    }
+
+    let mut damage_bonus = modifier(ability).min(0) + magic;
+    if !wielded.off_hand || attacker.has_style(TWO_WEAPON_FIGHTING) {
+        damage_bonus += modifier(ability).max(0);
+    }
+    if wielded.off_hand {
+        notes.push("Bonus action".to_string());
+        if !has(&WeaponProperty::Light) {
+            notes.push("Not light: can't be used for two-weapon fighting".to_string());
+        }
+    }
+    if melee && !two_handed && attacker.weapons == 1 && attacker.has_style(DUELING) {
+        damage_bonus += 2;
+        notes.push("Dueling".to_string());
+    }
+
+    let mut dice = match versatile {
+        Some(dice) if wielded.two_handed => dice.clone(),
+        _ => damage.dice.clone(),
+    };
+    if melee && two_handed && attacker.has_style(GREAT_WEAPON_FIGHTING) {
+        dice = reroll(&dice, 2);
+        notes.push("Great Weapon Fighting".to_string());
+    }
+
+    if two_handed {
+        notes.push("Two-handed".to_string());
+        if attacker.shield {
+            notes.push("Can't be used while wielding a shield".to_string());
+        }
+    }
+    for property in &weapon.properties {
+        match property {
+            WeaponProperty::Ammunition(range) => {
+                notes.push(format!("Range {}/{} ft.", range.normal, range.long))
+            }
+            WeaponProperty::Thrown(range) => {
+                notes.push(format!("Thrown {}/{} ft.", range.normal, range.long))
+            }
+            WeaponProperty::Reach => notes.push("Reach 10 ft.".to_string()),
+            WeaponProperty::Loading => notes.push("Loading".to_string()),
+            WeaponProperty::Heavy
+                if matches!(attacker.size, SizeCategory::Tiny | SizeCategory::Small) =>
+            {
+                notes.push("Heavy: disadvantage for Small creatures".to_string())
+            }
+            _ => {}
+        }
+    }
+
+    let name = match wielded.magic_bonus {
+        0 => item.name.clone(),
+        bonus => format!("+{bonus} {}", item.name),
+    };
+    Some(Attack {
+        name,
+        ability,
+        to_hit,
+        dice,
+        damage_bonus,
+        damage_type: damage.kind,
+        notes,
+    })
+}
+
+/// Adds `bonus` to the dice expression `dice`, such as `1d8+3`.
+fn with_bonus(dice: &str, bonus: i16) -> String {
+    match bonus {
+        0 => dice.to_string(),
+        bonus => format!("{dice}{bonus:+}"),
+    }
+}
+
+/// Makes every die of the dice expression `dice`, such as `2d6`, reroll values up to `values`
+/// once. Constant damage, such as a blowgun's, is left as it is.
+fn reroll(dice: &str, values: u32) -> String {
+    match dice.split_once('d') {
+        Some((_, sides)) if sides.parse::<u32>().is_ok_and(|sides| sides > values) => {
+            format!("{dice}r{values}")
+        }
+        _ => dice.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::content::ContentRegistry, frontend::item::ItemKind};
+
+    /// A Medium character with 16 Strength and 14 Dexterity, proficiency bonus +2, wielding
+    /// one weapon.
+    fn attacker<'a>(fighting_styles: &[&'a str]) -> Attacker<'a> {
+        Attacker {
+            scores: AbilityScores {
+                strength: 16,
+                dexterity: 14,
+                ..AbilityScores::default()
+            },
+            proficiency_bonus: 2,
+            fighting_styles: fighting_styles.to_vec(),
+            size: SizeCategory::Medium,
+            weapons: 1,
+            shield: false,
+        }
+    }
+
+    fn attack_with(wielded: &WieldedWeapon, attacker: &Attacker) -> Option<Attack> {
+        let content = ContentRegistry::builtin();
+        let item = content.item(&wielded.item).unwrap();
+        let ItemKind::Weapon(weapon) = &item.kind else {
+            panic!("{} isn't a weapon", item.name);
+        };
+        attack(item, weapon, wielded, true, attacker)
+    }
+
+    fn wielded(item: &str) -> WieldedWeapon {
+        WieldedWeapon::new(item.to_string())
+    }
+
+    #[test]
+    fn adds_the_modifier_and_proficiency_bonus() {
+        let attack = attack_with(&wielded("longsword"), &attacker(&[])).unwrap();
+        assert_eq!(attack.ability, Ability::Strength);
+        assert_eq!(attack.attack_roll(), "1d20+5");
+        assert_eq!(attack.damage_roll(), "1d8+3");
+        assert_eq!(attack.damage_type, DamageType::Slashing);
+
+        let longbow = attack_with(&wielded("longbow"), &attacker(&[])).unwrap();
+        assert_eq!(longbow.ability, Ability::Dexterity);
+        assert_eq!(longbow.attack_roll(), "1d20+4");
+        assert_eq!(longbow.notes, ["Two-handed", "Range 150/600 ft."]);
+
+        let content = ContentRegistry::builtin();
+        let item = content.item("longsword").unwrap();
+        let ItemKind::Weapon(weapon) = &item.kind else {
+            unreachable!();
+        };
+        let unskilled =
+            super::attack(item, weapon, &wielded("longsword"), false, &attacker(&[])).unwrap();
+        assert_eq!(unskilled.attack_roll(), "1d20+3");
+        assert_eq!(unskilled.notes, ["Not proficient"]);
+    }
+
+    #[test]
+    fn magic_weapons_add_their_bonus() {
+        let mut longsword = wielded("longsword");
+        longsword.magic_bonus = 2;
+        let attack = attack_with(&longsword, &attacker(&[])).unwrap();
+        assert_eq!(attack.name, "+2 Longsword");
+        assert_eq!(attack.attack_roll(), "1d20+7");
+        assert_eq!(attack.damage_roll(), "1d8+5");
+    }
+
+    #[test]
+    fn finesse_weapons_use_the_better_or_picked_ability() {
+        let mut rapier = wielded("rapier");
+        assert_eq!(
+            attack_with(&rapier, &attacker(&[])).unwrap().ability,
+            Ability::Strength
+        );
+
+        let mut nimble = attacker(&[]);
+        nimble.scores.dexterity = 18;
+        assert_eq!(
+            attack_with(&rapier, &nimble).unwrap().ability,
+            Ability::Dexterity
+        );
+
+        rapier.finesse = Some(Ability::Dexterity);
+        let attack = attack_with(&rapier, &attacker(&[])).unwrap();
+        assert_eq!(attack.attack_roll(), "1d20+4");
+        assert_eq!(attack.damage_roll(), "1d8+2");
+    }
+
+    #[test]
+    fn versatile_weapons_held_in_two_hands_deal_more_damage() {
+        let mut longsword = wielded("longsword");
+        let attack = attack_with(&longsword, &attacker(&[DUELING])).unwrap();
+        assert_eq!(attack.damage_roll(), "1d8+5");
+        assert_eq!(attack.notes, ["Dueling"]);
+
+        longsword.two_handed = true;
+        let attack = attack_with(&longsword, &attacker(&[DUELING])).unwrap();
+        assert_eq!(attack.damage_roll(), "1d10+3");
+        assert_eq!(attack.notes, ["Two-handed"]);
+
+        let attack = attack_with(&longsword, &attacker(&[GREAT_WEAPON_FIGHTING])).unwrap();
+        assert_eq!(attack.damage_roll(), "1d10r2+3");
+    }
+
+    #[test]
+    fn off_hand_attacks_add_no_positive_modifier_without_the_style() {
+        let mut shortsword = wielded("shortsword");
+        shortsword.off_hand = true;
+        let mut two_weapons = attacker(&[]);
+        two_weapons.weapons = 2;
+
+        let attack = attack_with(&shortsword, &two_weapons).unwrap();
+        assert_eq!(attack.attack_roll(), "1d20+5");
+        assert_eq!(attack.damage_roll(), "1d6");
+        assert_eq!(attack.notes, ["Bonus action"]);
+
+        two_weapons.fighting_styles = vec![TWO_WEAPON_FIGHTING];
+        let attack = attack_with(&shortsword, &two_weapons).unwrap();
+        assert_eq!(attack.damage_roll(), "1d6+3");
+
+        // A negative modifier still applies
+        two_weapons.scores = AbilityScores::splat(8);
+        let attack = attack_with(&shortsword, &two_weapons).unwrap();
+        assert_eq!(attack.damage_roll(), "1d6-1");
+        two_weapons.fighting_styles = vec![];
+        let attack = attack_with(&shortsword, &two_weapons).unwrap();
+        assert_eq!(attack.damage_roll(), "1d6-1");
+
+        let mut longsword = wielded("longsword");
+        longsword.off_hand = true;
+        let attack = attack_with(&longsword, &two_weapons).unwrap();
+        assert!(attack
+            .notes
+            .contains(&"Not light: can't be used for two-weapon fighting".to_string()));
+    }
+
+    #[test]
+    fn fighting_styles_apply_to_their_weapons() {
+        let archer = attacker(&[ARCHERY, GREAT_WEAPON_FIGHTING]);
+        let longbow = attack_with(&wielded("longbow"), &archer).unwrap();
+        assert_eq!(longbow.attack_roll(), "1d20+6");
+        assert_eq!(longbow.damage_roll(), "1d8+2");
+        assert!(longbow.notes.contains(&"Archery".to_string()));
+
+        let greatsword = attack_with(&wielded("greatsword"), &archer).unwrap();
+        assert_eq!(greatsword.attack_roll(), "1d20+5");
+        assert_eq!(greatsword.damage_roll(), "2d6r2+3");
+        assert_eq!(greatsword.notes, ["Great Weapon Fighting", "Two-handed"]);
+
+        let mut small = attacker(&[]);
+        small.size = SizeCategory::Small;
+        small.shield = true;
+        let greatsword = attack_with(&wielded("greatsword"), &small).unwrap();
+        assert_eq!(
+            greatsword.notes,
+            [
+                "Two-handed",
+                "Can't be used while wielding a shield",
+                "Heavy: disadvantage for Small creatures"
+            ]
+        );
+    }
+
+    #[test]
+    fn weapons_without_damage_have_no_attack() {
+        assert_eq!(attack_with(&wielded("net"), &attacker(&[])), None);
+    }
+
+    #[test]
+    fn rerolls_only_dice_with_more_sides_than_the_values() {
+        assert_eq!(reroll("2d6", 2), "2d6r2");
+        assert_eq!(reroll("1d2", 2), "1d2");
+        assert_eq!(reroll("1", 2), "1");
+    }
+}
@@ -0,0 +1,2130 @@
+//! A character and everything computed from it: its ability scores, proficiencies, hit points,
+//! spellcasting, equipment, and attacks.
+
+use std::{borrow::Cow, collections::BTreeMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::{
+    background::{Personality, PersonalityTable},
+    class::{ClassFeature, Die, FeatureOption, Preparation},
+    feat::{Feat, FeatPrerequisite},
+    item::{self, Armor, Item, ItemKind, ItemStack, Weapon, WeaponCategory},
+    race::RacialTrait,
+    spell::Spell,
+    utils::{
+        choice_key,
+        proficiency::{ArmorProficiency, Proficiency, WeaponProficiency},
+        DamageType, Effect, Selections, Sense, SizeCategory, Speed,
+    },
+};
+
+use super::{
+    abilities::{Ability, AbilityGeneration, AbilityScores, FeatChoice, Improvement},
+    armor_class::{self, ArmorClassOption, WornArmor, ARMOR, MAGE_ARMOR, MAGE_ARMOR_SPELL},
+    attacks::{self, Attack, Attacker, WieldedWeapon},
+    content::{ContentKind, ContentRegistry},
+    currency::{CurrencyError, Wealth},
+    dice::Roller,
+    equipment::{self, EquipmentSelections, EquipmentSource, StartingWealth},
+    hit_points::{self, HitPointGain, LevelHitPoints},
+    inventory::{self, Encumbrance, EncumbranceRule, Inventory, MAX_ATTUNED},
+    level_up::ChangelogEntry,
+    multiclass::{ClassLevels, Multiclass, MulticlassError},
+    proficiencies::{self, Grant, ProficiencyChoice},
+    skills::Checks,
+    spellcasting::{ClassSpellcasting, ExpendedSlots, SpellList, SpellSelection, SpellSlots},
+};
+
+/// The highest level a character can reach.
+pub const MAX_LEVEL: u8 = 20;
+
+/// A character, built from a race, a subrace, a class, a background, and the selections made for
+/// them.
+///
+/// Content is stored by id, and everything derived from it, such as the final ability scores,
+/// proficiencies, and hit points, is computed from the [`ContentRegistry`]. This is what gets
+/// saved to disk, so that a character can be reopened in the `New Character` page with all of
+/// its selections intact, and what gains levels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Character {
+    /// The name of the character.
+    pub name: String,
+
+    /// The id of the selected race.
+    pub race: Option<String>,
+
+    /// The id of the selected subrace.
+    pub subrace: Option<String>,
+
+    /// The id of the class the character started with.
+    pub class: Option<String>,
+
+    /// The id of the selected subclass of the class the character started with.
+    pub subclass: Option<String>,
+
+    /// The id of the selected background.
+    pub background: Option<String>,
+
+    /// The entries picked from the personality tables of the background.
+    pub personality: Personality,
+
+    /// The level of the character, the total of the levels in all of its classes.
+    pub level: u8,
+
+    /// The classes the character has multiclassed into, in the order they were first taken.
+    pub multiclasses: Vec<Multiclass>,
+
+    /// The selections made while generating the base ability scores.
+    pub abilities: AbilityGeneration,
+
+    /// The proficiencies selected for each choice offered by the race, subrace, class, and
+    /// background.
+    pub proficiency_selections: Selections<Proficiency>,
+
+    /// The proficiencies picked to replace duplicate grants, keyed by
+    /// [`Duplicate::slots`](proficiencies::Duplicate::slots).
+    pub proficiency_replacements: BTreeMap<String, Proficiency>,
+
+    /// The proficiencies picked for expertise with features such as the Rogue's Expertise.
+    pub expertise: Vec<Proficiency>,
+
+    /// How the hit die was resolved for each level after the first, starting with the 2nd.
+    pub hit_point_gains: Vec<HitPointGain>,
+
+    /// The current hit points of the character, or `None` while it has all of them.
+    pub hit_points: Option<u16>,
+
+    /// What each ability score improvement was spent on, keyed by the level it was gained at.
+    pub improvements: BTreeMap<u8, Improvement>,
+
+    /// The ids of the options picked for class features such as Fighting Style, keyed by the id
+    /// of the feature.
+    pub feature_options: BTreeMap<String, Vec<String>>,
+
+    /// The spells picked for each spellcasting class, keyed by the id of the class.
+    pub spells: BTreeMap<String, SpellSelection>,
+
+    /// The spell slots expended since the character's last rest.
+    pub expended_slots: ExpendedSlots,
+
+    /// The abilities picked for racial traits that increase ability scores of the character's
+    /// choice, such as the Variant Human's.
+    pub racial_increases: Vec<Ability>,
+
+    /// The feat picked for racial traits that grant one, such as the Variant Human's.
+    pub bonus_feat: Option<FeatChoice>,
+
+    /// The optional rules of the campaign the character is built for.
+    pub campaign: CampaignRules,
+
+    /// The choices made for the starting equipment of the character's class and background.
+    pub equipment: EquipmentSelections,
+
+    /// The armor and shield the character wears.
+    pub worn: WornArmor,
+
+    /// The weapons the character wields.
+    pub wielded: Vec<WieldedWeapon>,
+
+    /// The items the character carries, or `None` while they are still its starting
+    /// equipment.
+    pub inventory: Option<Inventory>,
+
+    /// The coins the character carries and what it received and spent, or `None` while it
+    /// still has only its starting gold.
+    pub wealth: Option<Wealth>,
+
+    /// What changed each time the character gained a level.
+    pub changelog: Vec<ChangelogEntry>,
+}
+
+impl Default for Character {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            race: None,
+            subrace: None,
+            class: None,
+            subclass: None,
+            background: None,
+            personality: Personality::default(),
+            level: 1,
+            multiclasses: vec![],
+            abilities: AbilityGeneration::default(),
+            proficiency_selections: Selections::new(),
+            proficiency_replacements: BTreeMap::new(),
+            expertise: vec![],
+            hit_point_gains: vec![],
+            hit_points: None,
+            improvements: BTreeMap::new(),
+            feature_options: BTreeMap::new(),
+            spells: BTreeMap::new(),
+            expended_slots: ExpendedSlots::default(),
+            racial_increases: vec![],
+            bonus_feat: None,
+            campaign: CampaignRules::default(),
+            equipment: EquipmentSelections::default(),
+            worn: WornArmor::default(),
+            wielded: vec![],
+            inventory: None,
+            wealth: None,
+            changelog: vec![],
+        }
+    }
+}
+
+/// The optional rules of the campaign a character is built for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CampaignRules {
+    /// Whether characters can take feats, instead of ability score improvements and from racial
+    /// traits.
+    pub feats: bool,
+
+    /// The rules used to decide how much weight slows characters down.
+    pub encumbrance: EncumbranceRule,
+}
+
+impl Default for CampaignRules {
+    fn default() -> Self {
+        Self {
+            feats: true,
+            encumbrance: EncumbranceRule::default(),
+        }
+    }
+}
+
+impl Character {
+    /// Returns the total ability score increases provided by the selected race and subrace,
+    /// including the ones picked for traits such as the Variant Human's.
+    ///
+    /// Selections that aren't registered in `content` are ignored.
+    pub fn asi(&self, content: &ContentRegistry) -> AbilityScores {
+        let race = self.race.as_ref().and_then(|id| content.race(id).ok());
+        let subrace = self
+            .subrace
+            .as_ref()
+            .and_then(|id| content.subrace(id).ok());
+
+        let mut bonuses = AbilityScores::splat(0);
+        let asi = race
+            .iter()
+            .flat_map(|race| &race.asi)
+            .chain(subrace.iter().flat_map(|subrace| &subrace.asi));
+        for asi in asi {
+            *bonuses.get_mut(asi.ability()) += asi.amount();
+        }
+        for ability in self.racial_increases(content) {
+            *bonuses.get_mut(ability) += 1;
+        }
+        bonuses
+    }
+
+    /// Returns the traits of the selected race and subrace.
+    ///
+    /// Selections that aren't registered in `content` are ignored.
+    pub fn traits<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a RacialTrait> {
+        let race = self.race.as_ref().and_then(|id| content.race(id).ok());
+        let subrace = self
+            .subrace
+            .as_ref()
+            .and_then(|id| content.subrace(id).ok());
+        race.into_iter()
+            .flat_map(|race| &race.traits)
+            .chain(subrace.into_iter().flat_map(|subrace| &subrace.traits))
+            .collect()
+    }
+
+    /// Returns the effects of the traits of the selected race and subrace.
+    ///
+    /// Selections that aren't registered in `content` are ignored.
+    pub fn racial_effects<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a Effect> {
+        self.traits(content)
+            .into_iter()
+            .flat_map(|racial_trait| &racial_trait.effects)
+            .collect()
+    }
+
+    /// Returns the effects of the racial traits, of the class features, and of the feats the
+    /// character has taken.
+    pub fn effects<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a Effect> {
+        let mut effects = self.racial_effects(content);
+        for feature in self.features(content) {
+            effects.extend(&feature.effects);
+        }
+        for (feat, _) in self.feats(content) {
+            effects.extend(&feat.effects);
+        }
+        effects
+    }
+
+    /// Returns the number of different abilities the racial traits let the character increase
+    /// by 1.
+    pub fn racial_increase_count(&self, content: &ContentRegistry) -> usize {
+        self.racial_effects(content)
+            .into_iter()
+            .map(|effect| match effect {
+                Effect::AbilityScoreChoices(count) => *count as usize,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Returns the abilities picked for the racial traits, up to the number they allow.
+    pub fn racial_increases(&self, content: &ContentRegistry) -> Vec<Ability> {
+        let mut increases = self.racial_increases.clone();
+        increases.dedup();
+        increases.truncate(self.racial_increase_count(content));
+        increases
+    }
+
+    /// Picks or unpicks `ability` for the racial traits. It is only picked while fewer than
+    /// [`Self::racial_increase_count`] are.
+    pub fn toggle_racial_increase(&mut self, content: &ContentRegistry, ability: Ability) {
+        let mut increases = self.racial_increases(content);
+        if let Some(i) = increases.iter().position(|&a| a == ability) {
+            increases.remove(i);
+        } else if increases.len() < self.racial_increase_count(content) {
+            increases.push(ability);
+        }
+        self.racial_increases = increases;
+    }
+
+    /// Returns `true` if the racial traits grant a feat and the campaign uses feats.
+    pub fn has_bonus_feat(&self, content: &ContentRegistry) -> bool {
+        self.campaign.feats
+            && self
+                .racial_effects(content)
+                .iter()
+                .any(|effect| matches!(effect, Effect::Feat))
+    }
+
+    /// Returns the feat taken for the ability score improvement gained at `level`, or for the
+    /// racial traits if `level` is `None`.
+    pub fn feat_choice(&self, level: Option<u8>) -> Option<&FeatChoice> {
+        match level {
+            None => self.bonus_feat.as_ref(),
+            Some(level) => match self.improvements.get(&level) {
+                Some(Improvement::Feat(choice)) => Some(choice),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns every feat the character has taken, from its racial traits and ability score
+    /// improvements, or none if the campaign doesn't use feats.
+    ///
+    /// Feats that aren't registered in `content` are ignored.
+    pub fn feats<'a>(&self, content: &'a ContentRegistry) -> Vec<(&'a Feat, &FeatChoice)> {
+        if !self.campaign.feats {
+            return vec![];
+        }
+        let bonus = self
+            .has_bonus_feat(content)
+            .then_some(self.bonus_feat.as_ref())
+            .flatten();
+        let levels = self.improvement_levels(content);
+        bonus
+            .into_iter()
+            .chain(
+                levels
+                    .into_iter()
+                    .filter_map(|level| self.feat_choice(Some(level))),
+            )
+            .filter_map(|choice| Some((content.feat(&choice.feat).ok()?, choice)))
+            .collect()
+    }
+
+    /// Returns the prerequisites of `feat` the character doesn't meet.
+    pub fn unmet_prerequisites<'a>(
+        &self,
+        content: &ContentRegistry,
+        feat: &'a Feat,
+    ) -> Vec<&'a FeatPrerequisite> {
+        let scores = self.final_ability_scores(content);
+        let proficiencies = self.proficiencies(content);
+        feat.prerequisites
+            .iter()
+            .filter(|prerequisite| match prerequisite {
+                FeatPrerequisite::Ability(prerequisite) => {
+                    !scores.is_some_and(|scores| prerequisite.is_met(&scores))
+                }
+                FeatPrerequisite::Proficiency(proficiency) => !proficiencies.contains(proficiency),
+                FeatPrerequisite::Spellcasting => self.spellcasting(content).is_empty(),
+                FeatPrerequisite::Race(ids) => !ids
+                    .iter()
+                    .any(|id| self.race.as_ref() == Some(id) || self.subrace.as_ref() == Some(id)),
+            })
+            .collect()
+    }
+
+    /// Returns the feats that can be taken for the ability score improvement gained at `level`,
+    /// or for the racial traits if `level` is `None`: the ones whose prerequisites are met and
+    /// that aren't taken elsewhere. No feats can be taken if the campaign doesn't use them.
+    pub fn feat_options<'a>(
+        &self,
+        content: &'a ContentRegistry,
+        level: Option<u8>,
+    ) -> Vec<&'a Feat> {
+        if !self.campaign.feats {
+            return vec![];
+        }
+        let current = self.feat_choice(level);
+        let taken = self
+            .feats(content)
+            .into_iter()
+            .filter(|(_, choice)| Some(*choice) != current)
+            .map(|(feat, _)| &feat.id)
+            .collect::<Vec<_>>();
+        content
+            .feats()
+            .filter(|feat| {
+                !taken.contains(&&feat.id) && self.unmet_prerequisites(content, feat).is_empty()
+            })
+            .collect()
+    }
+
+    /// Takes the feat with the id `feat` for the ability score improvement gained at `level`, or
+    /// for the racial traits if `level` is `None`, if it is one of the
+    /// [`Self::feat_options`].
+    ///
+    /// A feat that can only increase one ability increases it right away.
+    pub fn choose_feat(&mut self, content: &ContentRegistry, level: Option<u8>, feat: &str) {
+        let Some(feat) = self
+            .feat_options(content, level)
+            .into_iter()
+            .find(|f| f.id == feat)
+        else {
+            return;
+        };
+        let choice = FeatChoice {
+            feat: feat.id.clone(),
+            ability: match feat.ability_increase.as_slice() {
+                [ability] => Some(*ability),
+                _ => None,
+            },
+        };
+        match level {
+            None => self.bonus_feat = Some(choice),
+            Some(level) => {
+                self.improvements.insert(level, Improvement::Feat(choice));
+            }
+        }
+    }
+
+    /// Picks the ability increased by the feat taken for the ability score improvement gained at
+    /// `level`, or for the racial traits if `level` is `None`, if the feat can increase it.
+    pub fn choose_feat_ability(
+        &mut self,
+        content: &ContentRegistry,
+        level: Option<u8>,
+        ability: Ability,
+    ) {
+        let choice = match level {
+            None => self.bonus_feat.as_mut(),
+            Some(level) => match self.improvements.get_mut(&level) {
+                Some(Improvement::Feat(choice)) => Some(choice),
+                _ => None,
+            },
+        };
+        if let Some(choice) = choice {
+            if content
+                .feat(&choice.feat)
+                .is_ok_and(|feat| feat.ability_increase.contains(&ability))
+            {
+                choice.ability = Some(ability);
+            }
+        }
+    }
+
+    /// Spends the ability score improvement gained at `level` on ability scores instead of the
+    /// feat taken for it.
+    pub fn clear_feat(&mut self, level: u8) {
+        self.improvements
+            .insert(level, Improvement::Abilities(vec![]));
+    }
+
+    /// Describes what is wrong with a feat the character has taken, if anything: a feat that
+    /// isn't registered, an unmet prerequisite, or an ability increase that hasn't been picked.
+    fn feat_problem(&self, content: &ContentRegistry, choice: &FeatChoice) -> Option<String> {
+        let Ok(feat) = content.feat(&choice.feat) else {
+            return Some(format!("No feat with the id `{}` exists", choice.feat));
+        };
+        if let Some(prerequisite) = self.unmet_prerequisites(content, feat).first() {
+            return Some(format!(
+                "{} requires {}",
+                feat.name,
+                prerequisite.describe(|id| self.race_name(content, id))
+            ));
+        }
+        if !feat.ability_increase.is_empty()
+            && !choice
+                .ability
+                .is_some_and(|ability| feat.ability_increase.contains(&ability))
+        {
+            return Some(format!("Choose the ability {} increases", feat.name));
+        }
+        None
+    }
+
+    /// Returns the display name of the race or subrace with the id `id`.
+    pub fn race_name(&self, content: &ContentRegistry, id: &str) -> String {
+        match content.subrace(id) {
+            Ok(subrace) => subrace.name.clone(),
+            Err(_) => content.name_of(ContentKind::Race, id),
+        }
+    }
+
+    /// Returns every proficiency choice offered by the selected race, subrace, class, background,
+    /// and the classes multiclassed into, including the ones that grant everything without
+    /// requiring a selection.
+    ///
+    /// Selections that aren't registered in `content` are ignored.
+    pub fn proficiency_choices<'a>(
+        &self,
+        content: &'a ContentRegistry,
+    ) -> Vec<ProficiencyChoice<'a>> {
+        let race = self.race.as_ref().and_then(|id| content.race(id).ok());
+        let subrace = self
+            .subrace
+            .as_ref()
+            .and_then(|id| content.subrace(id).ok());
+        let class = self.class.as_ref().and_then(|id| content.class(id).ok());
+        let background = self
+            .background
+            .as_ref()
+            .and_then(|id| content.background(id).ok());
+
+        let mut choices = vec![];
+        let mut offer = |source: String, name: &'a str, list: &'a [_]| {
+            for (i, item) in list.iter().enumerate() {
+                choices.push(ProficiencyChoice {
+                    key: choice_key(&source, "proficiencies", i),
+                    source: name,
+                    choices: item,
+                });
+            }
+        };
+        if let Some(race) = race {
+            offer(format!("race:{}", race.id), &race.name, &race.proficiencies);
+        }
+        if let Some(subrace) = subrace {
+            offer(
+                format!("subrace:{}", subrace.id),
+                &subrace.name,
+                &subrace.proficiencies,
+            );
+        }
+        if let Some(class) = class {
+            offer(
+                format!("class:{}", class.id),
+                &class.name,
+                &class.proficiencies,
+            );
+        }
+        if let Some(background) = background {
+            offer(
+                format!("background:{}", background.id),
+                &background.name,
+                &background.proficiencies,
+            );
+        }
+        for class_levels in self.class_levels(content).into_iter().skip(1) {
+            let class = class_levels.class;
+            offer(
+                format!("multiclass:{}", class.id),
+                &class.name,
+                &class.multiclassing.proficiencies,
+            );
+        }
+        for (feat, _) in self.feats(content) {
+            offer(format!("feat:{}", feat.id), &feat.name, &feat.proficiencies);
+        }
+        choices
+    }
+
+    /// Returns the proficiency choices that still need a selection.
+    pub fn unresolved_choices<'a>(
+        &self,
+        content: &'a ContentRegistry,
+    ) -> Vec<ProficiencyChoice<'a>> {
+        self.proficiency_choices(content)
+            .into_iter()
+            .filter(|choice| {
+                !choice
+                    .choices
+                    .is_resolved(&choice.key, &self.proficiency_selections)
+            })
+            .collect()
+    }
+
+    /// Returns every proficiency granted by the selected race, subrace, class, background, and
+    /// racial traits, along with its source. Choices only contribute the proficiencies selected for
+    /// them.
+    ///
+    /// Selections that aren't registered in `content` are ignored.
+    pub fn proficiency_grants(&self, content: &ContentRegistry) -> Vec<Grant> {
+        let mut grants = vec![];
+        for choice in self.proficiency_choices(content) {
+            for proficiency in choice
+                .choices
+                .resolve(&choice.key, &self.proficiency_selections)
+            {
+                grants.push(Grant {
+                    proficiency,
+                    source: choice.source.into(),
+                });
+            }
+        }
+
+        let race = self.race.as_ref().and_then(|id| content.race(id).ok());
+        let subrace = self
+            .subrace
+            .as_ref()
+            .and_then(|id| content.subrace(id).ok());
+        let traits = race
+            .iter()
+            .flat_map(|race| &race.traits)
+            .chain(subrace.iter().flat_map(|subrace| &subrace.traits));
+        for racial_trait in traits {
+            for effect in &racial_trait.effects {
+                if let Effect::Proficiencies(items) = effect {
+                    for proficiency in items {
+                        grants.push(Grant {
+                            proficiency: proficiency.clone(),
+                            source: racial_trait.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        for (feat, _) in self.feats(content) {
+            for effect in &feat.effects {
+                if let Effect::Proficiencies(items) = effect {
+                    for proficiency in items {
+                        grants.push(Grant {
+                            proficiency: proficiency.clone(),
+                            source: feat.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        grants
+    }
+
+    /// Returns what still needs to be selected before the character is complete.
+    pub fn missing(&self, content: &ContentRegistry) -> Vec<String> {
+        let mut missing = vec![];
+        if self.name.trim().is_empty() {
+            missing.push("Enter a name".to_string());
+        }
+        match self.race.as_ref().map(|id| content.race(id)) {
+            None => missing.push("Select a race".into()),
+            Some(Ok(race)) if !race.subraces.is_empty() && self.subrace.is_none() => {
+                missing.push("Select a subrace".into())
+            }
+            _ => {}
+        }
+        if self.class.is_none() {
+            missing.push("Select a class".into());
+        }
+        for (i, class_levels) in self.class_levels(content).into_iter().enumerate() {
+            let class = class_levels.class;
+            if class_levels.subclass.is_none()
+                && class
+                    .subclasses
+                    .iter()
+                    .any(|s| s.level <= class_levels.level)
+            {
+                missing.push(if i == 0 {
+                    "Select a subclass".into()
+                } else {
+                    format!("Select a subclass for {}", class.name)
+                });
+            }
+        }
+        if self.background.is_none() {
+            missing.push("Select a background".into());
+        }
+        if self.abilities.scores().is_none() {
+            missing.push("Assign every ability score".into());
+        }
+        for choice in self.unresolved_choices(content) {
+            missing.push(format!("Choose a proficiency from {}", choice.source));
+        }
+        let slots = self.expertise_slots(content);
+        if self.expertise(content).len() < slots {
+            missing.push(format!("Choose {slots} proficiencies for expertise"));
+        }
+        let increases = self.racial_increase_count(content);
+        if self.racial_increases(content).len() < increases {
+            missing.push(format!("Choose {increases} abilities to increase"));
+        }
+        if self.has_bonus_feat(content) {
+            match &self.bonus_feat {
+                None => missing.push("Choose a feat".into()),
+                Some(choice) => missing.extend(self.feat_problem(content, choice)),
+            }
+        } else if self
+            .racial_effects(content)
+            .iter()
+            .any(|effect| matches!(effect, Effect::Feat))
+        {
+            missing.push("Select a race that doesn't grant a feat, as feats are disabled".into());
+        }
+        for level in self.improvement_levels(content) {
+            match self.improvements.get(&level) {
+                Some(Improvement::Feat(choice)) if self.campaign.feats => {
+                    missing.extend(self.feat_problem(content, choice))
+                }
+                Some(Improvement::Abilities(abilities))
+                    if abilities.len() == Improvement::POINTS => {}
+                _ => missing.push(format!(
+                    "Choose the ability score improvement of level {level}"
+                )),
+            }
+        }
+        for class_levels in self.class_levels(content) {
+            for feature in class_levels.features() {
+                if self.feature_options(content, feature).len()
+                    < feature.options_up_to(class_levels.level)
+                {
+                    missing.push(format!("Choose an option for {}", feature.name));
+                }
+            }
+        }
+        match self.equipment.wealth {
+            StartingWealth::Equipment => {
+                for source in self.equipment_sources(content) {
+                    if !source
+                        .lines
+                        .iter()
+                        .all(|(key, line)| self.equipment.is_resolved(content, line, key))
+                    {
+                        missing.push(format!(
+                            "Choose the starting equipment from {}",
+                            source.name
+                        ));
+                    }
+                }
+            }
+            StartingWealth::Gold(None) => missing.push("Roll the starting gold".into()),
+            StartingWealth::Gold(Some(_)) => {}
+        }
+        for casting in self.spellcasting(content) {
+            let class = casting.class;
+            for list in casting.lists() {
+                let count = self.spell_count(content, &class.id, list);
+                if self.spell_selection(content, &class.id, list).len() < count {
+                    missing.push(match list {
+                        SpellList::Cantrips => {
+                            format!("Choose {count} cantrips for {}", class.name)
+                        }
+                        SpellList::Spellbook => {
+                            format!("Choose {count} spells for the {} spellbook", class.name)
+                        }
+                        SpellList::Spells
+                            if casting.spellcasting.preparation == Preparation::Known =>
+                        {
+                            format!("Choose {count} spells known for {}", class.name)
+                        }
+                        SpellList::Spells => format!("Prepare {count} spells for {}", class.name),
+                    });
+                }
+            }
+        }
+        missing
+    }
+
+    /// Returns `true` if every selection needed to build the character has been made.
+    pub fn is_complete(&self, content: &ContentRegistry) -> bool {
+        self.missing(content).is_empty()
+    }
+
+    /// Returns the character's proficiencies, with duplicate grants replaced by the picked
+    /// replacements.
+    ///
+    /// Saved replacements are ignored unless the duplicate can be replaced and the replacement
+    /// is of the same kind, such as a skill for a skill.
+    pub fn proficiencies(&self, content: &ContentRegistry) -> Vec<Proficiency> {
+        let grants = self.proficiency_grants(content);
+        let mut proficiencies = proficiencies::unique(&grants);
+        for duplicate in proficiencies::duplicates(&grants) {
+            if !duplicate.replaceable() {
+                continue;
+            }
+            let options = Proficiency::replacements(duplicate.proficiency.kind());
+            for slot in duplicate.slots() {
+                match self.proficiency_replacements.get(&slot) {
+                    Some(replacement)
+                        if options.contains(replacement)
+                            && !proficiencies.contains(replacement) =>
+                    {
+                        proficiencies.push(replacement.clone())
+                    }
+                    _ => {}
+                }
+            }
+        }
+        proficiencies
+    }
+
+    /// Returns the names of the languages the character knows: the ones of its race and
+    /// subrace, followed by the ones it is proficient in.
+    pub fn languages(&self, content: &ContentRegistry) -> Vec<String> {
+        let race = self.race.as_ref().and_then(|id| content.race(id).ok());
+        let subrace = self
+            .subrace
+            .as_ref()
+            .and_then(|id| content.subrace(id).ok());
+        let racial = race
+            .into_iter()
+            .flat_map(|race| &race.languages)
+            .chain(subrace.into_iter().flat_map(|subrace| &subrace.languages))
+            .map(|language| language.name.clone());
+        let proficient =
+            (self.proficiencies(content).into_iter()).filter_map(|proficiency| match proficiency {
+                Proficiency::Language(name) => Some(name),
+                _ => None,
+            });
+
+        let mut languages: Vec<String> = vec![];
+        for language in racial.chain(proficient) {
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+        languages
+    }
+
+    /// Returns the ability scores after the racial increases, ability score improvements, and
+    /// feats are applied, or `None` if not every base score has been assigned.
+    pub fn final_ability_scores(&self, content: &ContentRegistry) -> Option<AbilityScores> {
+        let mut scores = self.abilities.scores()?;
+        let bonuses = self.asi(content);
+        for ability in Ability::ALL {
+            let score = scores.get_mut(ability);
+            *score = (*score + bonuses.get(ability)).min(AbilityScores::MAX_SCORE);
+        }
+        for level in self.improvement_levels(content) {
+            match self.improvements.get(&level) {
+                Some(Improvement::Feat(_)) if !self.campaign.feats => {}
+                Some(improvement) => improvement.apply(&mut scores),
+                None => {}
+            }
+        }
+        if let Some(feat) = self.bonus_feat.as_ref() {
+            if self.has_bonus_feat(content) {
+                feat.apply(&mut scores);
+            }
+        }
+        Some(scores)
+    }
+
+    /// Returns the final modifier of `ability`, or `None` if not every base ability score has
+    /// been assigned.
+    pub fn modifier(&self, content: &ContentRegistry, ability: Ability) -> Option<i8> {
+        Some(self.final_ability_scores(content)?.modifier(ability))
+    }
+
+    /// Returns the proficiency bonus for the character's level.
+    pub fn proficiency_bonus(&self) -> u8 {
+        proficiency_bonus(self.level)
+    }
+
+    /// Returns the id of the class the character took its `level`th level in.
+    pub fn class_at(&self, level: u8) -> Option<&str> {
+        self.multiclasses
+            .iter()
+            .find(|multiclass| multiclass.levels.contains(&level))
+            .map(|multiclass| multiclass.class.as_str())
+            .or(self.class.as_deref())
+    }
+
+    /// Returns the number of levels the character had in the class with the id `class` once it
+    /// reached `level`.
+    pub fn class_level_at(&self, class: &str, level: u8) -> u8 {
+        (1..=level.min(self.level))
+            .filter(|&level| self.class_at(level) == Some(class))
+            .count() as u8
+    }
+
+    /// Returns the number of levels the character has in the class with the id `class`.
+    pub fn class_level(&self, class: &str) -> u8 {
+        self.class_level_at(class, self.level)
+    }
+
+    /// Returns the id of the subclass selected for the class with the id `class`.
+    pub fn subclass_of(&self, class: &str) -> Option<&str> {
+        if self.class.as_deref() == Some(class) {
+            return self.subclass.as_deref();
+        }
+        self.multiclasses
+            .iter()
+            .find(|multiclass| multiclass.class == class)
+            .and_then(|multiclass| multiclass.subclass.as_deref())
+    }
+
+    /// Selects `subclass` for the class with the id `class`. Nothing happens if the character
+    /// has no levels in the class.
+    pub fn set_subclass(&mut self, class: &str, subclass: Option<String>) {
+        if self.class.as_deref() == Some(class) {
+            self.subclass = subclass;
+        } else if let Some(multiclass) = self
+            .multiclasses
+            .iter_mut()
+            .find(|multiclass| multiclass.class == class)
+        {
+            multiclass.subclass = subclass;
+        }
+    }
+
+    /// Returns each of the character's classes with the number of levels it has in them,
+    /// starting with the class it started with.
+    ///
+    /// Classes that aren't registered in `content`, or that the character has no levels in, are
+    /// ignored.
+    pub fn class_levels<'a>(&self, content: &'a ContentRegistry) -> Vec<ClassLevels<'a>> {
+        self.class
+            .iter()
+            .chain(self.multiclasses.iter().map(|multiclass| &multiclass.class))
+            .filter_map(|id| {
+                let level = self.class_level(id);
+                (level > 0).then_some(())?;
+                Some(ClassLevels {
+                    class: content.class(id).ok()?,
+                    subclass: self
+                        .subclass_of(id)
+                        .and_then(|id| content.subclass(id).ok()),
+                    level,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the number of levels the character has in the class that provides `feature`, or
+    /// 0 if none of its classes do.
+    pub fn feature_level(&self, content: &ContentRegistry, feature: &ClassFeature) -> u8 {
+        self.class_levels(content)
+            .into_iter()
+            .find(|class_levels| class_levels.provides(feature))
+            .map_or(0, |class_levels| class_levels.level)
+    }
+
+    /// Checks whether the character can take a level in the class with the id `class`.
+    ///
+    /// A level can always be taken in one of the character's classes. Multiclassing into
+    /// another class requires the ability scores to meet the prerequisites of the new class and
+    /// of every class the character already has.
+    pub fn can_take_level(
+        &self,
+        content: &ContentRegistry,
+        class: &str,
+    ) -> Result<(), MulticlassError> {
+        let new = content
+            .class(class)
+            .map_err(|_| MulticlassError::UnknownClass(class.into()))?;
+        let classes = self.class_levels(content);
+        if classes.is_empty() || classes.iter().any(|c| c.class.id == class) {
+            return Ok(());
+        }
+
+        let scores = self
+            .final_ability_scores(content)
+            .ok_or(MulticlassError::MissingScores)?;
+        for class_levels in classes {
+            let class = class_levels.class;
+            if let Some(prerequisite) = class.multiclassing.unmet(&scores).first() {
+                return Err(MulticlassError::OutOf {
+                    class: class.name.clone(),
+                    prerequisite: (*prerequisite).clone(),
+                });
+            }
+        }
+        if let Some(prerequisite) = new.multiclassing.unmet(&scores).first() {
+            return Err(MulticlassError::Into {
+                class: new.name.clone(),
+                prerequisite: (*prerequisite).clone(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Takes the character's last level in the class with the id `class`, multiclassing into it
+    /// if needed.
+    ///
+    /// The level is taken in the class the character started with unless it is given to one of
+    /// its multiclasses. Fails without changing anything if [`Self::can_take_level`] does.
+    pub fn take_level(
+        &mut self,
+        content: &ContentRegistry,
+        class: &str,
+    ) -> Result<(), MulticlassError> {
+        let level = self.level;
+        for multiclass in &mut self.multiclasses {
+            multiclass.levels.retain(|&l| l != level);
+        }
+        if let Err(err) = self.can_take_level(content, class) {
+            self.drop_unused_multiclasses();
+            return Err(err);
+        }
+
+        if self.class.as_deref() != Some(class) {
+            match self
+                .multiclasses
+                .iter_mut()
+                .find(|multiclass| multiclass.class == class)
+            {
+                Some(multiclass) => multiclass.levels.push(level),
+                None => self.multiclasses.push(Multiclass {
+                    class: class.into(),
+                    subclass: None,
+                    levels: vec![level],
+                }),
+            }
+        }
+        self.drop_unused_multiclasses();
+        Ok(())
+    }
+
+    /// Sets the character's level, forgetting the hit point gains and multiclass levels above
+    /// it.
+    pub fn set_level(&mut self, level: u8) {
+        self.level = level;
+        self.hit_point_gains
+            .truncate(level.saturating_sub(1) as usize);
+        for multiclass in &mut self.multiclasses {
+            multiclass.levels.retain(|&l| l <= level);
+        }
+        self.drop_unused_multiclasses();
+    }
+
+    /// Removes the multiclasses the character has no levels in.
+    fn drop_unused_multiclasses(&mut self) {
+        self.multiclasses
+            .retain(|multiclass| !multiclass.levels.is_empty());
+    }
+
+    /// Returns the features of the character's classes and subclasses gained at or below the
+    /// levels it has in them.
+    pub fn features<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a ClassFeature> {
+        self.class_levels(content)
+            .into_iter()
+            .flat_map(ClassLevels::features)
+            .collect()
+    }
+
+    /// Returns the character levels at which one of its classes or subclasses granted an ability
+    /// score improvement.
+    pub fn improvement_levels(&self, content: &ContentRegistry) -> Vec<u8> {
+        let classes = self.class_levels(content);
+        (1..=self.level)
+            .filter(|&level| {
+                let Some(id) = self.class_at(level) else {
+                    return false;
+                };
+                let class_level = self.class_level_at(id, level);
+                classes
+                    .iter()
+                    .find(|class_levels| class_levels.class.id == id)
+                    .is_some_and(|class_levels| {
+                        class_levels
+                            .features_at(class_level)
+                            .any(|feature| feature.ability_score_improvement)
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the options picked for `feature`, up to the number the level of its class allows.
+    pub fn feature_options<'a>(
+        &self,
+        content: &ContentRegistry,
+        feature: &'a ClassFeature,
+    ) -> Vec<&'a FeatureOption> {
+        let picked = self
+            .feature_options
+            .get(&feature.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        feature
+            .options
+            .iter()
+            .filter(|option| picked.contains(&option.id))
+            .take(feature.options_up_to(self.feature_level(content, feature)))
+            .collect()
+    }
+
+    /// Returns the options of `feature` that can still be picked: the ones not already picked
+    /// for it or for another feature, such as a second Fighting Style.
+    pub fn available_options<'a>(
+        &self,
+        content: &ContentRegistry,
+        feature: &'a ClassFeature,
+    ) -> Vec<&'a FeatureOption> {
+        let features = self.features(content);
+        let taken = features
+            .iter()
+            .flat_map(|f| self.feature_options(content, f))
+            .map(|option| &option.id)
+            .collect::<Vec<_>>();
+        feature
+            .options
+            .iter()
+            .filter(|option| !taken.contains(&&option.id))
+            .collect()
+    }
+
+    /// Picks or unpicks the option with the id `option` for the feature with the id `feature`.
+    ///
+    /// An option is only picked while fewer than the number the level of its class allows are
+    /// picked, and if it isn't already picked for another feature.
+    pub fn toggle_feature_option(
+        &mut self,
+        content: &ContentRegistry,
+        feature: &str,
+        option: &str,
+    ) {
+        let features = self.features(content);
+        let Some(feature) = features.into_iter().find(|f| f.id == feature) else {
+            return;
+        };
+        let mut picked = self
+            .feature_options(content, feature)
+            .iter()
+            .map(|option| option.id.clone())
+            .collect::<Vec<_>>();
+        if let Some(i) = picked.iter().position(|id| id == option) {
+            picked.remove(i);
+        } else if picked.len() < feature.options_up_to(self.feature_level(content, feature))
+            && self
+                .available_options(content, feature)
+                .iter()
+                .any(|o| o.id == option)
+        {
+            picked.push(option.to_string());
+        }
+        self.feature_options.insert(feature.id.clone(), picked);
+    }
+
+    /// Spends a point of the ability score improvement gained at `level` on `ability`, or takes
+    /// one back if `increase` is `false`.
+    ///
+    /// Points can't raise a score above [`AbilityScores::MAX_SCORE`]. A feat taken for the
+    /// improvement is dropped if the campaign doesn't use feats anymore.
+    pub fn change_improvement(
+        &mut self,
+        content: &ContentRegistry,
+        level: u8,
+        ability: Ability,
+        increase: bool,
+    ) {
+        let score = self
+            .final_ability_scores(content)
+            .map(|scores| scores.get(ability))
+            .unwrap_or_default();
+        if !self.campaign.feats
+            && matches!(self.improvements.get(&level), Some(Improvement::Feat(_)))
+        {
+            self.clear_feat(level);
+        }
+        let Improvement::Abilities(abilities) = self
+            .improvements
+            .entry(level)
+            .or_insert_with(|| Improvement::Abilities(vec![]))
+        else {
+            return;
+        };
+        if increase {
+            if abilities.len() < Improvement::POINTS && score < AbilityScores::MAX_SCORE {
+                abilities.push(ability);
+            }
+        } else if let Some(i) = abilities.iter().rposition(|&a| a == ability) {
+            abilities.remove(i);
+        }
+    }
+
+    /// Returns the number of proficiencies the features of the character's classes and
+    /// subclasses grant expertise in at the levels it has in them.
+    pub fn expertise_slots(&self, content: &ContentRegistry) -> usize {
+        self.class_levels(content)
+            .into_iter()
+            .flat_map(|class_levels| {
+                class_levels
+                    .features()
+                    .map(move |feature| feature.expertise_up_to(class_levels.level))
+            })
+            .sum()
+    }
+
+    /// Returns the proficiencies that can be picked for expertise: every skill the character is
+    /// proficient in, along with the tools the class features allow.
+    pub fn expertise_options(&self, content: &ContentRegistry) -> Vec<Proficiency> {
+        let tools = self
+            .features(content)
+            .into_iter()
+            .filter_map(|feature| feature.expertise.as_ref())
+            .flat_map(|expertise| &expertise.tools)
+            .map(|id| Proficiency::Tool(id.clone()))
+            .collect::<Vec<_>>();
+        self.proficiencies(content)
+            .into_iter()
+            .filter(|proficiency| {
+                matches!(proficiency, Proficiency::Skill(_)) || tools.contains(proficiency)
+            })
+            .collect()
+    }
+
+    /// Returns the picked expertise that is still valid, up to the number of slots the features
+    /// grant.
+    pub fn expertise(&self, content: &ContentRegistry) -> Vec<Proficiency> {
+        let options = self.expertise_options(content);
+        self.expertise
+            .iter()
+            .filter(|proficiency| options.contains(proficiency))
+            .take(self.expertise_slots(content))
+            .cloned()
+            .collect()
+    }
+
+    /// Picks or unpicks `proficiency` for expertise. It is only picked while fewer than
+    /// [`Self::expertise_slots`] are.
+    pub fn toggle_expertise(&mut self, content: &ContentRegistry, proficiency: Proficiency) {
+        let mut expertise = self.expertise(content);
+        if let Some(i) = expertise.iter().position(|p| *p == proficiency) {
+            expertise.remove(i);
+        } else if expertise.len() < self.expertise_slots(content) {
+            expertise.push(proficiency);
+        }
+        self.expertise = expertise;
+    }
+
+    /// Returns the character's classes that cast spells at the levels it has in them, either on
+    /// their own or through their subclass, starting with the class it started with.
+    pub fn spellcasting<'a>(&self, content: &'a ContentRegistry) -> Vec<ClassSpellcasting<'a>> {
+        self.class_levels(content)
+            .into_iter()
+            .filter_map(|class_levels| {
+                let spellcasting = class_levels.class.spellcasting.as_ref().or(class_levels
+                    .subclass
+                    .and_then(|subclass| subclass.spellcasting.as_ref()))?;
+                (class_levels.level >= spellcasting.level).then_some(ClassSpellcasting {
+                    class: class_levels.class,
+                    spellcasting,
+                    level: class_levels.level,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the spell slots of the character, combining those of its spellcasting classes.
+    pub fn spell_slots(&self, content: &ContentRegistry) -> SpellSlots {
+        let classes = self
+            .spellcasting(content)
+            .iter()
+            .map(|casting| (casting.spellcasting.progression, casting.level))
+            .collect::<Vec<_>>();
+        SpellSlots::new(&classes)
+    }
+
+    /// Returns the spell slots the character has left until its next rest.
+    pub fn remaining_slots(&self, content: &ContentRegistry) -> SpellSlots {
+        self.expended_slots.remaining(&self.spell_slots(content))
+    }
+
+    /// Expends a spell slot of `level`, returning `false` if none is left.
+    pub fn expend_slot(&mut self, content: &ContentRegistry, level: u8) -> bool {
+        let slots = self.spell_slots(content);
+        self.expended_slots.expend(&slots, level)
+    }
+
+    /// Expends a Pact Magic slot, returning `false` if none is left.
+    pub fn expend_pact_slot(&mut self, content: &ContentRegistry) -> bool {
+        let slots = self.spell_slots(content);
+        self.expended_slots.expend_pact_magic(&slots)
+    }
+
+    /// Returns the spells that can be picked for `list` of the class with the id `class`, sorted
+    /// by level and name.
+    ///
+    /// Cantrips come from the class spell list, as do spells up to the highest level the class
+    /// has slots for. Classes that prepare from a spellbook prepare the spells in it.
+    pub fn spell_options<'a>(
+        &self,
+        content: &'a ContentRegistry,
+        class: &str,
+        list: SpellList,
+    ) -> Vec<&'a Spell> {
+        let Some(casting) = self.class_spellcasting(content, class) else {
+            return vec![];
+        };
+        if list == SpellList::Spells && casting.spellcasting.preparation == Preparation::Spellbook {
+            return self.spell_selection(content, class, SpellList::Spellbook);
+        }
+        let levels = match list {
+            SpellList::Cantrips => 0..=0,
+            SpellList::Spells | SpellList::Spellbook => 1..=casting.max_spell_level(),
+        };
+        let mut spells = content
+            .spells()
+            .filter(|spell| {
+                levels.contains(&spell.level)
+                    && spell.classes.iter().any(|id| id == casting.spell_list())
+            })
+            .collect::<Vec<_>>();
+        spells.sort_by_key(|spell| (spell.level, &spell.name));
+        spells
+    }
+
+    /// Returns the number of spells to pick for `list` of the class with the id `class`, which
+    /// is never more than the spells there are to pick from.
+    pub fn spell_count(&self, content: &ContentRegistry, class: &str, list: SpellList) -> usize {
+        let Some(casting) = self.class_spellcasting(content, class) else {
+            return 0;
+        };
+        let scores = self.final_ability_scores(content);
+        casting
+            .count(list, scores.as_ref())
+            .min(self.spell_options(content, class, list).len())
+    }
+
+    /// Returns the spells picked for `list` of the class with the id `class` that can still be
+    /// picked, up to the number the class allows.
+    pub fn spell_selection<'a>(
+        &self,
+        content: &'a ContentRegistry,
+        class: &str,
+        list: SpellList,
+    ) -> Vec<&'a Spell> {
+        let Some(casting) = self.class_spellcasting(content, class) else {
+            return vec![];
+        };
+        let picked = self
+            .spells
+            .get(class)
+            .map(|selection| selection.list(list))
+            .unwrap_or_default();
+        let scores = self.final_ability_scores(content);
+        self.spell_options(content, class, list)
+            .into_iter()
+            .filter(|spell| picked.contains(&spell.id))
+            .take(casting.count(list, scores.as_ref()))
+            .collect()
+    }
+
+    /// Picks or unpicks the spell with the id `spell` for `list` of the class with the id
+    /// `class`. It is only picked while fewer than [`Self::spell_count`] are.
+    pub fn toggle_spell(
+        &mut self,
+        content: &ContentRegistry,
+        class: &str,
+        list: SpellList,
+        spell: &str,
+    ) {
+        let mut picked = self
+            .spell_selection(content, class, list)
+            .iter()
+            .map(|spell| spell.id.clone())
+            .collect::<Vec<_>>();
+        if let Some(i) = picked.iter().position(|id| id == spell) {
+            picked.remove(i);
+        } else if picked.len() < self.spell_count(content, class, list)
+            && self
+                .spell_options(content, class, list)
+                .iter()
+                .any(|s| s.id == spell)
+        {
+            picked.push(spell.to_string());
+        }
+        *self
+            .spells
+            .entry(class.to_string())
+            .or_default()
+            .list_mut(list) = picked;
+    }
+
+    /// Returns how the class with the id `class` casts spells, if it does at the character's
+    /// level in it.
+    fn class_spellcasting<'a>(
+        &self,
+        content: &'a ContentRegistry,
+        class: &str,
+    ) -> Option<ClassSpellcasting<'a>> {
+        self.spellcasting(content)
+            .into_iter()
+            .find(|casting| casting.class.id == class)
+    }
+
+    /// Returns what the modifiers of the character's skill checks and saving throws are computed
+    /// from, or `None` if not every base ability score has been assigned.
+    pub fn checks(&self, content: &ContentRegistry) -> Option<Checks> {
+        let effects = self.effects(content);
+        Some(Checks {
+            scores: self.final_ability_scores(content)?,
+            proficiency_bonus: self.proficiency_bonus(),
+            proficiencies: self.proficiencies(content),
+            expertise: self.expertise(content),
+            situational: Checks::situational(effects),
+        })
+    }
+
+    /// Returns the hit points gained at each of the character's levels, or `None` if no class is
+    /// selected or not every base ability score has been assigned.
+    pub fn hit_point_levels(&self, content: &ContentRegistry) -> Option<Vec<LevelHitPoints>> {
+        let hit_dice = (1..=self.level.max(1))
+            .map(|level| {
+                let class = content.class(self.class_at(level)?).ok()?;
+                Some(*class.hit_points.hit_dice())
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let scores = self.final_ability_scores(content)?;
+
+        let bonus = self
+            .effects(content)
+            .into_iter()
+            .map(|effect| match effect {
+                Effect::HitPointsPerLevel(amount) => *amount as u16,
+                _ => 0,
+            })
+            .sum();
+
+        Some(hit_points::levels(
+            &hit_dice,
+            scores.modifier(Ability::Constitution),
+            bonus,
+            &self.hit_point_gains,
+        ))
+    }
+
+    /// Returns the character's hit dice: the number of dice of each size its classes grant, from
+    /// the largest die to the smallest.
+    pub fn hit_dice(&self, content: &ContentRegistry) -> Vec<(u8, Die)> {
+        let mut hit_dice: Vec<(u8, Die)> = vec![];
+        for class_levels in self.class_levels(content) {
+            let die = *class_levels.class.hit_points.hit_dice();
+            match hit_dice
+                .iter_mut()
+                .find(|(_, d)| d.num_sides == die.num_sides)
+            {
+                Some((count, _)) => *count += class_levels.level,
+                None => hit_dice.push((class_levels.level, die)),
+            }
+        }
+        hit_dice.sort_by_key(|(_, die)| std::cmp::Reverse(die.num_sides));
+        hit_dice
+    }
+
+    /// Returns the maximum hit points of the character, or `None` if they can't be computed yet.
+    pub fn max_hit_points(&self, content: &ContentRegistry) -> Option<u16> {
+        let levels = self.hit_point_levels(content)?;
+        Some(levels.iter().map(LevelHitPoints::total).sum())
+    }
+
+    /// Returns the current hit points of the character, never more than the maximum, or `None`
+    /// if the maximum can't be computed yet.
+    pub fn current_hit_points(&self, content: &ContentRegistry) -> Option<u16> {
+        let max = self.max_hit_points(content)?;
+        Some(
+            self.hit_points
+                .map_or(max, |hit_points| hit_points.min(max)),
+        )
+    }
+
+    /// Sets the current hit points of the character, up to the maximum. Nothing happens if the
+    /// maximum can't be computed yet.
+    pub fn set_current_hit_points(&mut self, content: &ContentRegistry, hit_points: u16) {
+        if let Some(max) = self.max_hit_points(content) {
+            self.hit_points = (hit_points < max).then_some(hit_points);
+        }
+    }
+
+    /// Takes a short rest, regaining the Pact Magic slots.
+    pub fn short_rest(&mut self) {
+        self.expended_slots.short_rest();
+    }
+
+    /// Takes a long rest, regaining every hit point and spell slot.
+    pub fn long_rest(&mut self) {
+        self.hit_points = None;
+        self.expended_slots.long_rest();
+    }
+
+    /// Picks how the hit die is resolved for `level`, which must be after the first.
+    pub fn set_hit_point_gain(&mut self, level: u8, gain: HitPointGain) {
+        let Some(index) = (level as usize).checked_sub(2) else {
+            return;
+        };
+        if self.hit_point_gains.len() <= index {
+            self.hit_point_gains
+                .resize(index + 1, HitPointGain::Average);
+        }
+        self.hit_point_gains[index] = gain;
+    }
+
+    /// Rolls the hit die of the class `level` was taken in. Nothing happens if no class is
+    /// selected.
+    pub fn roll_hit_points(&mut self, content: &ContentRegistry, level: u8, roller: &mut Roller) {
+        let Some(class) = self.class_at(level).and_then(|id| content.class(id).ok()) else {
+            return;
+        };
+        let gain = HitPointGain::roll(class.hit_points.hit_dice(), roller);
+        self.set_hit_point_gain(level, gain);
+    }
+
+    /// Returns the starting equipment offered by the class the character started with and by
+    /// its background.
+    ///
+    /// Classes taken by multiclassing don't grant starting equipment.
+    pub fn equipment_sources<'a>(&self, content: &'a ContentRegistry) -> Vec<EquipmentSource<'a>> {
+        let mut sources = vec![];
+        if let Some(class) = self.class.as_ref().and_then(|id| content.class(id).ok()) {
+            let source = format!("class:{}", class.id);
+            sources.push(EquipmentSource {
+                name: &class.name,
+                lines: (class.starting_equipment.iter().enumerate())
+                    .map(|(i, line)| (choice_key(&source, "starting_equipment", i), line))
+                    .collect(),
+                gold: 0,
+            });
+        }
+        if let Some(background) =
+            (self.background.as_ref()).and_then(|id| content.background(id).ok())
+        {
+            let source = format!("background:{}", background.id);
+            sources.push(EquipmentSource {
+                name: &background.name,
+                lines: (background.equipment.iter().enumerate())
+                    .map(|(i, line)| (choice_key(&source, "equipment", i), line))
+                    .collect(),
+                gold: background.gold,
+            });
+        }
+        sources
+    }
+
+    /// Returns the items the character starts with, with packs replaced by their contents.
+    ///
+    /// Items that haven't been picked yet are left out, and no items are granted if the
+    /// starting gold is taken instead.
+    pub fn starting_items(&self, content: &ContentRegistry) -> Vec<ItemStack> {
+        if self.equipment.wealth != StartingWealth::Equipment {
+            return vec![];
+        }
+        let mut items = vec![];
+        for source in self.equipment_sources(content) {
+            for (key, line) in source.lines {
+                for stack in self.equipment.resolve(content, line, &key) {
+                    equipment::add_stack(content, &mut items, stack);
+                }
+            }
+        }
+        items
+    }
+
+    /// Returns the gold pieces the character starts with, either granted along with the
+    /// starting equipment or rolled instead of it.
+    pub fn starting_gold(&self, content: &ContentRegistry) -> u32 {
+        match self.equipment.wealth {
+            StartingWealth::Equipment => self
+                .equipment_sources(content)
+                .iter()
+                .map(|source| source.gold)
+                .sum(),
+            StartingWealth::Gold(gold) => gold.unwrap_or_default(),
+        }
+    }
+
+    /// Takes the starting gold of the class instead of the starting equipment if `gold` is
+    /// `true`, or the starting equipment otherwise.
+    ///
+    /// The starting gold still has to be rolled. Nothing happens if the class the character
+    /// started with has no starting gold.
+    pub fn set_starting_wealth(&mut self, content: &ContentRegistry, gold: bool) {
+        let class = self.class.as_ref().and_then(|id| content.class(id).ok());
+        match (gold, &self.equipment.wealth) {
+            (false, _) => self.equipment.wealth = StartingWealth::Equipment,
+            (true, StartingWealth::Equipment)
+                if class.is_some_and(|c| c.starting_gold.is_some()) =>
+            {
+                self.equipment.wealth = StartingWealth::Gold(None)
+            }
+            (true, _) => {}
+        }
+    }
+
+    /// Rolls the starting gold of the class the character started with, replacing any earlier
+    /// roll. Nothing happens if the starting gold isn't taken.
+    pub fn roll_starting_gold(&mut self, content: &ContentRegistry, roller: &mut Roller) {
+        if self.equipment.wealth == StartingWealth::Equipment {
+            return;
+        }
+        let Some(dice) = (self.class.as_ref())
+            .and_then(|id| content.class(id).ok())
+            .and_then(|class| class.starting_gold.as_ref())
+        else {
+            return;
+        };
+        if let Ok(wealth) = StartingWealth::roll(dice, roller) {
+            self.equipment.wealth = wealth;
+        }
+    }
+
+    /// Returns the inventory the character starts with: its starting equipment, with the
+    /// contents of packs carried in their container and its weapons, armor, and spellcasting
+    /// foci equipped.
+    pub fn starting_inventory(&self, content: &ContentRegistry) -> Inventory {
+        let mut inventory = Inventory::default();
+        if self.equipment.wealth != StartingWealth::Equipment {
+            return inventory;
+        }
+        for source in self.equipment_sources(content) {
+            for (key, line) in source.lines {
+                for stack in self.equipment.resolve(content, line, &key) {
+                    inventory.add_stack(content, &stack);
+                }
+            }
+        }
+        inventory
+    }
+
+    /// Returns the items the character carries, which are its starting inventory until the
+    /// inventory is edited.
+    pub fn inventory(&self, content: &ContentRegistry) -> Cow<'_, Inventory> {
+        match &self.inventory {
+            Some(inventory) => Cow::Borrowed(inventory),
+            None => Cow::Owned(self.starting_inventory(content)),
+        }
+    }
+
+    /// Returns the items the character carries for editing, starting from its starting
+    /// inventory if it hasn't been edited yet.
+    pub fn inventory_mut(&mut self, content: &ContentRegistry) -> &mut Inventory {
+        if self.inventory.is_none() {
+            self.inventory = Some(self.starting_inventory(content));
+        }
+        self.inventory.get_or_insert_with(Inventory::default)
+    }
+
+    /// Returns the purse the character starts with, holding its starting gold.
+    pub fn starting_wealth(&self, content: &ContentRegistry) -> Wealth {
+        Wealth::starting(self.starting_gold(content), "Starting gold")
+    }
+
+    /// Returns the coins the character carries, which are its starting gold until it receives
+    /// or spends any.
+    pub fn wealth(&self, content: &ContentRegistry) -> Cow<'_, Wealth> {
+        match &self.wealth {
+            Some(wealth) => Cow::Borrowed(wealth),
+            None => Cow::Owned(self.starting_wealth(content)),
+        }
+    }
+
+    /// Returns the coins the character carries for editing, starting from its starting gold
+    /// if it hasn't received or spent any yet.
+    pub fn wealth_mut(&mut self, content: &ContentRegistry) -> &mut Wealth {
+        if self.wealth.is_none() {
+            self.wealth = Some(self.starting_wealth(content));
+        }
+        self.wealth.get_or_insert_with(Wealth::default)
+    }
+
+    /// Buys a bundle of the item with the id `item`, such as 20 arrows, paying for it out of
+    /// the purse and adding it to the inventory.
+    pub fn buy(&mut self, content: &ContentRegistry, item: &str) -> Result<(), CurrencyError> {
+        let Ok(item) = content.item(item) else {
+            return Err(CurrencyError::NotForSale);
+        };
+        let price = item.cost.in_copper();
+        if price == 0 {
+            return Err(CurrencyError::NotForSale);
+        }
+        let description = match item.bundle {
+            1 => format!("Bought {}", item.name),
+            bundle => format!("Bought {} × {bundle}", item.name),
+        };
+        self.wealth_mut(content).pay(price, &description)?;
+        self.inventory_mut(content).add(&item.id, item.bundle, None);
+        Ok(())
+    }
+
+    /// Returns the weight in pounds of everything the character carries, coins included.
+    pub fn carried_weight(&self, content: &ContentRegistry) -> f32 {
+        self.inventory(content).carried_weight(content) + self.wealth(content).purse.weight()
+    }
+
+    /// Returns the size category of the selected race, or Medium if no race is selected.
+    fn size(&self, content: &ContentRegistry) -> SizeCategory {
+        (self.race.as_ref())
+            .and_then(|id| content.race(id).ok())
+            .map_or(SizeCategory::Medium, |race| race.size.category.clone())
+    }
+
+    /// Returns the weight in pounds the character can carry, or `None` if not every base
+    /// ability score has been assigned.
+    pub fn carrying_capacity(&self, content: &ContentRegistry) -> Option<f32> {
+        let strength = self.final_ability_scores(content)?.get(Ability::Strength);
+        Some(inventory::carrying_capacity(strength, &self.size(content)))
+    }
+
+    /// Returns how much the weight the character carries slows it down under the encumbrance
+    /// rule of the campaign, or `None` if not every base ability score has been assigned.
+    pub fn encumbrance(&self, content: &ContentRegistry) -> Option<Encumbrance> {
+        let strength = self.final_ability_scores(content)?.get(Ability::Strength);
+        Some(Encumbrance::of(
+            self.campaign.encumbrance,
+            self.carried_weight(content),
+            strength,
+            &self.size(content),
+        ))
+    }
+
+    /// Returns warnings about the items the character carries, such as being attuned to too
+    /// many of them or containers holding more than they can.
+    pub fn inventory_warnings(&self, content: &ContentRegistry) -> Vec<String> {
+        let inventory = self.inventory(content);
+        let mut warnings = vec![];
+        if inventory.attuned() > MAX_ATTUNED {
+            warnings.push(format!(
+                "You are attuned to {} items, but can only be attuned to {MAX_ATTUNED} at once.",
+                inventory.attuned()
+            ));
+        }
+        for entry in &inventory.entries {
+            let Ok(item) = content.item(&entry.item) else {
+                continue;
+            };
+            if let ItemKind::Container(container) = &item.kind {
+                let weight = inventory.contents_weight(content, entry.id);
+                if weight > container.capacity * entry.quantity as f32 {
+                    warnings.push(format!(
+                        "The contents of your {} weigh {}, more than the {} it can hold.",
+                        item.name.to_lowercase(),
+                        item::pounds(weight),
+                        item::pounds(container.capacity * entry.quantity as f32),
+                    ));
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Returns the items the character has equipped that are armor of a category in
+    /// `categories`, sorted by name.
+    fn owned_armor<'a>(
+        &self,
+        content: &'a ContentRegistry,
+        categories: &[ArmorProficiency],
+    ) -> Vec<(&'a Item, &'a Armor)> {
+        let mut armor = self
+            .inventory(content)
+            .entries
+            .iter()
+            .filter(|entry| entry.equipped)
+            .filter_map(|entry| content.item(&entry.item).ok())
+            .filter_map(|item| match &item.kind {
+                ItemKind::Armor(armor) if categories.contains(&armor.category) => {
+                    Some((item, armor))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        armor.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+        armor
+    }
+
+    /// Returns the suits of armor the character has equipped and can wear.
+    pub fn armor_options<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a Item> {
+        (self.owned_armor(content, &ARMOR).into_iter())
+            .map(|(item, _)| item)
+            .collect()
+    }
+
+    /// Returns the shields the character has equipped and can wield.
+    pub fn shield_options<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a Item> {
+        (self
+            .owned_armor(content, &[ArmorProficiency::Shields])
+            .into_iter())
+        .map(|(item, _)| item)
+        .collect()
+    }
+
+    /// Returns the suit of armor the character wears, if it still owns it.
+    pub fn worn_armor<'a>(&self, content: &'a ContentRegistry) -> Option<(&'a Item, &'a Armor)> {
+        let id = self.worn.armor.as_ref()?;
+        (self.owned_armor(content, &ARMOR).into_iter()).find(|(item, _)| &item.id == id)
+    }
+
+    /// Returns the shield the character wields, if it still owns it.
+    pub fn worn_shield<'a>(&self, content: &'a ContentRegistry) -> Option<(&'a Item, &'a Armor)> {
+        let id = self.worn.shield.as_ref()?;
+        (self
+            .owned_armor(content, &[ArmorProficiency::Shields])
+            .into_iter())
+        .find(|(item, _)| &item.id == id)
+    }
+
+    /// Returns `true` if the character knows or has prepared the Mage Armor spell, or has it in
+    /// its spellbook.
+    pub fn knows_mage_armor(&self) -> bool {
+        self.spells.values().any(|selection| {
+            (selection.spells.iter())
+                .chain(&selection.spellbook)
+                .any(|spell| spell == MAGE_ARMOR_SPELL)
+        })
+    }
+
+    /// Returns every way of computing the Armor Class that applies to the character, or `None`
+    /// if not every base ability score has been assigned.
+    ///
+    /// Besides the worn armor, this includes the formulas granted by racial traits, class
+    /// features, and feats, and Mage Armor while it is active.
+    pub fn armor_class_options(&self, content: &ContentRegistry) -> Option<Vec<ArmorClassOption>> {
+        let scores = self.final_ability_scores(content)?;
+        let race = self.race.as_ref().and_then(|id| content.race(id).ok());
+        let subrace = (self.subrace.as_ref()).and_then(|id| content.subrace(id).ok());
+        let traits = (race.into_iter().flat_map(|race| &race.traits))
+            .chain(subrace.into_iter().flat_map(|subrace| &subrace.traits))
+            .flat_map(|racial_trait| {
+                (racial_trait.effects.iter()).map(|e| (&racial_trait.name, e))
+            });
+        let features = self.features(content).into_iter();
+        let features =
+            features.flat_map(|feature| feature.effects.iter().map(|e| (&feature.name, e)));
+        let feats = self.feats(content).into_iter();
+        let feats = feats.flat_map(|(feat, _)| feat.effects.iter().map(|e| (&feat.name, e)));
+
+        let mut formulas = traits
+            .chain(features)
+            .chain(feats)
+            .filter_map(|(source, effect)| match effect {
+                Effect::ArmorClass(formula) => Some((source.as_str(), formula)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if self.worn.mage_armor && self.knows_mage_armor() {
+            formulas.push(("Mage Armor", &MAGE_ARMOR));
+        }
+
+        let armor = self.worn_armor(content);
+        let shield = self.worn_shield(content);
+        let mut options = armor_class::options(
+            &scores,
+            armor.map(|(item, armor)| (item.name.as_str(), armor)),
+            shield.map(|(_, shield)| shield),
+            &formulas,
+        );
+        if armor.is_some() && self.fighting_styles(content).contains(&attacks::DEFENSE) {
+            for option in &mut options {
+                option.bonuses.push(("Defense".to_string(), 1));
+            }
+        }
+        Some(options)
+    }
+
+    /// Returns the way of computing the Armor Class that gives the highest one, or `None` if not
+    /// every base ability score has been assigned.
+    pub fn armor_class(&self, content: &ContentRegistry) -> Option<ArmorClassOption> {
+        let options = self.armor_class_options(content)?;
+        armor_class::best(&options).cloned()
+    }
+
+    /// Returns warnings about the armor and shield the character wears: the ones it isn't
+    /// proficient with, and armor it doesn't have the Strength for.
+    pub fn armor_warnings(&self, content: &ContentRegistry) -> Vec<String> {
+        let proficiencies = self.proficiencies(content);
+        let worn = self.worn_armor(content).into_iter();
+        let mut warnings = worn
+            .clone()
+            .chain(self.worn_shield(content))
+            .filter(|(_, armor)| !proficiencies.contains(&Proficiency::Armor(armor.category)))
+            .map(|(item, armor)| armor_class::non_proficiency_warning(&item.name, armor.category))
+            .collect::<Vec<_>>();
+
+        let Some(scores) = self.final_ability_scores(content) else {
+            return warnings;
+        };
+        for (item, armor) in worn {
+            if !armor_class::lacks_strength(&scores, armor) {
+                continue;
+            }
+            let strength = armor.strength.unwrap_or_default();
+            warnings.push(if self.ignores_heavy_armor_speed_penalty(content) {
+                format!(
+                    "{} requires Strength {strength}, but your speed isn't reduced by wearing \
+                     heavy armor.",
+                    item.name
+                )
+            } else {
+                format!(
+                    "{} requires Strength {strength}: your speed is reduced by {} feet.",
+                    item.name,
+                    armor_class::STRENGTH_SPEED_PENALTY
+                )
+            });
+        }
+        warnings
+    }
+
+    /// Returns the damage types the character is resistant to.
+    pub fn resistances(&self, content: &ContentRegistry) -> Vec<DamageType> {
+        let mut resistances = (self.effects(content).into_iter())
+            .filter_map(|effect| match effect {
+                Effect::Resistance(damage_type) => Some(*damage_type),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        resistances.sort();
+        resistances.dedup();
+        resistances
+    }
+
+    /// Returns the tags of the effects the character has advantage on saving throws against,
+    /// such as `poison`.
+    pub fn save_advantages<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a str> {
+        let mut tags = (self.effects(content).into_iter())
+            .filter_map(|effect| match effect {
+                Effect::SaveAdvantage(tag) => Some(tag.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
+    /// Returns the special senses of the character, keeping the longest range of each kind.
+    pub fn senses(&self, content: &ContentRegistry) -> Vec<Sense> {
+        let mut senses: Vec<Sense> = vec![];
+        for effect in self.effects(content) {
+            if let Effect::Sense(sense) = effect {
+                match senses.iter_mut().find(|s| s.kind == sense.kind) {
+                    Some(existing) => existing.range = existing.range.max(sense.range),
+                    None => senses.push(*sense),
+                }
+            }
+        }
+        senses.sort_by_key(|sense| sense.kind);
+        senses
+    }
+
+    /// Returns `true` if the character's speed isn't reduced by wearing heavy armor.
+    pub fn ignores_heavy_armor_speed_penalty(&self, content: &ContentRegistry) -> bool {
+        self.effects(content)
+            .into_iter()
+            .any(|effect| matches!(effect, Effect::IgnoreHeavyArmorSpeedPenalty))
+    }
+
+    /// Returns the speeds of the character, with trait bonuses applied, the walking speed
+    /// reduced if it wears armor it doesn't have the Strength for, and every speed reduced by
+    /// the weight it carries.
+    ///
+    /// Nothing is returned if no race is selected.
+    pub fn speeds(&self, content: &ContentRegistry) -> Vec<Speed> {
+        let Some(race) = self.race.as_ref().and_then(|id| content.race(id).ok()) else {
+            return vec![];
+        };
+        let mut speeds = race.speed.clone();
+        for effect in self.effects(content) {
+            if let Effect::SpeedBonus(bonus) = effect {
+                match speeds.iter_mut().find(|speed| speed.same_kind(bonus)) {
+                    Some(speed) => *speed.amount_mut() += bonus.amount(),
+                    None => speeds.push(bonus.clone()),
+                }
+            }
+        }
+
+        let slowed = self.worn_armor(content).is_some_and(|(_, armor)| {
+            self.final_ability_scores(content)
+                .is_some_and(|scores| armor_class::lacks_strength(&scores, armor))
+        });
+        if slowed && !self.ignores_heavy_armor_speed_penalty(content) {
+            for speed in &mut speeds {
+                if let Speed::Walking(amount) = speed {
+                    *amount = amount.saturating_sub(armor_class::STRENGTH_SPEED_PENALTY);
+                }
+            }
+        }
+        if let Some(encumbrance) = self.encumbrance(content) {
+            for speed in &mut speeds {
+                *speed.amount_mut() = encumbrance.apply(speed.amount());
+            }
+        }
+        speeds
+    }
+
+    /// Returns the ids of the options picked for the character's features that are fighting
+    /// styles, such as `archery`.
+    pub fn fighting_styles<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a str> {
+        const STYLES: [&str; 5] = [
+            attacks::ARCHERY,
+            attacks::DEFENSE,
+            attacks::DUELING,
+            attacks::GREAT_WEAPON_FIGHTING,
+            attacks::TWO_WEAPON_FIGHTING,
+        ];
+        self.features(content)
+            .into_iter()
+            .flat_map(|feature| self.feature_options(content, feature))
+            .map(|option| option.id.as_str())
+            .filter(|id| STYLES.contains(id))
+            .collect()
+    }
+
+    /// Returns the weapons the character has equipped, along with how many of each, sorted by
+    /// name.
+    pub fn weapon_options<'a>(&self, content: &'a ContentRegistry) -> Vec<(&'a Item, u32)> {
+        let mut weapons: Vec<(&Item, u32)> = vec![];
+        for entry in self.inventory(content).entries.iter() {
+            let Ok(item) = content.item(&entry.item) else {
+                continue;
+            };
+            if !entry.equipped || !matches!(item.kind, ItemKind::Weapon(_)) {
+                continue;
+            }
+            match weapons.iter_mut().find(|(weapon, _)| weapon.id == item.id) {
+                Some((_, quantity)) => *quantity += entry.quantity,
+                None => weapons.push((item, entry.quantity)),
+            }
+        }
+        weapons.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+        weapons
+    }
+
+    /// Returns the weapons the character wields, along with their index in [`Self::wielded`],
+    /// leaving out the ones it no longer owns enough of.
+    pub fn wielded_weapons<'a>(
+        &'a self,
+        content: &'a ContentRegistry,
+    ) -> Vec<(usize, &'a Item, &'a Weapon, &'a WieldedWeapon)> {
+        let owned = self.weapon_options(content);
+        let mut wielded: Vec<(usize, &Item, &Weapon, &WieldedWeapon)> = vec![];
+        for (i, weapon) in self.wielded.iter().enumerate() {
+            let Some((item, quantity)) = owned.iter().find(|(item, _)| item.id == weapon.item)
+            else {
+                continue;
+            };
+            let ItemKind::Weapon(stats) = &item.kind else {
+                continue;
+            };
+            let held = wielded
+                .iter()
+                .filter(|(_, other, ..)| other.id == item.id)
+                .count();
+            if (held as u32) < *quantity {
+                wielded.push((i, item, stats, weapon));
+            }
+        }
+        wielded
+    }
+
+    /// Wields another one of the weapon with the id `item`, if the character owns more of it
+    /// than it already wields.
+    pub fn wield(&mut self, content: &ContentRegistry, item: &str) {
+        let owned = self
+            .weapon_options(content)
+            .iter()
+            .find(|(weapon, _)| weapon.id == item)
+            .map_or(0, |(_, quantity)| *quantity);
+        let held = self.wielded.iter().filter(|w| w.item == item).count();
+        if (held as u32) < owned {
+            self.wielded.push(WieldedWeapon::new(item.to_string()));
+        }
+    }
+
+    /// Returns `true` if the character is proficient with `weapon`, the statistics of `item`.
+    pub fn is_proficient_with(
+        &self,
+        content: &ContentRegistry,
+        item: &Item,
+        weapon: &Weapon,
+    ) -> bool {
+        let category = match weapon.category {
+            WeaponCategory::Simple => WeaponProficiency::Simple,
+            WeaponCategory::Martial => WeaponProficiency::Martial,
+        };
+        let proficiencies = self.proficiencies(content);
+        [category, WeaponProficiency::Specific(item.id.clone())]
+            .into_iter()
+            .any(|weapon| proficiencies.contains(&Proficiency::Weapon(weapon)))
+    }
+
+    /// Returns the attacks with the weapons the character wields, along with their index in
+    /// [`Self::wielded`], or `None` if not every base ability score has been assigned.
+    ///
+    /// Weapons that deal no damage, such as a net, are left out.
+    pub fn attacks(&self, content: &ContentRegistry) -> Option<Vec<(usize, Attack)>> {
+        let wielded = self.wielded_weapons(content);
+        let size = self.size(content);
+        let inventory = self.inventory(content);
+        let attacker = Attacker {
+            scores: self.final_ability_scores(content)?,
+            proficiency_bonus: self.proficiency_bonus(),
+            fighting_styles: self.fighting_styles(content),
+            size,
+            weapons: wielded.len(),
+            shield: self.worn_shield(content).is_some(),
+        };
+        Some(
+            wielded
+                .into_iter()
+                .filter_map(|(i, item, weapon, wielded)| {
+                    let proficient = self.is_proficient_with(content, item, weapon);
+                    let mut attack = attacks::attack(item, weapon, wielded, proficient, &attacker)?;
+                    if let Some(ammunition) = &weapon.ammunition {
+                        let name = content.name_of(ContentKind::Item, ammunition);
+                        let count = inventory.count(ammunition);
+                        attack.notes.push(format!("{count} × {name} left"));
+                    }
+                    Some((i, attack))
+                })
+                .collect(),
+        )
+    }
+
+    /// Rolls on one of the personality tables of the selected background, replacing the entries
+    /// picked from it.
+    ///
+    /// Tables that more than one entry is picked from are rolled on until that many different
+    /// entries come up. Entries listed more than once in a table are rolled as one, so that a
+    /// table with fewer different entries than are picked from it can't be rolled on forever.
+    /// Nothing happens if no background is selected.
+    pub fn roll_personality(
+        &mut self,
+        content: &ContentRegistry,
+        table: PersonalityTable,
+        roller: &mut Roller,
+    ) {
+        let Some(background) = self
+            .background
+            .as_ref()
+            .and_then(|id| content.background(id).ok())
+        else {
+            return;
+        };
+        let mut entries: Vec<&String> = vec![];
+        for entry in background.table(table) {
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+        let count = table.count().min(entries.len());
+
+        let mut rolled: Vec<String> = vec![];
+        while rolled.len() < count {
+            let entry = entries[roller.roll_die(entries.len() as u32) as usize - 1];
+            if !rolled.contains(entry) {
+                rolled.push(entry.clone());
+            }
+        }
+        self.personality.set(table, rolled);
+    }
+}
+
+/// Returns the proficiency bonus for a character of the given level.
+pub fn proficiency_bonus(level: u8) -> u8 {
+    2 + (level.max(1) - 1) / 4
+}
@@ -0,0 +1,1095 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::frontend::{
+    background::Background,
+    class::{AbilityPrerequisite, Class, ClassFeature, Spellcasting, Subclass},
+    feat::{Feat, FeatPrerequisite},
+    item::{EquipmentItem, Item, ItemCategory, ItemKind, StartingEquipment, WeaponProperty},
+    race::{Race, RacialTrait, Subrace},
+    spell::Spell,
+    utils::{
+        proficiency::{self, Proficiency},
+        Choices, Effect,
+    },
+};
+
+use super::{abilities::AbilityScores, character::MAX_LEVEL, dice::Expression};
+
+/// Environment variable that overrides the directory built-in content is loaded from.
+pub const CONTENT_DIR_ENV: &str = "DREGG_CONTENT_DIR";
+
+/// The kinds of content that can be registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Race,
+    Subrace,
+    Class,
+    Subclass,
+    Background,
+    Trait,
+    Spell,
+    Feat,
+    Item,
+}
+
+impl Display for ContentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentKind::Race => f.write_str("race"),
+            ContentKind::Subrace => f.write_str("subrace"),
+            ContentKind::Class => f.write_str("class"),
+            ContentKind::Subclass => f.write_str("subclass"),
+            ContentKind::Background => f.write_str("background"),
+            ContentKind::Trait => f.write_str("trait"),
+            ContentKind::Spell => f.write_str("spell"),
+            ContentKind::Feat => f.write_str("feat"),
+            ContentKind::Item => f.write_str("item"),
+        }
+    }
+}
+
+/// A reference to a registered piece of content, used to list it in the UI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentRef {
+    /// The id of the content.
+    pub id: String,
+
+    /// The display name of the content.
+    pub name: String,
+}
+
+impl Display for ContentRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Errors that can occur while loading or looking up content.
+#[derive(Debug)]
+pub enum ContentError {
+    /// Reading a file or directory failed.
+    Io { path: PathBuf, source: io::Error },
+
+    /// A file could not be deserialized.
+    Parse {
+        /// The file that failed to parse.
+        path: PathBuf,
+
+        /// The path to the field that failed to parse (e.g. `subraces[0].asi`).
+        field: String,
+
+        /// The error from the parser.
+        source: Box<ron::error::SpannedError>,
+    },
+
+    /// A field has a value that isn't allowed.
+    Invalid {
+        path: PathBuf,
+        field: String,
+        reason: String,
+    },
+
+    /// Two entries of the same kind share an id.
+    Duplicate {
+        path: PathBuf,
+        kind: ContentKind,
+        id: String,
+    },
+
+    /// No content of the given kind is registered with the id.
+    NotFound { kind: ContentKind, id: String },
+}
+
+impl Display for ContentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentError::Io { path, source } => {
+                f.write_fmt(format_args!("{}: {}", path.display(), source))
+            }
+            ContentError::Parse {
+                path,
+                field,
+                source,
+            } => f.write_fmt(format_args!(
+                "{}:{}:{}: in `{}`: {}",
+                path.display(),
+                source.position.line,
+                source.position.col,
+                field,
+                source.code
+            )),
+            ContentError::Invalid {
+                path,
+                field,
+                reason,
+            } => f.write_fmt(format_args!(
+                "{}: in `{}`: {}",
+                path.display(),
+                field,
+                reason
+            )),
+            ContentError::Duplicate { path, kind, id } => f.write_fmt(format_args!(
+                "{}: {} `{}` is already defined",
+                path.display(),
+                kind,
+                id
+            )),
+            ContentError::NotFound { kind, id } => {
+                f.write_fmt(format_args!("No {} with the id `{}` exists", kind, id))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContentError::Io { source, .. } => Some(source),
+            ContentError::Parse { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Maps stable string ids to the content that characters are built from.
+#[derive(Debug, Default)]
+pub struct ContentRegistry {
+    races: BTreeMap<String, Race>,
+    subraces: BTreeMap<String, Subrace>,
+    classes: BTreeMap<String, Class>,
+    subclasses: BTreeMap<String, Subclass>,
+    backgrounds: BTreeMap<String, Background>,
+    traits: BTreeMap<String, RacialTrait>,
+    spells: BTreeMap<String, Spell>,
+    feats: BTreeMap<String, Feat>,
+    items: BTreeMap<String, Item>,
+}
+
+impl ContentRegistry {
+    /// Loads the built-in content along with any content in the user's data directory.
+    ///
+    /// Files that fail to load are skipped and returned as errors, so that one bad file doesn't
+    /// prevent the rest of the content from loading.
+    pub fn load() -> (Self, Vec<ContentError>) {
+        let mut registry = Self::default();
+        let mut errors = vec![];
+        for dir in content_dirs(&mut errors) {
+            registry.load_dir(&dir, &mut errors);
+        }
+        (registry, errors)
+    }
+
+    /// Loads only the built-in content, so that tests don't depend on the user's content.
+    #[cfg(test)]
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        let mut errors = vec![];
+        registry.load_dir(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("content"),
+            &mut errors,
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        registry
+    }
+
+    /// Loads all content in `dir`, adding it to the registry.
+    ///
+    /// Items are loaded first, as classes and backgrounds list them as starting equipment.
+    /// Weapons that fire ammunition are registered after the other items, as they name their
+    /// ammunition, and packs are registered last, as they list their contents.
+    pub fn load_dir(&mut self, dir: &Path, errors: &mut Vec<ContentError>) {
+        let mut items = load_all::<Item>(&dir.join("items"), errors);
+        items.sort_by_key(|(_, item)| match &item.kind {
+            ItemKind::Weapon(weapon) if weapon.ammunition.is_some() => 1,
+            ItemKind::Pack(_) => 2,
+            _ => 0,
+        });
+        for (path, item) in items {
+            if let Err(err) = self.register_item(&path, item) {
+                errors.push(err);
+            }
+        }
+        for (path, race) in load_all::<Race>(&dir.join("races"), errors) {
+            if let Err(err) = self.register_race(&path, race) {
+                errors.push(err);
+            }
+        }
+        for (path, class) in load_all::<Class>(&dir.join("classes"), errors) {
+            if let Err(err) = self.register_class(&path, class) {
+                errors.push(err);
+            }
+        }
+        for (path, background) in load_all::<Background>(&dir.join("backgrounds"), errors) {
+            if let Err(err) = self.register_background(&path, background) {
+                errors.push(err);
+            }
+        }
+        for (path, spell) in load_all::<Spell>(&dir.join("spells"), errors) {
+            if let Err(err) = self.register_spell(&path, spell) {
+                errors.push(err);
+            }
+        }
+        for (path, feat) in load_all::<Feat>(&dir.join("feats"), errors) {
+            if let Err(err) = self.register_feat(&path, feat) {
+                errors.push(err);
+            }
+        }
+    }
+
+    /// Registers a race, along with its subraces and traits.
+    ///
+    /// `path` is the file the race was loaded from, used for error messages.
+    pub fn register_race(&mut self, path: &Path, race: Race) -> Result<(), ContentError> {
+        validate_id(path, "id", &race.id)?;
+        check_unused(path, ContentKind::Race, &race.id, &self.races)?;
+
+        for (i, subrace) in race.subraces.iter().enumerate() {
+            validate_id(path, &format!("subraces[{i}].id"), &subrace.id)?;
+            check_unused(path, ContentKind::Subrace, &subrace.id, &self.subraces)?;
+            if race.subraces[..i].iter().any(|s| s.id == subrace.id) {
+                return Err(ContentError::Duplicate {
+                    path: path.into(),
+                    kind: ContentKind::Subrace,
+                    id: subrace.id.clone(),
+                });
+            }
+        }
+
+        validate_proficiencies(path, "proficiencies", &race.proficiencies)?;
+        for (i, subrace) in race.subraces.iter().enumerate() {
+            validate_proficiencies(
+                path,
+                &format!("subraces[{i}].proficiencies"),
+                &subrace.proficiencies,
+            )?;
+        }
+
+        let traits = race
+            .traits
+            .iter()
+            .chain(race.subraces.iter().flat_map(|s| &s.traits));
+        for racial_trait in traits.clone() {
+            validate_id(path, "traits", &racial_trait.id)?;
+            for effect in &racial_trait.effects {
+                if let Effect::Proficiencies(proficiencies) = effect {
+                    validate_proficiencies(
+                        path,
+                        &format!("traits[{}].effects", racial_trait.id),
+                        &[Choices::All(proficiencies.clone())],
+                    )?;
+                }
+            }
+            // The same trait may be shared by several races, as long as it's identical
+            if let Some(existing) = self.traits.get(&racial_trait.id) {
+                if existing != racial_trait {
+                    return Err(ContentError::Duplicate {
+                        path: path.into(),
+                        kind: ContentKind::Trait,
+                        id: racial_trait.id.clone(),
+                    });
+                }
+            }
+        }
+
+        for racial_trait in traits {
+            self.traits
+                .insert(racial_trait.id.clone(), racial_trait.clone());
+        }
+        for subrace in &race.subraces {
+            self.subraces.insert(subrace.id.clone(), subrace.clone());
+        }
+        self.races.insert(race.id.clone(), race);
+        Ok(())
+    }
+
+    /// Registers a class.
+    ///
+    /// `path` is the file the class was loaded from, used for error messages.
+    pub fn register_class(&mut self, path: &Path, class: Class) -> Result<(), ContentError> {
+        validate_id(path, "id", &class.id)?;
+        check_unused(path, ContentKind::Class, &class.id, &self.classes)?;
+
+        let table = &class.class_table;
+        if table.rows.len() != MAX_LEVEL as usize {
+            return Err(ContentError::Invalid {
+                path: path.into(),
+                field: "class_table.rows".into(),
+                reason: format!("expected {} rows, found {}", MAX_LEVEL, table.rows.len()),
+            });
+        }
+        for (i, row) in table.rows.iter().enumerate() {
+            if row.len() != table.columns.len() {
+                return Err(ContentError::Invalid {
+                    path: path.into(),
+                    field: format!("class_table.rows[{i}]"),
+                    reason: format!(
+                        "expected {} values, found {}",
+                        table.columns.len(),
+                        row.len()
+                    ),
+                });
+            }
+        }
+
+        validate_proficiencies(path, "proficiencies", &class.proficiencies)?;
+        validate_features(path, "features", &class.features, 1)?;
+        self.validate_starting_equipment(path, "starting_equipment", &class.starting_equipment)?;
+        if let Some(gold) = &class.starting_gold {
+            validate_dice(path, "starting_gold", gold)?;
+        }
+
+        let multiclassing = &class.multiclassing;
+        for (i, prerequisite) in multiclassing.prerequisites.iter().enumerate() {
+            validate_ability_prerequisite(
+                path,
+                &format!("multiclassing.prerequisites[{i}]"),
+                prerequisite,
+            )?;
+        }
+        validate_proficiencies(
+            path,
+            "multiclassing.proficiencies",
+            &multiclassing.proficiencies,
+        )?;
+        if let Some(spellcasting) = &class.spellcasting {
+            validate_spellcasting(path, "spellcasting", spellcasting)?;
+        }
+
+        for (i, subclass) in class.subclasses.iter().enumerate() {
+            validate_id(path, &format!("subclasses[{i}].id"), &subclass.id)?;
+            check_unused(path, ContentKind::Subclass, &subclass.id, &self.subclasses)?;
+            if class.subclasses[..i].iter().any(|s| s.id == subclass.id) {
+                return Err(ContentError::Duplicate {
+                    path: path.into(),
+                    kind: ContentKind::Subclass,
+                    id: subclass.id.clone(),
+                });
+            }
+            if subclass.level == 0 || subclass.level > MAX_LEVEL {
+                return Err(ContentError::Invalid {
+                    path: path.into(),
+                    field: format!("subclasses[{i}].level"),
+                    reason: format!("the level must be between 1 and {}", MAX_LEVEL),
+                });
+            }
+            validate_features(
+                path,
+                &format!("subclasses[{i}].features"),
+                &subclass.features,
+                subclass.level,
+            )?;
+            if let Some(spellcasting) = &subclass.spellcasting {
+                validate_spellcasting(
+                    path,
+                    &format!("subclasses[{i}].spellcasting"),
+                    spellcasting,
+                )?;
+            }
+        }
+
+        for subclass in &class.subclasses {
+            self.subclasses
+                .insert(subclass.id.clone(), subclass.clone());
+        }
+        self.classes.insert(class.id.clone(), class);
+        Ok(())
+    }
+
+    /// Registers a background.
+    ///
+    /// `path` is the file the background was loaded from, used for error messages.
+    pub fn register_background(
+        &mut self,
+        path: &Path,
+        background: Background,
+    ) -> Result<(), ContentError> {
+        validate_id(path, "id", &background.id)?;
+        validate_proficiencies(path, "proficiencies", &background.proficiencies)?;
+        self.validate_starting_equipment(path, "equipment", &background.equipment)?;
+        check_unused(
+            path,
+            ContentKind::Background,
+            &background.id,
+            &self.backgrounds,
+        )?;
+        self.backgrounds.insert(background.id.clone(), background);
+        Ok(())
+    }
+
+    /// Registers a spell.
+    ///
+    /// `path` is the file the spell was loaded from, used for error messages. Every class the
+    /// spell lists must already be registered.
+    pub fn register_spell(&mut self, path: &Path, spell: Spell) -> Result<(), ContentError> {
+        validate_id(path, "id", &spell.id)?;
+        check_unused(path, ContentKind::Spell, &spell.id, &self.spells)?;
+
+        if spell.level > Spell::MAX_LEVEL {
+            return Err(ContentError::Invalid {
+                path: path.into(),
+                field: "level".into(),
+                reason: format!("the level must be between 0 and {}", Spell::MAX_LEVEL),
+            });
+        }
+        for (i, class) in spell.classes.iter().enumerate() {
+            if !self.classes.contains_key(class) {
+                return Err(ContentError::Invalid {
+                    path: path.into(),
+                    field: format!("classes[{i}]"),
+                    reason: format!("no class with the id `{class}` exists"),
+                });
+            }
+        }
+
+        self.spells.insert(spell.id.clone(), spell);
+        Ok(())
+    }
+
+    /// Registers a feat.
+    ///
+    /// `path` is the file the feat was loaded from, used for error messages. Every race or
+    /// subrace the prerequisites name must already be registered.
+    pub fn register_feat(&mut self, path: &Path, feat: Feat) -> Result<(), ContentError> {
+        validate_id(path, "id", &feat.id)?;
+        check_unused(path, ContentKind::Feat, &feat.id, &self.feats)?;
+
+        for (i, prerequisite) in feat.prerequisites.iter().enumerate() {
+            let field = format!("prerequisites[{i}]");
+            match prerequisite {
+                FeatPrerequisite::Ability(prerequisite) => {
+                    validate_ability_prerequisite(path, &field, prerequisite)?
+                }
+                FeatPrerequisite::Proficiency(proficiency) => validate_proficiencies(
+                    path,
+                    &field,
+                    &[Choices::All(vec![proficiency.clone()])],
+                )?,
+                FeatPrerequisite::Spellcasting => {}
+                FeatPrerequisite::Race(ids) => {
+                    if ids.is_empty() {
+                        return Err(ContentError::Invalid {
+                            path: path.into(),
+                            field,
+                            reason: "at least one race is required".into(),
+                        });
+                    }
+                    for id in ids {
+                        if !self.races.contains_key(id) && !self.subraces.contains_key(id) {
+                            return Err(ContentError::Invalid {
+                                path: path.into(),
+                                field,
+                                reason: format!("no race or subrace with the id `{id}` exists"),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        validate_proficiencies(path, "proficiencies", &feat.proficiencies)?;
+        for effect in &feat.effects {
+            if let Effect::Proficiencies(proficiencies) = effect {
+                validate_proficiencies(path, "effects", &[Choices::All(proficiencies.clone())])?;
+            }
+        }
+
+        self.feats.insert(feat.id.clone(), feat);
+        Ok(())
+    }
+
+    /// Registers an item.
+    ///
+    /// `path` is the file the item was loaded from, used for error messages. Every item a pack
+    /// contains and the ammunition a weapon fires must already be registered.
+    pub fn register_item(&mut self, path: &Path, item: Item) -> Result<(), ContentError> {
+        validate_id(path, "id", &item.id)?;
+        check_unused(path, ContentKind::Item, &item.id, &self.items)?;
+
+        if item.bundle == 0 {
+            return Err(ContentError::Invalid {
+                path: path.into(),
+                field: "bundle".into(),
+                reason: "a bundle holds at least one item".into(),
+            });
+        }
+        match &item.kind {
+            ItemKind::Weapon(weapon) => {
+                if let Some(damage) = &weapon.damage {
+                    validate_dice(path, "kind.damage.dice", &damage.dice)?;
+                }
+                for (i, property) in weapon.properties.iter().enumerate() {
+                    if let WeaponProperty::Versatile(dice) = property {
+                        validate_dice(path, &format!("kind.properties[{i}]"), dice)?;
+                    }
+                }
+                if let Some(ammunition) = &weapon.ammunition {
+                    self.validate_item(path, "kind.ammunition", ammunition)?;
+                    if self.items[ammunition].kind != ItemKind::Ammunition {
+                        return Err(ContentError::Invalid {
+                            path: path.into(),
+                            field: "kind.ammunition".into(),
+                            reason: format!("`{ammunition}` is not ammunition"),
+                        });
+                    }
+                }
+            }
+            ItemKind::Container(container) => {
+                if container.capacity <= 0.0 {
+                    return Err(ContentError::Invalid {
+                        path: path.into(),
+                        field: "kind.capacity".into(),
+                        reason: "a container holds some weight".into(),
+                    });
+                }
+            }
+            ItemKind::Tool => {
+                if proficiency::tool(&item.id).is_none() {
+                    return Err(ContentError::Invalid {
+                        path: path.into(),
+                        field: "id".into(),
+                        reason: format!("no tool with the id `{}` exists", item.id),
+                    });
+                }
+            }
+            ItemKind::Pack(contents) => {
+                for (i, stack) in contents.iter().enumerate() {
+                    self.validate_item(path, &format!("kind.contents[{i}]"), &stack.item)?;
+                }
+            }
+            ItemKind::Armor(_) | ItemKind::Ammunition | ItemKind::Focus(_) | ItemKind::Gear => {}
+        }
+
+        self.items.insert(item.id.clone(), item);
+        Ok(())
+    }
+
+    /// Returns the race with the given id.
+    pub fn race(&self, id: &str) -> Result<&Race, ContentError> {
+        lookup(ContentKind::Race, id, &self.races)
+    }
+
+    /// Returns the subrace with the given id.
+    pub fn subrace(&self, id: &str) -> Result<&Subrace, ContentError> {
+        lookup(ContentKind::Subrace, id, &self.subraces)
+    }
+
+    /// Returns the class with the given id.
+    pub fn class(&self, id: &str) -> Result<&Class, ContentError> {
+        lookup(ContentKind::Class, id, &self.classes)
+    }
+
+    /// Returns the subclass with the given id.
+    pub fn subclass(&self, id: &str) -> Result<&Subclass, ContentError> {
+        lookup(ContentKind::Subclass, id, &self.subclasses)
+    }
+
+    /// Returns the background with the given id.
+    pub fn background(&self, id: &str) -> Result<&Background, ContentError> {
+        lookup(ContentKind::Background, id, &self.backgrounds)
+    }
+
+    /// Returns the racial trait with the given id.
+    pub fn racial_trait(&self, id: &str) -> Result<&RacialTrait, ContentError> {
+        lookup(ContentKind::Trait, id, &self.traits)
+    }
+
+    /// Returns the spell with the given id.
+    pub fn spell(&self, id: &str) -> Result<&Spell, ContentError> {
+        lookup(ContentKind::Spell, id, &self.spells)
+    }
+
+    /// Returns the feat with the given id.
+    pub fn feat(&self, id: &str) -> Result<&Feat, ContentError> {
+        lookup(ContentKind::Feat, id, &self.feats)
+    }
+
+    /// Returns the item with the given id.
+    pub fn item(&self, id: &str) -> Result<&Item, ContentError> {
+        lookup(ContentKind::Item, id, &self.items)
+    }
+
+    /// Returns all registered races, sorted by id.
+    pub fn races(&self) -> impl Iterator<Item = &Race> {
+        self.races.values()
+    }
+
+    /// Returns all registered classes, sorted by id.
+    pub fn classes(&self) -> impl Iterator<Item = &Class> {
+        self.classes.values()
+    }
+
+    /// Returns all registered backgrounds, sorted by id.
+    pub fn backgrounds(&self) -> impl Iterator<Item = &Background> {
+        self.backgrounds.values()
+    }
+
+    /// Returns all registered spells, sorted by id.
+    pub fn spells(&self) -> impl Iterator<Item = &Spell> {
+        self.spells.values()
+    }
+
+    /// Returns all registered feats, sorted by id.
+    pub fn feats(&self) -> impl Iterator<Item = &Feat> {
+        self.feats.values()
+    }
+
+    /// Returns all registered items, sorted by id.
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.items.values()
+    }
+
+    /// Returns the registered items in `category`, sorted by name.
+    pub fn items_in(&self, category: &ItemCategory) -> Vec<&Item> {
+        let mut items = self
+            .items
+            .values()
+            .filter(|item| item.is_in(category))
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        items
+    }
+
+    /// Returns the display name of the content with the given id, falling back to the id if it
+    /// isn't registered.
+    pub fn name_of(&self, kind: ContentKind, id: &str) -> String {
+        let name = match kind {
+            ContentKind::Race => self.races.get(id).map(|race| &race.name),
+            ContentKind::Subrace => self.subraces.get(id).map(|subrace| &subrace.name),
+            ContentKind::Class => self.classes.get(id).map(|class| &class.name),
+            ContentKind::Subclass => self.subclasses.get(id).map(|subclass| &subclass.name),
+            ContentKind::Background => self.backgrounds.get(id).map(|bg| &bg.name),
+            ContentKind::Trait => self.traits.get(id).map(|t| &t.name),
+            ContentKind::Spell => self.spells.get(id).map(|spell| &spell.name),
+            ContentKind::Feat => self.feats.get(id).map(|feat| &feat.name),
+            ContentKind::Item => self.items.get(id).map(|item| &item.name),
+        };
+        name.cloned().unwrap_or_else(|| id.into())
+    }
+
+    /// Validates that every item in a list of starting equipment is registered, that every
+    /// category has an item to pick, and that every choice has at least two alternatives.
+    fn validate_starting_equipment(
+        &self,
+        path: &Path,
+        field: &str,
+        equipment: &[StartingEquipment],
+    ) -> Result<(), ContentError> {
+        for (i, equipment) in equipment.iter().enumerate() {
+            let field = format!("{field}[{i}]");
+            let alternatives = match equipment {
+                StartingEquipment::All(items) => vec![(field, items)],
+                StartingEquipment::Either(alternatives) => {
+                    if alternatives.len() < 2 {
+                        return Err(ContentError::Invalid {
+                            path: path.into(),
+                            field,
+                            reason: "at least two alternatives are required".into(),
+                        });
+                    }
+                    alternatives
+                        .iter()
+                        .enumerate()
+                        .map(|(j, items)| (format!("{field}.{j}"), items))
+                        .collect()
+                }
+            };
+            for (field, items) in alternatives {
+                if items.is_empty() {
+                    return Err(ContentError::Invalid {
+                        path: path.into(),
+                        field,
+                        reason: "at least one item is required".into(),
+                    });
+                }
+                for (j, item) in items.iter().enumerate() {
+                    let field = format!("{field}[{j}]");
+                    match item {
+                        EquipmentItem::Item { item, .. } => {
+                            self.validate_item(path, &field, item)?
+                        }
+                        EquipmentItem::Any { category, .. } => {
+                            if self.items_in(category).is_empty() {
+                                return Err(ContentError::Invalid {
+                                    path: path.into(),
+                                    field,
+                                    reason: format!("no {category} exists"),
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if no item with the id `item` is registered.
+    fn validate_item(&self, path: &Path, field: &str, item: &str) -> Result<(), ContentError> {
+        if self.items.contains_key(item) {
+            Ok(())
+        } else {
+            Err(ContentError::Invalid {
+                path: path.into(),
+                field: field.into(),
+                reason: format!("no item with the id `{item}` exists"),
+            })
+        }
+    }
+}
+
+/// Validates that an ability score minimum names at least one ability and a score that can be
+/// reached.
+fn validate_ability_prerequisite(
+    path: &Path,
+    field: &str,
+    prerequisite: &AbilityPrerequisite,
+) -> Result<(), ContentError> {
+    if prerequisite.abilities.is_empty()
+        || prerequisite.score == 0
+        || prerequisite.score > AbilityScores::MAX_SCORE
+    {
+        return Err(ContentError::Invalid {
+            path: path.into(),
+            field: field.into(),
+            reason: format!(
+                "at least one ability and a score between 1 and {} are required",
+                AbilityScores::MAX_SCORE
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Validates that every tool in a list of proficiency choices exists, and that every choice can
+/// be satisfied.
+fn validate_proficiencies(
+    path: &Path,
+    field: &str,
+    choices: &[Choices<Proficiency>],
+) -> Result<(), ContentError> {
+    for (i, choices) in choices.iter().enumerate() {
+        validate_choices(path, &format!("{field}[{i}]"), choices)?;
+    }
+    Ok(())
+}
+
+/// Validates a single group of proficiency choices, including every nested alternative.
+fn validate_choices(
+    path: &Path,
+    field: &str,
+    choices: &Choices<Proficiency>,
+) -> Result<(), ContentError> {
+    let invalid = |reason: String| ContentError::Invalid {
+        path: path.into(),
+        field: field.into(),
+        reason,
+    };
+
+    if let Choices::Either(alternatives) = choices {
+        if alternatives.len() < 2 {
+            return Err(invalid("at least two alternatives are required".into()));
+        }
+        for (i, alternative) in alternatives.iter().enumerate() {
+            validate_choices(path, &format!("{field}.{i}"), alternative)?;
+        }
+        return Ok(());
+    }
+
+    let options = choices.options();
+    for item in &options {
+        if let Proficiency::Tool(id) = item {
+            if proficiency::tool(id).is_none() {
+                return Err(invalid(format!("no tool with the id `{id}` exists")));
+            }
+        }
+    }
+    if let Some(count) = choices.count() {
+        if count == 0 || count > options.len() {
+            return Err(invalid(format!(
+                "{} proficiencies can't be chosen out of {}",
+                count,
+                options.len()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Validates the ids and levels of a list of class or subclass features.
+///
+/// Every level a feature is gained at must be between `min_level` and [`MAX_LEVEL`].
+fn validate_features(
+    path: &Path,
+    field: &str,
+    features: &[ClassFeature],
+    min_level: u8,
+) -> Result<(), ContentError> {
+    for (i, feature) in features.iter().enumerate() {
+        validate_id(path, &format!("{field}[{i}].id"), &feature.id)?;
+        if features[..i].iter().any(|f| f.id == feature.id) {
+            return Err(ContentError::Invalid {
+                path: path.into(),
+                field: format!("{field}[{i}].id"),
+                reason: format!("the feature `{}` is listed twice", feature.id),
+            });
+        }
+        if feature.levels.is_empty()
+            || feature
+                .levels
+                .iter()
+                .any(|&level| level < min_level || level > MAX_LEVEL)
+        {
+            return Err(ContentError::Invalid {
+                path: path.into(),
+                field: format!("{field}[{i}].levels"),
+                reason: format!("levels must be between {} and {}", min_level, MAX_LEVEL),
+            });
+        }
+        for (j, option) in feature.options.iter().enumerate() {
+            validate_id(path, &format!("{field}[{i}].options[{j}].id"), &option.id)?;
+            if feature.options[..j].iter().any(|o| o.id == option.id) {
+                return Err(ContentError::Invalid {
+                    path: path.into(),
+                    field: format!("{field}[{i}].options[{j}].id"),
+                    reason: format!("the option `{}` is listed twice", option.id),
+                });
+            }
+        }
+        if let Some(expertise) = &feature.expertise {
+            if expertise.count == 0 {
+                return Err(ContentError::Invalid {
+                    path: path.into(),
+                    field: format!("{field}[{i}].expertise.count"),
+                    reason: "at least one proficiency must be chosen".into(),
+                });
+            }
+            if let Some(id) = expertise
+                .tools
+                .iter()
+                .find(|id| proficiency::tool(id).is_none())
+            {
+                return Err(ContentError::Invalid {
+                    path: path.into(),
+                    field: format!("{field}[{i}].expertise.tools"),
+                    reason: format!("no tool with the id `{id}` exists"),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validates the level the spellcasting is gained at, and that the per-level counts either have
+/// a value for every level or are empty.
+fn validate_spellcasting(
+    path: &Path,
+    field: &str,
+    spellcasting: &Spellcasting,
+) -> Result<(), ContentError> {
+    if spellcasting.level == 0 || spellcasting.level > MAX_LEVEL {
+        return Err(ContentError::Invalid {
+            path: path.into(),
+            field: format!("{field}.level"),
+            reason: format!("the level must be between 1 and {}", MAX_LEVEL),
+        });
+    }
+    let counts = [
+        ("cantrips_known", &spellcasting.cantrips_known),
+        ("spells_known", &spellcasting.spells_known),
+    ];
+    for (name, counts) in counts {
+        if !counts.is_empty() && counts.len() != MAX_LEVEL as usize {
+            return Err(ContentError::Invalid {
+                path: path.into(),
+                field: format!("{field}.{name}"),
+                reason: format!("expected {} values, found {}", MAX_LEVEL, counts.len()),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Validates that a dice expression, such as the damage of a weapon, can be parsed.
+fn validate_dice(path: &Path, field: &str, dice: &str) -> Result<(), ContentError> {
+    match dice.parse::<Expression>() {
+        Ok(_) => Ok(()),
+        Err(err) => Err(ContentError::Invalid {
+            path: path.into(),
+            field: field.into(),
+            reason: format!("`{dice}` is not a valid dice expression: {err}"),
+        }),
+    }
+}
+
+/// Returns an error if `id` is already registered in `map`.
+fn check_unused<T>(
+    path: &Path,
+    kind: ContentKind,
+    id: &str,
+    map: &BTreeMap<String, T>,
+) -> Result<(), ContentError> {
+    if map.contains_key(id) {
+        Err(ContentError::Duplicate {
+            path: path.into(),
+            kind,
+            id: id.into(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Looks up `id` in `map`.
+fn lookup<'a, T>(
+    kind: ContentKind,
+    id: &str,
+    map: &'a BTreeMap<String, T>,
+) -> Result<&'a T, ContentError> {
+    map.get(id).ok_or_else(|| ContentError::NotFound {
+        kind,
+        id: id.into(),
+    })
+}
+
+/// Checks that an id is non-empty and only contains lowercase letters, digits, and dashes.
+fn validate_id(path: &Path, field: &str, id: &str) -> Result<(), ContentError> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(ContentError::Invalid {
+            path: path.into(),
+            field: field.into(),
+            reason: format!(
+                "`{id}` is not a valid id (only lowercase letters, digits, and dashes are allowed)"
+            ),
+        })
+    }
+}
+
+/// Returns the directories content is loaded from.
+///
+/// These are the built-in content directory, followed by the `content` directory inside the
+/// user's data directory (if one exists). If the built-in content directory isn't found, an
+/// error is added to `errors` and only the user's content is loaded.
+pub fn content_dirs(errors: &mut Vec<ContentError>) -> Vec<PathBuf> {
+    let mut dirs = vec![];
+    match builtin_content_dir() {
+        Ok(dir) => dirs.push(dir),
+        Err(err) => errors.push(err),
+    }
+    if let Some(data_dir) = dirs::data_dir() {
+        dirs.push(data_dir.join("dregg").join("content"));
+    }
+    dirs
+}
+
+/// Returns the directory the built-in content is loaded from.
+///
+/// This is the directory named by [`CONTENT_DIR_ENV`], or else the `content` directory next to
+/// the executable. Debug builds fall back to the `content` directory of the source tree, so that
+/// `cargo run` finds it. Fails if the directory doesn't exist.
+fn builtin_content_dir() -> Result<PathBuf, ContentError> {
+    let not_found = |path: PathBuf| ContentError::Io {
+        path,
+        source: io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "the built-in content wasn't found; place the `content` directory next to the \
+                 executable or set {CONTENT_DIR_ENV} to its location"
+            ),
+        ),
+    };
+
+    if let Some(dir) = std::env::var_os(CONTENT_DIR_ENV) {
+        let dir = PathBuf::from(dir);
+        return if dir.is_dir() {
+            Ok(dir)
+        } else {
+            Err(not_found(dir))
+        };
+    }
+    let installed = (std::env::current_exe().ok())
+        .and_then(|exe| Some(exe.parent()?.join("content")))
+        .unwrap_or_else(|| PathBuf::from("content"));
+    if installed.is_dir() {
+        return Ok(installed);
+    }
+
+    #[cfg(debug_assertions)]
+    return Ok(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("content"));
+    #[cfg(not(debug_assertions))]
+    Err(not_found(installed))
+}
+
+/// Deserializes every `.ron` file in `dir`.
+///
+/// A missing directory is treated as empty.
+fn load_all<T: DeserializeOwned>(dir: &Path, errors: &mut Vec<ContentError>) -> Vec<(PathBuf, T)> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return vec![],
+        Err(source) => {
+            errors.push(ContentError::Io {
+                path: dir.into(),
+                source,
+            });
+            return vec![];
+        }
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut items = vec![];
+    for path in paths {
+        match load_file(&path) {
+            Ok(item) => items.push((path, item)),
+            Err(err) => errors.push(err),
+        }
+    }
+    items
+}
+
+/// Deserializes a single `.ron` file.
+fn load_file<T: DeserializeOwned>(path: &Path) -> Result<T, ContentError> {
+    let contents = fs::read_to_string(path).map_err(|source| ContentError::Io {
+        path: path.into(),
+        source,
+    })?;
+
+    let mut deserializer =
+        ron::Deserializer::from_str(&contents).map_err(|source| ContentError::Parse {
+            path: path.into(),
+            field: String::from("."),
+            source: Box::new(source),
+        })?;
+
+    let item = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let field = err.path().to_string();
+        ContentError::Parse {
+            path: path.into(),
+            field,
+            source: Box::new(deserializer.span_error(err.into_inner())),
+        }
+    })?;
+    deserializer.end().map_err(|err| ContentError::Parse {
+        path: path.into(),
+        field: String::from("."),
+        source: Box::new(deserializer.span_error(err)),
+    })?;
+
+    Ok(item)
+}
@@ -0,0 +1,354 @@
+//! A character's purse of coins and the log of what it received and spent.
+//!
+//! Amounts are handled in copper pieces. Paying spends the smallest coins first, and breaks a
+//! larger coin for change when the smaller ones don't add up to the price exactly.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::item::Coin;
+
+/// Every coin, from the most to the least valuable.
+pub const COINS: [Coin; 5] = [Coin::Pp, Coin::Gp, Coin::Ep, Coin::Sp, Coin::Cp];
+
+/// The coins change is given in, from the most to the least valuable. Electrum pieces are
+/// only spent, never given back.
+const CHANGE: [Coin; 4] = [Coin::Pp, Coin::Gp, Coin::Sp, Coin::Cp];
+
+/// Errors that can occur while spending or exchanging coins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyError {
+    /// The purse holds less than the amount, in copper pieces, to pay.
+    InsufficientFunds { price: u64, total: u64 },
+
+    /// The purse holds fewer coins than are exchanged.
+    NotEnoughCoins(Coin),
+
+    /// The coins can't be exchanged for a whole number of the other coin.
+    Indivisible(Coin),
+
+    /// The item has no price, such as the contents of a pack that aren't sold on their own.
+    NotForSale,
+}
+
+impl Display for CurrencyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CurrencyError::InsufficientFunds { price, total } => f.write_fmt(format_args!(
+                "Costs {}, but the purse only holds {}",
+                describe_copper(*price),
+                describe_copper(*total)
+            )),
+            CurrencyError::NotEnoughCoins(coin) => {
+                f.write_fmt(format_args!("Not enough {coin} in the purse"))
+            }
+            CurrencyError::Indivisible(coin) => f.write_fmt(format_args!(
+                "Can't be exchanged for a whole number of {coin}"
+            )),
+            CurrencyError::NotForSale => f.write_str("Not sold on its own"),
+        }
+    }
+}
+
+impl std::error::Error for CurrencyError {}
+
+/// The coins a character carries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Purse {
+    pub cp: u32,
+    pub sp: u32,
+    pub ep: u32,
+    pub gp: u32,
+    pub pp: u32,
+}
+
+impl Purse {
+    /// Returns the number of `coin` in the purse.
+    pub fn get(&self, coin: Coin) -> u32 {
+        match coin {
+            Coin::Cp => self.cp,
+            Coin::Sp => self.sp,
+            Coin::Ep => self.ep,
+            Coin::Gp => self.gp,
+            Coin::Pp => self.pp,
+        }
+    }
+
+    fn get_mut(&mut self, coin: Coin) -> &mut u32 {
+        match coin {
+            Coin::Cp => &mut self.cp,
+            Coin::Sp => &mut self.sp,
+            Coin::Ep => &mut self.ep,
+            Coin::Gp => &mut self.gp,
+            Coin::Pp => &mut self.pp,
+        }
+    }
+
+    /// Returns the value of every coin in the purse in copper pieces.
+    ///
+    /// The total is a `u64`, since a purse full of platinum pieces is worth more copper pieces
+    /// than a `u32` holds.
+    pub fn total(&self) -> u64 {
+        COINS
+            .iter()
+            .map(|coin| self.get(*coin) as u64 * coin.value() as u64)
+            .sum()
+    }
+
+    /// Returns the total weight of the coins in pounds, at 50 coins to the pound.
+    pub fn weight(&self) -> f32 {
+        COINS.iter().map(|coin| self.get(*coin) as u64).sum::<u64>() as f32 / 50.0
+    }
+
+    /// Adds `amount` of `coin` to the purse.
+    pub fn add(&mut self, coin: Coin, amount: u32) {
+        let count = self.get_mut(coin);
+        *count = count.saturating_add(amount);
+    }
+
+    /// Pays `price` copper pieces out of the purse.
+    ///
+    /// The smallest coins are spent first. If they don't add up to the price exactly, the
+    /// smallest coin worth at least what is left is broken, and the change is given back in as
+    /// few coins as possible.
+    pub fn pay(&mut self, price: u64) -> Result<(), CurrencyError> {
+        let total = self.total();
+        if total < price {
+            return Err(CurrencyError::InsufficientFunds { price, total });
+        }
+
+        let mut left = price;
+        for coin in COINS.iter().rev() {
+            let value = coin.value() as u64;
+            let spent = (self.get(*coin) as u64).min(left / value);
+            *self.get_mut(*coin) -= spent as u32;
+            left -= spent * value;
+        }
+        if left > 0 {
+            // One of the coins left is always worth more than what is left to pay
+            let coin = (COINS.iter().rev())
+                .find(|coin| self.get(**coin) > 0 && coin.value() as u64 >= left)
+                .copied()
+                .expect("The purse holds enough to pay");
+            *self.get_mut(coin) -= 1;
+            self.give_change(coin.value() as u64 - left);
+        }
+        Ok(())
+    }
+
+    /// Exchanges `amount` of `from` for coins of `to` of the same value.
+    pub fn exchange(&mut self, from: Coin, to: Coin, amount: u32) -> Result<(), CurrencyError> {
+        if self.get(from) < amount {
+            return Err(CurrencyError::NotEnoughCoins(from));
+        }
+        let value = amount as u64 * from.value() as u64;
+        if !value.is_multiple_of(to.value() as u64) {
+            return Err(CurrencyError::Indivisible(to));
+        }
+        *self.get_mut(from) -= amount;
+        self.add(to, saturate(value / to.value() as u64));
+        Ok(())
+    }
+
+    /// Exchanges every coin for as few coins as possible of the same total value, leaving
+    /// electrum pieces out.
+    pub fn consolidate(&mut self) {
+        let total = self.total();
+        *self = Purse::default();
+        self.give_change(total);
+    }
+
+    /// Adds `amount` copper pieces to the purse in as few coins as possible, leaving electrum
+    /// pieces out.
+    fn give_change(&mut self, mut amount: u64) {
+        for coin in CHANGE {
+            self.add(coin, saturate(amount / coin.value() as u64));
+            amount %= coin.value() as u64;
+        }
+    }
+}
+
+impl Display for Purse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let coins = (COINS.iter())
+            .filter(|coin| self.get(**coin) > 0)
+            .map(|coin| format!("{} {coin}", self.get(*coin)))
+            .collect::<Vec<_>>();
+        if coins.is_empty() {
+            f.write_str("Empty")
+        } else {
+            f.write_str(&coins.join(" "))
+        }
+    }
+}
+
+/// Something a character received or spent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transaction {
+    /// What the coins were for, such as `Bought Longsword`.
+    pub description: String,
+
+    /// The copper pieces received, or spent if negative.
+    pub amount: i64,
+}
+
+impl Display for Transaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.amount < 0 { '-' } else { '+' };
+        f.write_fmt(format_args!(
+            "{sign}{}: {}",
+            describe_copper(self.amount.unsigned_abs()),
+            self.description
+        ))
+    }
+}
+
+/// A character's purse, along with every transaction since it started with its starting gold.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Wealth {
+    /// The coins the character carries.
+    pub purse: Purse,
+
+    /// What the character received and spent, oldest first.
+    pub log: Vec<Transaction>,
+}
+
+impl Wealth {
+    /// Creates a purse holding `gold` gold pieces, logged as `description`.
+    pub fn starting(gold: u32, description: &str) -> Self {
+        let mut wealth = Self::default();
+        if gold > 0 {
+            wealth.receive(Coin::Gp, gold, description);
+        }
+        wealth
+    }
+
+    /// Adds `amount` of `coin` to the purse, logged as `description`.
+    pub fn receive(&mut self, coin: Coin, amount: u32, description: &str) {
+        self.purse.add(coin, amount);
+        self.log.push(Transaction {
+            description: description.to_string(),
+            amount: amount as i64 * coin.value() as i64,
+        });
+    }
+
+    /// Pays `price` copper pieces out of the purse, making change as needed, logged as
+    /// `description`.
+    pub fn pay(&mut self, price: u64, description: &str) -> Result<(), CurrencyError> {
+        self.purse.pay(price)?;
+        self.log.push(Transaction {
+            description: description.to_string(),
+            amount: -i64::try_from(price).unwrap_or(i64::MAX),
+        });
+        Ok(())
+    }
+}
+
+/// Returns the next smaller coin that `coin` breaks into, or `None` for copper pieces.
+pub fn smaller(coin: Coin) -> Option<Coin> {
+    match coin {
+        Coin::Pp => Some(Coin::Gp),
+        Coin::Gp | Coin::Ep => Some(Coin::Sp),
+        Coin::Sp => Some(Coin::Cp),
+        Coin::Cp => None,
+    }
+}
+
+/// Describes an amount of copper pieces in gold, silver, and copper pieces, such as
+/// `12 gp 5 sp`.
+pub fn describe_copper(amount: u64) -> String {
+    if amount == 0 {
+        return "0 gp".to_string();
+    }
+    let mut parts = vec![];
+    let mut left = amount;
+    for coin in [Coin::Gp, Coin::Sp, Coin::Cp] {
+        let value = coin.value() as u64;
+        if left / value > 0 {
+            parts.push(format!("{} {coin}", left / value));
+        }
+        left %= value;
+    }
+    parts.join(" ")
+}
+
+/// Converts a number of coins to a `u32`, capping it at `u32::MAX`.
+fn saturate(count: u64) -> u32 {
+    u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn purse(cp: u32, sp: u32, ep: u32, gp: u32, pp: u32) -> Purse {
+        Purse { cp, sp, ep, gp, pp }
+    }
+
+    #[test]
+    fn pays_with_the_smallest_coins_first() {
+        let mut coins = purse(5, 3, 0, 2, 0);
+        coins.pay(25).unwrap();
+        assert_eq!(coins, purse(0, 1, 0, 2, 0));
+    }
+
+    #[test]
+    fn breaks_a_larger_coin_for_change() {
+        let mut coins = purse(0, 3, 0, 1, 0);
+        coins.pay(25).unwrap();
+        assert_eq!(coins, purse(5, 0, 0, 1, 0));
+
+        let mut coins = purse(0, 0, 0, 1, 0);
+        coins.pay(25).unwrap();
+        assert_eq!(coins, purse(5, 7, 0, 0, 0));
+
+        let mut coins = purse(5, 0, 1, 0, 1);
+        coins.pay(52).unwrap();
+        assert_eq!(coins, purse(3, 0, 0, 0, 1));
+    }
+
+    #[test]
+    fn refuses_to_pay_more_than_the_purse_holds() {
+        let mut coins = purse(0, 9, 0, 0, 0);
+        assert_eq!(
+            coins.pay(100),
+            Err(CurrencyError::InsufficientFunds {
+                price: 100,
+                total: 90
+            })
+        );
+        assert_eq!(coins, purse(0, 9, 0, 0, 0));
+    }
+
+    #[test]
+    fn exchanges_and_consolidates_coins() {
+        let mut coins = purse(0, 0, 3, 0, 0);
+        assert_eq!(
+            coins.exchange(Coin::Ep, Coin::Gp, 1),
+            Err(CurrencyError::Indivisible(Coin::Gp))
+        );
+        coins.exchange(Coin::Ep, Coin::Gp, 2).unwrap();
+        assert_eq!(coins, purse(0, 0, 1, 1, 0));
+
+        let mut coins = purse(125, 30, 1, 9, 0);
+        coins.consolidate();
+        assert_eq!(coins, purse(5, 7, 0, 3, 1));
+    }
+
+    #[test]
+    fn handles_purses_worth_more_than_a_u32() {
+        let mut wealth = Wealth::starting(0, "Starting gold");
+        wealth.receive(Coin::Pp, 5_000_000, "Received");
+        assert_eq!(wealth.purse.total(), 5_000_000_000);
+
+        wealth.pay(4_999_999_999, "Bought a castle").unwrap();
+        assert_eq!(wealth.purse, purse(1, 0, 0, 0, 0));
+        assert_eq!(
+            wealth.log.last().unwrap().to_string(),
+            "-49999999 gp 9 sp 9 cp: Bought a castle"
+        );
+    }
+}
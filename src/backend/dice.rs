@@ -0,0 +1,1106 @@
+//! Dice expressions such as `2d6+3`, `4d6kh3`, `1d20adv`, `2d6r2`, and `8d6/2`.
+//!
+//! An expression is a sum of terms. Each term is either a constant or a group of dice, optionally
+//! followed by multipliers (`*10`) and divisors (`/2`, rounded down). A group of dice may keep
+//! only its highest or lowest dice with `khN`/`klN`, or be rolled with advantage or
+//! disadvantage with `adv`/`dis` (which doubles the dice rolled and keeps the highest or lowest
+//! half). It may also reroll each die that shows `N` or lower once with `rN`, keeping the new
+//! roll.
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::frontend::class::Die;
+
+/// The most dice a single group may contain.
+pub const MAX_DICE: u32 = 1000;
+
+/// The most sides a single die may have.
+pub const MAX_SIDES: u32 = 1000;
+
+/// Errors that can occur while parsing or evaluating dice expressions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceError {
+    /// The expression could not be parsed.
+    Parse {
+        /// The byte offset in the expression where the error occurred.
+        position: usize,
+        message: String,
+    },
+
+    /// The exact distribution of the expression is too expensive to compute.
+    TooComplex,
+}
+
+impl Display for DiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiceError::Parse { position, message } => {
+                f.write_fmt(format_args!("{} (at position {})", message, position + 1))
+            }
+            DiceError::TooComplex => {
+                f.write_str("The expression is too complex to compute a distribution for")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DiceError {}
+
+/// Which dice of a group count towards its total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    All,
+    Highest(u32),
+    Lowest(u32),
+}
+
+/// A group of identical dice, such as `4d6kh3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiceGroup {
+    /// The number of dice rolled.
+    pub count: u32,
+
+    /// The number of sides on each die.
+    pub sides: u32,
+
+    /// Which dice count towards the total.
+    pub keep: Keep,
+
+    /// Dice that show this value or lower are rerolled once, and the new roll is used. 0 if no
+    /// dice are rerolled.
+    pub reroll: u32,
+
+    /// How the group was written, used to display it.
+    notation: Notation,
+}
+
+/// How the keep rule of a group was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Notation {
+    Keep,
+    Advantage,
+    Disadvantage,
+}
+
+impl DiceGroup {
+    /// Creates a group where every die is kept.
+    pub fn new(count: u32, sides: u32) -> Self {
+        Self {
+            count,
+            sides,
+            keep: Keep::All,
+            reroll: 0,
+            notation: Notation::Keep,
+        }
+    }
+
+    /// The number of dice that count towards the total.
+    pub fn kept(&self) -> u32 {
+        match self.keep {
+            Keep::All => self.count,
+            Keep::Highest(n) | Keep::Lowest(n) => n.min(self.count),
+        }
+    }
+
+    /// The number of ways a single die of the group can end up showing `value`, out of
+    /// [`Self::die_outcomes`].
+    fn die_ways(&self, value: u32) -> u128 {
+        let kept = if value > self.reroll { 1 } else { 0 };
+        if self.reroll == 0 {
+            kept
+        } else {
+            // Either the first roll is kept, or any rerolled value is followed by `value`.
+            kept * self.sides as u128 + self.reroll as u128
+        }
+    }
+
+    /// The number of equally likely ways a single die of the group can be rolled, rerolls
+    /// included.
+    fn die_outcomes(&self) -> u128 {
+        if self.reroll == 0 {
+            self.sides as u128
+        } else {
+            self.sides as u128 * self.sides as u128
+        }
+    }
+
+    /// Marks which of the rolled values are kept.
+    fn keep_mask(&self, values: &[u32]) -> Vec<bool> {
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        match self.keep {
+            Keep::All => return vec![true; values.len()],
+            Keep::Highest(_) => order.sort_by(|&a, &b| values[b].cmp(&values[a])),
+            Keep::Lowest(_) => order.sort_by(|&a, &b| values[a].cmp(&values[b])),
+        }
+
+        let mut mask = vec![false; values.len()];
+        for &i in order.iter().take(self.kept() as usize) {
+            mask[i] = true;
+        }
+        mask
+    }
+
+    /// The number of ways each total of the group can be rolled.
+    fn distribution(&self) -> Result<Distribution, DiceError> {
+        match self.keep {
+            Keep::All => {
+                let die = Distribution {
+                    ways: (1..=self.sides)
+                        .map(|value| (value as i64, self.die_ways(value)))
+                        .collect(),
+                    outcomes: self.die_outcomes(),
+                };
+                let mut dist = Distribution::constant(0);
+                for _ in 0..self.count {
+                    dist = dist.add(&die)?;
+                }
+                Ok(dist)
+            }
+            Keep::Highest(_) | Keep::Lowest(_) => self.keep_distribution(),
+        }
+    }
+
+    /// Computes the distribution of a group that keeps some of its dice, by enumerating every
+    /// multiset of values along with the number of orderings that produce it.
+    fn keep_distribution(&self) -> Result<Distribution, DiceError> {
+        const MAX_MULTISETS: u128 = 1_000_000;
+        if binomial(self.count + self.sides - 1, self.sides - 1).is_none_or(|n| n > MAX_MULTISETS) {
+            return Err(DiceError::TooComplex);
+        }
+
+        let mut dist = Distribution::default();
+        let mut values = Vec::with_capacity(self.count as usize);
+        self.enumerate(self.sides, self.count, &mut values, &mut dist)?;
+        dist.outcomes = (0..self.count).try_fold(1u128, |outcomes, _| {
+            outcomes
+                .checked_mul(self.die_outcomes())
+                .ok_or(DiceError::TooComplex)
+        })?;
+        Ok(dist)
+    }
+
+    /// Recursively enumerates the multisets of `remaining` dice using values up to `max`.
+    ///
+    /// `values` holds the values chosen so far, in descending order.
+    fn enumerate(
+        &self,
+        max: u32,
+        remaining: u32,
+        values: &mut Vec<u32>,
+        dist: &mut Distribution,
+    ) -> Result<(), DiceError> {
+        if remaining == 0 {
+            let kept = self.kept() as usize;
+            let total: u32 = match self.keep {
+                Keep::Highest(_) => values[..kept].iter().sum(),
+                _ => values[values.len() - kept..].iter().sum(),
+            };
+
+            // The number of distinct orderings of the multiset
+            let mut ways = factorial(self.count).ok_or(DiceError::TooComplex)?;
+            let mut start = 0;
+            while start < values.len() {
+                let run = values[start..]
+                    .iter()
+                    .take_while(|&&v| v == values[start])
+                    .count();
+                ways /= factorial(run as u32).ok_or(DiceError::TooComplex)?;
+                start += run;
+            }
+            // Rerolls make some values more likely than others
+            for &value in values.iter() {
+                ways = ways
+                    .checked_mul(self.die_ways(value))
+                    .ok_or(DiceError::TooComplex)?;
+            }
+
+            *dist.ways.entry(total as i64).or_default() += ways;
+            return Ok(());
+        }
+
+        for value in (1..=max).rev() {
+            values.push(value);
+            self.enumerate(value, remaining - 1, values, dist)?;
+            values.pop();
+        }
+        Ok(())
+    }
+}
+
+impl Display for DiceGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.notation, self.keep) {
+            (Notation::Advantage, _) => {
+                f.write_fmt(format_args!("{}d{}adv", self.count / 2, self.sides))
+            }
+            (Notation::Disadvantage, _) => {
+                f.write_fmt(format_args!("{}d{}dis", self.count / 2, self.sides))
+            }
+            (_, Keep::All) => f.write_fmt(format_args!("{}d{}", self.count, self.sides)),
+            (_, Keep::Highest(n)) => {
+                f.write_fmt(format_args!("{}d{}kh{}", self.count, self.sides, n))
+            }
+            (_, Keep::Lowest(n)) => {
+                f.write_fmt(format_args!("{}d{}kl{}", self.count, self.sides, n))
+            }
+        }?;
+        if self.reroll > 0 {
+            f.write_fmt(format_args!("r{}", self.reroll))?;
+        }
+        Ok(())
+    }
+}
+
+/// The value a term is built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Dice(DiceGroup),
+    Constant(i64),
+}
+
+/// A scaling applied to a term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scale {
+    Multiply(i64),
+
+    /// Division, rounded down.
+    Divide(i64),
+}
+
+impl Scale {
+    /// Applies the scaling to `value`, or returns `None` if the result overflows.
+    fn checked_apply(&self, value: i64) -> Option<i64> {
+        match self {
+            Scale::Multiply(n) => value.checked_mul(*n),
+            Scale::Divide(n) => value.checked_div_euclid(*n),
+        }
+    }
+}
+
+/// A single term of an expression, such as `-1d4` or `5d4*10`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+    /// Whether the term is subtracted from the total.
+    pub negative: bool,
+
+    pub operand: Operand,
+
+    /// Scalings applied to the operand, in order.
+    pub scales: Vec<Scale>,
+}
+
+impl Term {
+    /// Applies the scales and sign of the term to the value of its operand, saturating if the
+    /// result overflows.
+    ///
+    /// Parsed expressions never overflow, as the parser rejects the ones whose totals could.
+    fn apply(&self, value: i64) -> i64 {
+        self.checked_apply(value)
+            .unwrap_or(if (value < 0) == self.negative {
+                i64::MAX
+            } else {
+                i64::MIN
+            })
+    }
+
+    /// Applies the scales and sign of the term to the value of its operand, or returns `None`
+    /// if the result or any step of it overflows.
+    fn checked_apply(&self, value: i64) -> Option<i64> {
+        let value =
+            (self.scales.iter()).try_fold(value, |value, scale| scale.checked_apply(value))?;
+        if self.negative {
+            value.checked_neg()
+        } else {
+            Some(value)
+        }
+    }
+}
+
+/// A parsed dice expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expression {
+    pub terms: Vec<Term>,
+}
+
+impl Expression {
+    /// Creates an expression that rolls a single die.
+    pub fn die(sides: u32) -> Self {
+        Self::dice(1, sides)
+    }
+
+    /// Creates an expression that rolls `count` dice with `sides` sides.
+    pub fn dice(count: u32, sides: u32) -> Self {
+        Self {
+            terms: vec![Term {
+                negative: false,
+                operand: Operand::Dice(DiceGroup::new(count, sides)),
+                scales: vec![],
+            }],
+        }
+    }
+
+    /// The lowest possible total.
+    pub fn min(&self) -> i64 {
+        self.bounds().0
+    }
+
+    /// The highest possible total.
+    pub fn max(&self) -> i64 {
+        self.bounds().1
+    }
+
+    /// The exact distribution of the totals of the expression.
+    pub fn distribution(&self) -> Result<Distribution, DiceError> {
+        let mut dist = Distribution::constant(0);
+        for term in &self.terms {
+            let operand = match term.operand {
+                Operand::Dice(group) => group.distribution()?,
+                Operand::Constant(value) => Distribution::constant(value),
+            };
+            dist = dist.add(&operand.map(|value| term.apply(value)))?;
+        }
+        Ok(dist)
+    }
+
+    /// The average total of the expression.
+    pub fn average(&self) -> Result<f64, DiceError> {
+        Ok(self.distribution()?.mean())
+    }
+
+    /// Returns the lowest and highest possible totals, saturating if they overflow.
+    fn bounds(&self) -> (i64, i64) {
+        self.checked_bounds().unwrap_or((i64::MIN, i64::MAX))
+    }
+
+    /// Returns the lowest and highest possible totals, or `None` if computing any total could
+    /// overflow.
+    ///
+    /// Scales only ever move a value in the same direction, so the values of every term stay
+    /// within the values at the ends of its range, at every step.
+    fn checked_bounds(&self) -> Option<(i64, i64)> {
+        let mut bounds: (i64, i64) = (0, 0);
+        for term in &self.terms {
+            let (low, high) = match term.operand {
+                Operand::Dice(group) => (group.kept() as i64, (group.kept() * group.sides) as i64),
+                Operand::Constant(value) => (value, value),
+            };
+            let (a, b) = (term.checked_apply(low)?, term.checked_apply(high)?);
+            bounds.0 = bounds.0.checked_add(a.min(b))?;
+            bounds.1 = bounds.1.checked_add(a.max(b))?;
+        }
+        Some(bounds)
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            if term.negative {
+                f.write_str(if i == 0 { "-" } else { " - " })?;
+            } else if i > 0 {
+                f.write_str(" + ")?;
+            }
+
+            match term.operand {
+                Operand::Dice(group) => group.fmt(f)?,
+                Operand::Constant(value) => value.fmt(f)?,
+            }
+            for scale in &term.scales {
+                match scale {
+                    Scale::Multiply(n) => f.write_fmt(format_args!("×{n}"))?,
+                    Scale::Divide(n) => f.write_fmt(format_args!("/{n}"))?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Expression {
+    type Err = DiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser { input: s, pos: 0 }.expression()
+    }
+}
+
+/// A recursive descent parser for dice expressions.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn expression(mut self) -> Result<Expression, DiceError> {
+        let mut terms = vec![];
+
+        self.skip_whitespace();
+        let mut negative = self.eat("-");
+        if !negative {
+            self.eat("+");
+        }
+        loop {
+            terms.push(self.term(negative)?);
+
+            self.skip_whitespace();
+            if self.eat("+") {
+                negative = false;
+            } else if self.eat("-") {
+                negative = true;
+            } else if self.pos == self.input.len() {
+                break;
+            } else {
+                return Err(self.error("Expected `+`, `-`, `*`, or `/`"));
+            }
+        }
+
+        let expression = Expression { terms };
+        if expression.checked_bounds().is_none() {
+            return Err(DiceError::Parse {
+                position: 0,
+                message: "The totals of the expression are too large".into(),
+            });
+        }
+        Ok(expression)
+    }
+
+    fn term(&mut self, negative: bool) -> Result<Term, DiceError> {
+        self.skip_whitespace();
+        let count = self.number()?;
+
+        let operand = if self.eat("d") || self.eat("D") {
+            let count = count.unwrap_or(1);
+            let sides = self
+                .number()?
+                .ok_or_else(|| self.error("Expected the number of sides"))?;
+            Operand::Dice(self.group(count, sides)?)
+        } else {
+            let value = count.ok_or_else(|| self.error("Expected a number or dice"))?;
+            Operand::Constant(value as i64)
+        };
+
+        let mut scales = vec![];
+        loop {
+            self.skip_whitespace();
+            let scale = if self.eat("*") || self.eat("x") || self.eat("×") {
+                Scale::Multiply
+            } else if self.eat("/") {
+                Scale::Divide
+            } else {
+                break;
+            };
+
+            self.skip_whitespace();
+            let n = self
+                .number()?
+                .ok_or_else(|| self.error("Expected a number"))? as i64;
+            if matches!(scale(0), Scale::Divide(_)) && n == 0 {
+                return Err(self.error("Cannot divide by zero"));
+            }
+            scales.push(scale(n));
+        }
+
+        Ok(Term {
+            negative,
+            operand,
+            scales,
+        })
+    }
+
+    fn group(&mut self, count: u32, sides: u32) -> Result<DiceGroup, DiceError> {
+        if count == 0 || count > MAX_DICE {
+            return Err(self.error(&format!("The number of dice must be 1 to {MAX_DICE}")));
+        }
+        if sides == 0 || sides > MAX_SIDES {
+            return Err(self.error(&format!("The number of sides must be 1 to {MAX_SIDES}")));
+        }
+
+        let mut group = DiceGroup::new(count, sides);
+        // `kl` has to be tried before the bare `k` that would consume its first letter
+        if self.eat("kl") {
+            group.keep = Keep::Lowest(self.keep_count(count)?);
+        } else if self.eat("kh") || self.eat("k") {
+            group.keep = Keep::Highest(self.keep_count(count)?);
+        } else if self.eat("dl") {
+            group.keep = Keep::Highest(count - self.drop_count(count)?);
+        } else if self.eat("dh") {
+            group.keep = Keep::Lowest(count - self.drop_count(count)?);
+        } else if self.eat("adv") {
+            group = self.doubled(group, Keep::Highest(count), Notation::Advantage)?;
+        } else if self.eat("dis") {
+            group = self.doubled(group, Keep::Lowest(count), Notation::Disadvantage)?;
+        }
+
+        if self.eat("r") {
+            let reroll = self
+                .number()?
+                .ok_or_else(|| self.error("Expected the highest value to reroll"))?;
+            if reroll == 0 || reroll >= sides {
+                return Err(self.error(&format!("The value to reroll must be 1 to {}", sides - 1)));
+            }
+            group.reroll = reroll;
+        }
+        Ok(group)
+    }
+
+    /// Turns a group into one rolled with advantage or disadvantage.
+    fn doubled(
+        &self,
+        group: DiceGroup,
+        keep: Keep,
+        notation: Notation,
+    ) -> Result<DiceGroup, DiceError> {
+        if group.count * 2 > MAX_DICE {
+            return Err(self.error(&format!("The number of dice must be 1 to {MAX_DICE}")));
+        }
+        Ok(DiceGroup {
+            count: group.count * 2,
+            keep,
+            notation,
+            ..group
+        })
+    }
+
+    fn keep_count(&mut self, count: u32) -> Result<u32, DiceError> {
+        match self.number()? {
+            Some(n) if n <= count => Ok(n),
+            Some(_) => Err(self.error("Cannot keep more dice than are rolled")),
+            None => Ok(1),
+        }
+    }
+
+    fn drop_count(&mut self, count: u32) -> Result<u32, DiceError> {
+        match self.number()? {
+            Some(n) if n <= count => Ok(n),
+            Some(_) => Err(self.error("Cannot drop more dice than are rolled")),
+            None => Ok(1),
+        }
+    }
+
+    /// Parses an unsigned number, if there is one.
+    fn number(&mut self) -> Result<Option<u32>, DiceError> {
+        let start = self.pos;
+        let digits = self.input[self.pos..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return Ok(None);
+        }
+        self.pos += digits;
+        self.input[start..self.pos]
+            .parse()
+            .map(Some)
+            .map_err(|_| DiceError::Parse {
+                position: start,
+                message: "Number is too large".into(),
+            })
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.input[self.pos..].starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos += self.input[self.pos..]
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .map(char::len_utf8)
+            .sum::<usize>();
+    }
+
+    fn error(&self, message: &str) -> DiceError {
+        DiceError::Parse {
+            position: self.pos,
+            message: message.into(),
+        }
+    }
+}
+
+/// The exact distribution of the totals of an expression.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Distribution {
+    /// The number of outcomes that produce each total.
+    pub ways: BTreeMap<i64, u128>,
+
+    /// The total number of equally likely outcomes.
+    pub outcomes: u128,
+}
+
+impl Distribution {
+    /// A distribution with a single possible total.
+    fn constant(value: i64) -> Self {
+        Self {
+            ways: BTreeMap::from([(value, 1)]),
+            outcomes: 1,
+        }
+    }
+
+    /// The distribution of the sum of two independent distributions.
+    fn add(&self, other: &Self) -> Result<Self, DiceError> {
+        let mut ways = BTreeMap::new();
+        for (a, x) in &self.ways {
+            for (b, y) in &other.ways {
+                let n = x.checked_mul(*y).ok_or(DiceError::TooComplex)?;
+                let entry = ways.entry(a + b).or_insert(0u128);
+                *entry = entry.checked_add(n).ok_or(DiceError::TooComplex)?;
+            }
+        }
+        Ok(Self {
+            ways,
+            outcomes: self
+                .outcomes
+                .checked_mul(other.outcomes)
+                .ok_or(DiceError::TooComplex)?,
+        })
+    }
+
+    /// Applies `f` to every total.
+    fn map(&self, f: impl Fn(i64) -> i64) -> Self {
+        let mut ways = BTreeMap::new();
+        for (value, n) in &self.ways {
+            *ways.entry(f(*value)).or_insert(0) += n;
+        }
+        Self {
+            ways,
+            outcomes: self.outcomes,
+        }
+    }
+
+    /// The probability of rolling `total`.
+    pub fn probability(&self, total: i64) -> f64 {
+        self.ways.get(&total).copied().unwrap_or(0) as f64 / self.outcomes as f64
+    }
+
+    /// The probability of rolling `total` or higher.
+    pub fn at_least(&self, total: i64) -> f64 {
+        self.ways
+            .range(total..)
+            .map(|(_, n)| *n as f64)
+            .sum::<f64>()
+            / self.outcomes as f64
+    }
+
+    /// The mean total.
+    pub fn mean(&self) -> f64 {
+        self.ways
+            .iter()
+            .map(|(value, n)| *value as f64 * *n as f64)
+            .sum::<f64>()
+            / self.outcomes as f64
+    }
+}
+
+/// The result of rolling one die.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DieResult {
+    /// The die and the value it rolled.
+    pub die: Die,
+
+    /// Whether the die counts towards the total.
+    pub kept: bool,
+}
+
+/// The result of evaluating one term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermResult {
+    /// The individual dice rolled, empty for constants.
+    pub dice: Vec<DieResult>,
+
+    /// The value of the term after its scales and sign are applied.
+    pub value: i64,
+}
+
+/// The result of rolling an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollResult {
+    /// The expression that was rolled.
+    pub expression: Expression,
+
+    /// The result of each term of the expression.
+    pub terms: Vec<TermResult>,
+
+    /// The total of the roll.
+    pub total: i64,
+}
+
+impl RollResult {
+    /// Every die rolled, in order.
+    pub fn dice(&self) -> impl Iterator<Item = &DieResult> {
+        self.terms.iter().flat_map(|term| &term.dice)
+    }
+}
+
+impl Display for RollResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let breakdown = self
+            .terms
+            .iter()
+            .zip(&self.expression.terms)
+            .map(|(result, term)| match term.operand {
+                Operand::Dice(_) => {
+                    let dice = result
+                        .dice
+                        .iter()
+                        .map(|d| {
+                            if d.kept {
+                                d.die.value.to_string()
+                            } else {
+                                format!("~{}~", d.die.value)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!("[{dice}]")
+                }
+                Operand::Constant(value) => value.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        f.write_fmt(format_args!(
+            "{}: {} = {}",
+            self.expression, breakdown, self.total
+        ))
+    }
+}
+
+/// Rolls dice expressions using a seedable random number generator.
+#[derive(Debug, Clone)]
+pub struct Roller {
+    rng: StdRng,
+}
+
+impl Default for Roller {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Roller {
+    /// Creates a roller seeded from the operating system's entropy.
+    pub fn new() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Creates a roller that produces the same rolls for the same seed.
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Rolls a single die, returning the value rolled.
+    pub fn roll_die(&mut self, sides: u32) -> u32 {
+        self.rng.gen_range(1..=sides.max(1))
+    }
+
+    /// Rolls an expression.
+    pub fn roll(&mut self, expression: &Expression) -> RollResult {
+        let mut terms = Vec::with_capacity(expression.terms.len());
+        let mut total: i64 = 0;
+
+        for term in &expression.terms {
+            let result = match term.operand {
+                Operand::Dice(group) => {
+                    let mut rerolled = Vec::with_capacity(group.count as usize);
+                    let values = (0..group.count)
+                        .map(|_| {
+                            let value = self.roll_die(group.sides);
+                            if value <= group.reroll {
+                                rerolled.push(Some(value));
+                                self.roll_die(group.sides)
+                            } else {
+                                rerolled.push(None);
+                                value
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    let mask = group.keep_mask(&values);
+                    let sum: u32 = values
+                        .iter()
+                        .zip(&mask)
+                        .filter(|(_, kept)| **kept)
+                        .map(|(value, _)| value)
+                        .sum();
+                    let die = |value: u32, kept| DieResult {
+                        die: Die {
+                            num_sides: group.sides as usize,
+                            value: value as usize,
+                        },
+                        kept,
+                    };
+                    // A rerolled die shows up as a dropped die before the roll that replaced it
+                    let dice = values
+                        .iter()
+                        .zip(mask)
+                        .zip(rerolled)
+                        .flat_map(|((&value, kept), rerolled)| {
+                            rerolled
+                                .map(|value| die(value, false))
+                                .into_iter()
+                                .chain([die(value, kept)])
+                        })
+                        .collect();
+                    TermResult {
+                        dice,
+                        value: term.apply(sum as i64),
+                    }
+                }
+                Operand::Constant(value) => TermResult {
+                    dice: vec![],
+                    value: term.apply(value),
+                },
+            };
+            total = total.saturating_add(result.value);
+            terms.push(result);
+        }
+
+        RollResult {
+            expression: expression.clone(),
+            terms,
+            total,
+        }
+    }
+
+    /// Parses and rolls an expression.
+    pub fn roll_str(&mut self, expression: &str) -> Result<RollResult, DiceError> {
+        Ok(self.roll(&expression.parse()?))
+    }
+}
+
+/// Returns `n!`, or `None` if it overflows.
+fn factorial(n: u32) -> Option<u128> {
+    (1..=n as u128).try_fold(1u128, |acc, i| acc.checked_mul(i))
+}
+
+/// Returns `n choose k`, or `None` if it overflows.
+fn binomial(n: u32, k: u32) -> Option<u128> {
+    let k = k.min(n - k) as u128;
+    (0..k).try_fold(1u128, |acc, i| {
+        acc.checked_mul(n as u128 - i).map(|acc| acc / (i + 1))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(expression: &str) -> Expression {
+        expression
+            .parse()
+            .unwrap_or_else(|error| panic!("{expression}: {error}"))
+    }
+
+    /// The only group of dice of the single-term expression `expression`.
+    fn group(expression: &str) -> DiceGroup {
+        match parse(expression).terms[..] {
+            [Term {
+                operand: Operand::Dice(group),
+                ..
+            }] => group,
+            _ => panic!("{expression} isn't a single group of dice"),
+        }
+    }
+
+    fn average(expression: &str) -> f64 {
+        parse(expression).average().unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn parses_every_notation() {
+        assert_eq!(group("4d6kh3").keep, Keep::Highest(3));
+        assert_eq!(group("4d6k3").keep, Keep::Highest(3));
+        assert_eq!(group("4d6kl3").keep, Keep::Lowest(3));
+        assert_eq!(group("2d20kl1").keep, Keep::Lowest(1));
+        assert_eq!(group("4d6dl1").keep, Keep::Highest(3));
+        assert_eq!(group("4d6dh1").keep, Keep::Lowest(3));
+
+        let advantage = group("1d20adv");
+        assert_eq!((advantage.count, advantage.keep), (2, Keep::Highest(1)));
+        let disadvantage = group("1d20dis");
+        assert_eq!(
+            (disadvantage.count, disadvantage.keep),
+            (2, Keep::Lowest(1))
+        );
+
+        let reroll = group("4d6kh3r1");
+        assert_eq!((reroll.keep, reroll.reroll), (Keep::Highest(3), 1));
+
+        assert_eq!(parse("8d6/2").terms[0].scales, vec![Scale::Divide(2)]);
+        assert_eq!(parse("5d4*10").terms[0].scales, vec![Scale::Multiply(10)]);
+        assert_eq!(parse("5d4x10"), parse("5d4*10"));
+
+        let terms = parse("1d8 + 2d6 - 3").terms;
+        assert_eq!(terms.len(), 3);
+        assert!(terms[2].negative);
+        assert_eq!(terms[2].operand, Operand::Constant(3));
+    }
+
+    #[test]
+    fn displays_expressions_that_parse_back() {
+        for expression in [
+            "4d6kh3", "4d6kl3", "1d20adv", "1d20dis", "2d6r2", "4d6kh3r1", "8d6/2",
+        ] {
+            let parsed = parse(expression);
+            assert_eq!(parse(&parsed.to_string()), parsed, "{expression}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        for expression in [
+            "",
+            "d",
+            "0d6",
+            "1d0",
+            "4d6kh5",
+            "4d6dl5",
+            "2d6r6",
+            "1d6/0",
+            "1d6 +",
+            "1d6 ^ 2",
+            "1d1*4294967295*4294967295*4294967295",
+        ] {
+            assert!(
+                expression.parse::<Expression>().is_err(),
+                "{expression} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn computes_exact_distributions() {
+        let two_d6 = parse("2d6").distribution().unwrap();
+        assert_eq!(two_d6.outcomes, 36);
+        assert_eq!(two_d6.ways[&7], 6);
+        assert_close(two_d6.at_least(12), 1.0 / 36.0);
+
+        let ability = parse("4d6kh3").distribution().unwrap();
+        assert_eq!(ability.outcomes, 1296);
+        assert_eq!(ability.ways[&18], 21);
+        assert_eq!(ability.ways[&3], 1);
+        assert_eq!(parse("4d6dl1").distribution().unwrap(), ability);
+        assert_eq!(
+            parse("4d6kl3").distribution().unwrap(),
+            parse("4d6dh1").distribution().unwrap()
+        );
+
+        assert_eq!(
+            parse("1d20adv").distribution().unwrap(),
+            parse("2d20kh1").distribution().unwrap()
+        );
+    }
+
+    #[test]
+    fn computes_averages_and_bounds() {
+        assert_close(average("4d6kh3"), 15869.0 / 1296.0);
+        assert!((average("4d6kh3") - 12.24).abs() < 0.01);
+        assert_close(average("4d6kl3"), 11347.0 / 1296.0);
+        assert_close(average("1d20adv"), 13.825);
+        assert_close(average("1d20dis"), 7.175);
+        assert_close(average("2d6r2"), 25.0 / 3.0);
+        assert_close(average("5d4*10"), 125.0);
+        assert_close(average("1d4/2"), 1.0);
+        assert_close(average("1d8 + 2d6 - 3"), 8.5);
+
+        let fireball = parse("8d6/2");
+        assert_eq!((fireball.min(), fireball.max()), (4, 24));
+        let gold = parse("5d4*10");
+        assert_eq!((gold.min(), gold.max()), (50, 200));
+        let advantage = parse("1d20adv");
+        assert_eq!((advantage.min(), advantage.max()), (1, 20));
+    }
+
+    #[test]
+    fn seeded_rollers_repeat_their_rolls() {
+        for expression in [
+            "4d6kh3", "4d6kl3", "4d6dl1", "4d6dh1", "1d20adv", "1d20dis", "2d6r2", "8d6/2",
+            "5d4*10",
+        ] {
+            let expression = parse(expression);
+            let (mut a, mut b) = (Roller::seeded(7), Roller::seeded(7));
+            for _ in 0..20 {
+                assert_eq!(a.roll(&expression), b.roll(&expression));
+            }
+        }
+    }
+
+    #[test]
+    fn seeded_rolls_follow_their_notation() {
+        let mut roller = Roller::seeded(42);
+        for notation in [
+            "4d6kh3", "4d6kl3", "4d6dl1", "4d6dh1", "1d20adv", "1d20dis", "2d6r2", "8d6/2",
+            "5d4*10",
+        ] {
+            let expression = parse(notation);
+            let Operand::Dice(group) = expression.terms[0].operand else {
+                unreachable!()
+            };
+            for _ in 0..200 {
+                let roll = roller.roll(&expression);
+                assert!(
+                    (expression.min()..=expression.max()).contains(&roll.total),
+                    "{roll}"
+                );
+
+                let dice = roll.dice().collect::<Vec<_>>();
+                let (values, kept): (Vec<u32>, Vec<bool>) = if group.reroll > 0 {
+                    // Every die is kept, so the dropped dice are the rolls that were rerolled
+                    for die in dice.iter().filter(|die| !die.kept) {
+                        assert!(die.die.value as u32 <= group.reroll, "{roll}");
+                    }
+                    (dice.iter())
+                        .filter(|die| die.kept)
+                        .map(|die| (die.die.value as u32, true))
+                        .unzip()
+                } else {
+                    (dice.iter())
+                        .map(|die| (die.die.value as u32, die.kept))
+                        .unzip()
+                };
+                assert_eq!(values.len(), group.count as usize, "{roll}");
+                assert_eq!(
+                    kept.iter().filter(|kept| **kept).count(),
+                    group.kept() as usize,
+                    "{roll}"
+                );
+
+                let (kept_values, dropped_values): (Vec<_>, Vec<_>) =
+                    (values.iter().zip(&kept)).partition(|(_, kept)| **kept);
+                let kept_values = kept_values.into_iter().map(|(value, _)| *value);
+                let dropped_values = dropped_values.into_iter().map(|(value, _)| *value);
+                let sum = kept_values.clone().map(i64::from).sum::<i64>();
+                assert_eq!(expression.terms[0].apply(sum), roll.total, "{roll}");
+
+                match group.keep {
+                    Keep::Highest(_) => {
+                        assert!(dropped_values.max() <= kept_values.min(), "{roll}")
+                    }
+                    Keep::Lowest(_) => assert!(
+                        dropped_values
+                            .min()
+                            .is_none_or(|dropped| Some(dropped) >= kept_values.max()),
+                        "{roll}"
+                    ),
+                    Keep::All => {}
+                }
+            }
+        }
+    }
+}
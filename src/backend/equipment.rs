@@ -0,0 +1,184 @@
+//! Resolving the starting equipment of a character from the choices offered by its class and
+//! background, or the starting gold taken instead.
+//!
+//! Each line of starting equipment is stored under its [`choice_key`](crate::frontend::utils::choice_key),
+//! such as `class:fighter/starting_equipment[1]`. The item picked for an
+//! [`EquipmentItem::Any`] is stored under [`item_key`] of the alternative it belongs to.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::{
+    item::{EquipmentItem, ItemKind, ItemStack, StartingEquipment},
+    utils::alternative_key,
+};
+
+use super::{
+    content::ContentRegistry,
+    dice::{DiceError, Roller},
+};
+
+/// The choices made for the starting equipment of a character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EquipmentSelections {
+    /// Whether the starting equipment or the starting gold of the class is taken.
+    pub wealth: StartingWealth,
+
+    /// The index of the alternative chosen for each [`StartingEquipment::Either`], keyed by the
+    /// key of the line.
+    pub alternatives: BTreeMap<String, usize>,
+
+    /// The id of the item picked for each [`EquipmentItem::Any`], keyed by [`item_key`].
+    pub items: BTreeMap<String, String>,
+}
+
+impl EquipmentSelections {
+    /// Returns the items of the line of starting equipment stored under `key`, along with the
+    /// key the item picked for each of them is stored under, or `None` if no alternative has
+    /// been chosen yet.
+    pub fn granted<'a>(
+        &self,
+        equipment: &'a StartingEquipment,
+        key: &str,
+    ) -> Option<Vec<(String, &'a EquipmentItem)>> {
+        let (key, items) = match equipment {
+            StartingEquipment::All(items) => (key.to_string(), items),
+            StartingEquipment::Either(alternatives) => {
+                let index = *self.alternatives.get(key)?;
+                (alternative_key(key, index), alternatives.get(index)?)
+            }
+        };
+        Some(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (item_key(&key, i), item))
+                .collect(),
+        )
+    }
+
+    /// Returns `true` if an alternative has been chosen for the line of starting equipment
+    /// stored under `key`, and an item has been picked out of its category for each
+    /// [`EquipmentItem::Any`] of it.
+    pub fn is_resolved(
+        &self,
+        content: &ContentRegistry,
+        equipment: &StartingEquipment,
+        key: &str,
+    ) -> bool {
+        self.granted(equipment, key).is_some_and(|items| {
+            items
+                .iter()
+                .all(|(key, item)| self.resolve_item(content, key, item).is_some())
+        })
+    }
+
+    /// Returns the item granted by `item`, stored under `key`, or `None` if it is an
+    /// [`EquipmentItem::Any`] and no item of its category has been picked.
+    pub fn resolve_item(
+        &self,
+        content: &ContentRegistry,
+        key: &str,
+        item: &EquipmentItem,
+    ) -> Option<ItemStack> {
+        match item {
+            EquipmentItem::Item { item, quantity } => Some(ItemStack {
+                item: item.clone(),
+                quantity: *quantity,
+            }),
+            EquipmentItem::Any { category, quantity } => {
+                let picked = self.items.get(key)?;
+                content
+                    .item(picked)
+                    .is_ok_and(|item| item.is_in(category))
+                    .then(|| ItemStack {
+                        item: picked.clone(),
+                        quantity: *quantity,
+                    })
+            }
+        }
+    }
+
+    /// Returns the items granted by the line of starting equipment stored under `key`, leaving
+    /// out the ones that haven't been picked yet.
+    pub fn resolve(
+        &self,
+        content: &ContentRegistry,
+        equipment: &StartingEquipment,
+        key: &str,
+    ) -> Vec<ItemStack> {
+        self.granted(equipment, key)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(key, item)| self.resolve_item(content, &key, item))
+            .collect()
+    }
+
+    /// Chooses the alternative at `index` for the line of starting equipment stored under
+    /// `key`, discarding the items picked for the other alternatives.
+    pub fn choose(&mut self, key: &str, index: usize) {
+        self.alternatives.insert(key.to_string(), index);
+        let chosen = format!("{}[", alternative_key(key, index));
+        let alternatives = format!("{key}.");
+        self.items
+            .retain(|item, _| !item.starts_with(&alternatives) || item.starts_with(&chosen));
+    }
+}
+
+/// Whether a character starts with the equipment of its class and background, or with gold to
+/// buy equipment instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StartingWealth {
+    /// The starting equipment of the class and background.
+    #[default]
+    Equipment,
+
+    /// The starting gold of the class, once it has been rolled.
+    Gold(Option<u32>),
+}
+
+impl StartingWealth {
+    /// Rolls the starting gold given by the dice expression `dice`, such as `5d4*10`.
+    pub fn roll(dice: &str, roller: &mut Roller) -> Result<Self, DiceError> {
+        let total = roller.roll_str(dice)?.total;
+        Ok(Self::Gold(Some(total.max(0) as u32)))
+    }
+}
+
+/// Returns the key the item picked for the item at `index` of a group of starting equipment,
+/// stored under `key`, is stored under, such as `class:fighter/starting_equipment[1].0[0]`.
+pub fn item_key(key: &str, index: usize) -> String {
+    format!("{key}[{index}]")
+}
+
+/// Adds `stack` to `items`, merging it with a stack of the same item and replacing packs with
+/// their contents.
+pub fn add_stack(content: &ContentRegistry, items: &mut Vec<ItemStack>, stack: ItemStack) {
+    if let Ok(ItemKind::Pack(contents)) = content.item(&stack.item).map(|item| &item.kind) {
+        for _ in 0..stack.quantity {
+            for item in contents {
+                add_stack(content, items, item.clone());
+            }
+        }
+        return;
+    }
+    match items.iter_mut().find(|item| item.item == stack.item) {
+        Some(item) => item.quantity += stack.quantity,
+        None => items.push(stack),
+    }
+}
+
+/// The starting equipment offered by a class or background.
+#[derive(Debug, Clone)]
+pub struct EquipmentSource<'a> {
+    /// The name of the class or background.
+    pub name: &'a str,
+
+    /// Each line of starting equipment, along with the key its choices are stored under.
+    pub lines: Vec<(String, &'a StartingEquipment)>,
+
+    /// The gold pieces granted along with the equipment.
+    pub gold: u32,
+}
@@ -0,0 +1,98 @@
+//! Computing a character's maximum hit points from its hit dice, Constitution, and level.
+//!
+//! The first level always uses the highest roll of the hit die. Every level after that uses
+//! either a roll of the hit die or its fixed average, picked separately for each level.
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::class::Die;
+
+use super::dice::Roller;
+
+/// How the hit die was resolved for a level after the first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HitPointGain {
+    /// The fixed average of the hit die.
+    #[default]
+    Average,
+
+    /// The value rolled on the hit die.
+    Rolled(u8),
+}
+
+impl HitPointGain {
+    /// Rolls the hit die.
+    pub fn roll(hit_die: &Die, roller: &mut Roller) -> Self {
+        Self::Rolled(roller.roll_die(hit_die.num_sides as u32) as u8)
+    }
+
+    /// The hit points the hit die contributes.
+    pub fn amount(&self, hit_die: &Die) -> u16 {
+        match self {
+            HitPointGain::Average => average(hit_die),
+            HitPointGain::Rolled(value) => *value as u16,
+        }
+    }
+}
+
+/// The hit points gained at one level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelHitPoints {
+    pub level: u8,
+
+    /// The hit die of the class the level was taken in.
+    pub hit_die: Die,
+
+    /// How the hit die was resolved, or `None` for the first level.
+    pub gain: Option<HitPointGain>,
+
+    /// The hit points from the hit die.
+    pub die: u16,
+
+    /// The Constitution modifier.
+    pub constitution: i8,
+
+    /// The hit points from traits such as Dwarven Toughness.
+    pub bonus: u16,
+}
+
+impl LevelHitPoints {
+    /// The hit points gained at the level, which is always at least 1 before the bonus.
+    pub fn total(&self) -> u16 {
+        (self.die as i32 + self.constitution as i32).max(1) as u16 + self.bonus
+    }
+}
+
+/// The fixed average of the hit die, rounded up, such as 5 for a d8.
+pub fn average(hit_die: &Die) -> u16 {
+    hit_die.num_sides as u16 / 2 + 1
+}
+
+/// Returns the hit points gained at each level.
+///
+/// `hit_dice` holds the hit die of the class taken at each level, starting with the 1st, and
+/// `gains` how it was resolved for each level after the first, starting with the 2nd. Levels
+/// without an entry in `gains` use the average.
+pub fn levels(
+    hit_dice: &[Die],
+    constitution: i8,
+    bonus_per_level: u16,
+    gains: &[HitPointGain],
+) -> Vec<LevelHitPoints> {
+    hit_dice
+        .iter()
+        .zip(1..)
+        .map(|(hit_die, level)| {
+            let gain =
+                (level > 1).then(|| gains.get(level as usize - 2).copied().unwrap_or_default());
+            LevelHitPoints {
+                level,
+                hit_die: *hit_die,
+                gain,
+                die: gain.map_or(hit_die.num_sides as u16, |gain| gain.amount(hit_die)),
+                constitution,
+                bonus: bonus_per_level,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,508 @@
+//! A character's inventory: the stacks of items it carries, the containers they are carried in,
+//! which of them are equipped or attuned to, and how much they weigh against what the
+//! character can carry.
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::{
+    item::{ItemKind, ItemStack},
+    utils::SizeCategory,
+};
+
+use super::content::ContentRegistry;
+
+/// The most magic items a character can be attuned to at once.
+pub const MAX_ATTUNED: usize = 3;
+
+/// The items a character carries.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Inventory {
+    /// The stacks of items, in the order they were added.
+    pub entries: Vec<InventoryEntry>,
+
+    /// The id given to the next entry added.
+    next_id: u32,
+}
+
+/// A stack of identical items in an inventory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryEntry {
+    /// The id of the entry, unique within its inventory.
+    pub id: u32,
+
+    /// The id of the item.
+    pub item: String,
+
+    /// The number of items in the stack, such as the arrows left in a quiver.
+    pub quantity: u32,
+
+    /// The id of the entry of the container the stack is carried in, or `None` if it is
+    /// carried directly.
+    #[serde(default)]
+    pub container: Option<u32>,
+
+    /// Whether the items are worn or held ready to use, such as a suit of armor or a weapon.
+    #[serde(default)]
+    pub equipped: bool,
+
+    /// Whether the character is attuned to the item.
+    #[serde(default)]
+    pub attuned: bool,
+}
+
+impl Inventory {
+    /// Returns the entry with the id `id`.
+    pub fn entry(&self, id: u32) -> Option<&InventoryEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// Returns the entry with the id `id` for editing.
+    pub fn entry_mut(&mut self, id: u32) -> Option<&mut InventoryEntry> {
+        self.entries.iter_mut().find(|entry| entry.id == id)
+    }
+
+    /// Returns the entries carried directly in `container`, or carried directly by the
+    /// character if it is `None`.
+    pub fn contents(&self, container: Option<u32>) -> impl Iterator<Item = &InventoryEntry> {
+        (self.entries.iter()).filter(move |entry| entry.container == container)
+    }
+
+    /// Returns every entry along with how deeply it is nested in containers, with the contents
+    /// of each container listed right after it.
+    pub fn tree(&self) -> Vec<(usize, &InventoryEntry)> {
+        let mut tree = vec![];
+        self.push_tree(None, 0, &mut tree);
+        tree
+    }
+
+    fn push_tree<'a>(
+        &'a self,
+        container: Option<u32>,
+        depth: usize,
+        tree: &mut Vec<(usize, &'a InventoryEntry)>,
+    ) {
+        for entry in self.contents(container) {
+            tree.push((depth, entry));
+            self.push_tree(Some(entry.id), depth + 1, tree);
+        }
+    }
+
+    /// Returns the total quantity of the item with the id `item` in the inventory.
+    pub fn count(&self, item: &str) -> u32 {
+        (self.entries.iter())
+            .filter(|entry| entry.item == item)
+            .map(|entry| entry.quantity)
+            .sum()
+    }
+
+    /// Returns the number of items the character is attuned to.
+    pub fn attuned(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.attuned).count()
+    }
+
+    /// Adds `quantity` of the item with the id `item` to `container`, merging it with a stack
+    /// of the same item there that isn't equipped or attuned to, and returns the id of its
+    /// entry.
+    pub fn add(&mut self, item: &str, quantity: u32, container: Option<u32>) -> u32 {
+        let existing = self.entries.iter_mut().find(|entry| {
+            entry.item == item && entry.container == container && !entry.equipped && !entry.attuned
+        });
+        if let Some(entry) = existing {
+            entry.quantity += quantity;
+            return entry.id;
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push(InventoryEntry {
+            id,
+            item: item.to_string(),
+            quantity,
+            container,
+            equipped: false,
+            attuned: false,
+        });
+        id
+    }
+
+    /// Sets the quantity of the entry with the id `id`, removing it if `quantity` is 0.
+    pub fn set_quantity(&mut self, id: u32, quantity: u32) {
+        if quantity == 0 {
+            self.remove(id);
+        } else if let Some(entry) = self.entry_mut(id) {
+            entry.quantity = quantity;
+        }
+    }
+
+    /// Removes the entry with the id `id`. Its contents are moved to the container it was in.
+    pub fn remove(&mut self, id: u32) {
+        let Some(i) = self.entries.iter().position(|entry| entry.id == id) else {
+            return;
+        };
+        let removed = self.entries.remove(i);
+        for entry in &mut self.entries {
+            if entry.container == Some(id) {
+                entry.container = removed.container;
+            }
+        }
+    }
+
+    /// Moves the entry with the id `id` into `container`, or out of every container if it is
+    /// `None`.
+    ///
+    /// Nothing happens if `container` is the entry itself or is carried inside it.
+    pub fn move_to(&mut self, id: u32, container: Option<u32>) {
+        if container.is_some_and(|container| container == id || self.is_inside(container, id)) {
+            return;
+        }
+        if let Some(entry) = self.entry_mut(id) {
+            entry.container = container;
+        }
+    }
+
+    /// Returns `true` if the entry with the id `id` is carried in `container`, directly or
+    /// inside other containers.
+    pub fn is_inside(&self, id: u32, container: u32) -> bool {
+        let mut current = self.entry(id).and_then(|entry| entry.container);
+        while let Some(parent) = current {
+            if parent == container {
+                return true;
+            }
+            current = self.entry(parent).and_then(|entry| entry.container);
+        }
+        false
+    }
+
+    /// Returns the weight of the entry with the id `id` in pounds, including its contents unless
+    /// it is a container whose contents weigh nothing.
+    pub fn weight(&self, content: &ContentRegistry, id: u32) -> f32 {
+        let Some(entry) = self.entry(id) else {
+            return 0.0;
+        };
+        let Ok(item) = content.item(&entry.item) else {
+            return 0.0;
+        };
+        let contents = match &item.kind {
+            ItemKind::Container(container) if container.weightless => 0.0,
+            _ => self.contents_weight(content, id),
+        };
+        item.weight_of(entry.quantity) + contents
+    }
+
+    /// Returns the weight of the contents of the entry with the id `id` in pounds, to compare
+    /// against its capacity.
+    pub fn contents_weight(&self, content: &ContentRegistry, id: u32) -> f32 {
+        self.contents(Some(id))
+            .map(|entry| self.weight(content, entry.id))
+            .sum()
+    }
+
+    /// Returns the total weight the character carries in pounds.
+    pub fn carried_weight(&self, content: &ContentRegistry) -> f32 {
+        self.contents(None)
+            .map(|entry| self.weight(content, entry.id))
+            .sum()
+    }
+
+    /// Adds `stack`, with the contents of a pack carried in the first container it includes,
+    /// such as the backpack of an explorer's pack, as long as they fit. Weapons, armor, and
+    /// spellcasting foci are equipped.
+    pub fn add_stack(&mut self, content: &ContentRegistry, stack: &ItemStack) {
+        let Ok(item) = content.item(&stack.item) else {
+            return;
+        };
+        match &item.kind {
+            ItemKind::Pack(contents) => {
+                let container =
+                    contents
+                        .iter()
+                        .find_map(|stack| match &content.item(&stack.item).ok()?.kind {
+                            ItemKind::Container(container) => Some((&stack.item, container)),
+                            _ => None,
+                        });
+                for _ in 0..stack.quantity {
+                    let id = container.map(|(item, _)| self.push(item, 1));
+                    for stack in contents {
+                        if container.is_some_and(|(item, _)| item == &stack.item) {
+                            continue;
+                        }
+                        // Items that don't fit, such as rope, are strapped to the outside
+                        let fits = id.zip(container).filter(|(id, (_, container))| {
+                            let weight = content
+                                .item(&stack.item)
+                                .map_or(0.0, |item| item.weight_of(stack.quantity));
+                            self.contents_weight(content, *id) + weight <= container.capacity
+                        });
+                        self.add(&stack.item, stack.quantity, fits.map(|(id, _)| id));
+                    }
+                }
+            }
+            ItemKind::Weapon(_) | ItemKind::Armor(_) | ItemKind::Focus(_) => {
+                let id = self.push(&item.id, stack.quantity);
+                if let Some(entry) = self.entry_mut(id) {
+                    entry.equipped = true;
+                }
+            }
+            ItemKind::Container(_) => {
+                self.push(&item.id, stack.quantity);
+            }
+            _ => {
+                self.add(&item.id, stack.quantity, None);
+            }
+        }
+    }
+
+    /// Adds a new entry carried directly by the character, without merging it with another
+    /// stack, and returns its id.
+    fn push(&mut self, item: &str, quantity: u32) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push(InventoryEntry {
+            id,
+            item: item.to_string(),
+            quantity,
+            container: None,
+            equipped: false,
+            attuned: false,
+        });
+        id
+    }
+}
+
+/// The rules used to decide how much a character can carry before it slows down.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EncumbranceRule {
+    /// A character can carry up to its carrying capacity without penalty.
+    #[default]
+    Standard,
+
+    /// A character is slowed down once it carries more than 5 times its Strength score.
+    Variant,
+}
+
+/// How much a character is slowed down by the weight it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Encumbrance {
+    Unencumbered,
+
+    /// Carrying more than 5 times the Strength score, with the variant rule.
+    Encumbered,
+
+    /// Carrying more than 10 times the Strength score, with the variant rule.
+    HeavilyEncumbered,
+
+    /// Pushing or dragging more than the carrying capacity.
+    OverCapacity,
+
+    /// Carrying more than twice the carrying capacity.
+    Immobile,
+}
+
+impl Encumbrance {
+    /// Returns how much a character of `size` with `strength` is slowed down by carrying
+    /// `weight` pounds under `rule`.
+    pub fn of(rule: EncumbranceRule, weight: f32, strength: u8, size: &SizeCategory) -> Self {
+        let capacity = carrying_capacity(strength, size);
+        let threshold = |times: f32| strength as f32 * times * size_multiplier(size);
+        if weight > capacity * 2.0 {
+            Self::Immobile
+        } else if weight > capacity {
+            Self::OverCapacity
+        } else if rule == EncumbranceRule::Variant && weight > threshold(10.0) {
+            Self::HeavilyEncumbered
+        } else if rule == EncumbranceRule::Variant && weight > threshold(5.0) {
+            Self::Encumbered
+        } else {
+            Self::Unencumbered
+        }
+    }
+
+    /// Applies the encumbrance to a speed in feet.
+    pub fn apply(&self, speed: u16) -> u16 {
+        match self {
+            Encumbrance::Unencumbered => speed,
+            Encumbrance::Encumbered => speed.saturating_sub(10),
+            Encumbrance::HeavilyEncumbered => speed.saturating_sub(20),
+            Encumbrance::OverCapacity => speed.min(5),
+            Encumbrance::Immobile => 0,
+        }
+    }
+
+    /// Describes the effects of the encumbrance, or `None` if there are none.
+    pub fn describe(&self) -> Option<&'static str> {
+        match self {
+            Encumbrance::Unencumbered => None,
+            Encumbrance::Encumbered => Some("Encumbered: your speed drops by 10 feet."),
+            Encumbrance::HeavilyEncumbered => Some(
+                "Heavily encumbered: your speed drops by 20 feet, and you have disadvantage on \
+                 ability checks, attack rolls, and saving throws that use Strength, Dexterity, or \
+                 Constitution.",
+            ),
+            Encumbrance::OverCapacity => {
+                Some("Over your carrying capacity: your speed drops to 5 feet.")
+            }
+            Encumbrance::Immobile => {
+                Some("More than twice your carrying capacity: you can't move the load.")
+            }
+        }
+    }
+}
+
+/// Returns the weight in pounds a creature of `size` with `strength` can carry: 15 times its
+/// Strength score, doubled for each size category above Medium and halved for Tiny.
+pub fn carrying_capacity(strength: u8, size: &SizeCategory) -> f32 {
+    strength as f32 * 15.0 * size_multiplier(size)
+}
+
+/// How much the size of a creature multiplies the weight it can carry.
+fn size_multiplier(size: &SizeCategory) -> f32 {
+    match size {
+        SizeCategory::Tiny => 0.5,
+        SizeCategory::Small | SizeCategory::Medium => 1.0,
+        SizeCategory::Large => 2.0,
+        // Huge creatures would carry 4 times as much
+        SizeCategory::Gargantuan => 8.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(item: &str, quantity: u32) -> ItemStack {
+        ItemStack {
+            item: item.to_string(),
+            quantity,
+        }
+    }
+
+    /// Returns the ids of the items carried directly in `container`.
+    fn items(inventory: &Inventory, container: Option<u32>) -> Vec<&str> {
+        (inventory.contents(container))
+            .map(|entry| entry.item.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn packs_fill_their_container_up_to_its_capacity() {
+        let content = ContentRegistry::builtin();
+        let mut inventory = Inventory::default();
+        inventory.add_stack(&content, &stack("dungeoneers-pack", 1));
+
+        let backpack = inventory.entries[0].id;
+        assert_eq!(
+            items(&inventory, None),
+            ["backpack", "rations", "hempen-rope"]
+        );
+        assert_eq!(
+            items(&inventory, Some(backpack)),
+            [
+                "crowbar",
+                "hammer",
+                "piton",
+                "torch",
+                "tinderbox",
+                "waterskin"
+            ]
+        );
+        assert_eq!(inventory.contents_weight(&content, backpack), 26.5);
+        assert_eq!(inventory.carried_weight(&content), 61.5);
+    }
+
+    #[test]
+    fn equips_weapons_and_armor_and_merges_other_stacks() {
+        let content = ContentRegistry::builtin();
+        let mut inventory = Inventory::default();
+        inventory.add_stack(&content, &stack("longsword", 1));
+        inventory.add_stack(&content, &stack("longsword", 1));
+        inventory.add_stack(&content, &stack("arrow", 20));
+        inventory.add_stack(&content, &stack("arrow", 20));
+
+        assert_eq!(items(&inventory, None), ["longsword", "longsword", "arrow"]);
+        assert!(inventory.entries[0].equipped && inventory.entries[1].equipped);
+        assert!(!inventory.entries[2].equipped);
+        assert_eq!(inventory.count("arrow"), 40);
+    }
+
+    #[test]
+    fn removing_a_container_moves_its_contents_out() {
+        let mut inventory = Inventory::default();
+        let chest = inventory.add("chest", 1, None);
+        let backpack = inventory.add("backpack", 1, Some(chest));
+        let torch = inventory.add("torch", 1, Some(backpack));
+
+        inventory.remove(backpack);
+        assert_eq!(inventory.entry(torch).unwrap().container, Some(chest));
+        inventory.set_quantity(chest, 0);
+        assert_eq!(inventory.entry(torch).unwrap().container, None);
+        assert_eq!(items(&inventory, None), ["torch"]);
+    }
+
+    #[test]
+    fn containers_cant_be_moved_inside_themselves() {
+        let mut inventory = Inventory::default();
+        let chest = inventory.add("chest", 1, None);
+        let backpack = inventory.add("backpack", 1, Some(chest));
+        let pouch = inventory.add("pouch", 1, Some(backpack));
+
+        assert!(inventory.is_inside(pouch, chest));
+        assert!(!inventory.is_inside(chest, pouch));
+
+        inventory.move_to(chest, Some(pouch));
+        inventory.move_to(chest, Some(chest));
+        assert_eq!(inventory.entry(chest).unwrap().container, None);
+
+        inventory.move_to(pouch, None);
+        inventory.move_to(chest, Some(pouch));
+        assert_eq!(inventory.entry(chest).unwrap().container, Some(pouch));
+    }
+
+    #[test]
+    fn weightless_containers_ignore_the_weight_of_their_contents() {
+        let content = ContentRegistry::builtin();
+        let mut inventory = Inventory::default();
+        let bag = inventory.add("bag-of-holding", 1, None);
+        inventory.add("hempen-rope", 1, Some(bag));
+        let backpack = inventory.add("backpack", 1, None);
+        inventory.add("hempen-rope", 1, Some(backpack));
+
+        assert_eq!(inventory.weight(&content, bag), 15.0);
+        assert_eq!(inventory.contents_weight(&content, bag), 10.0);
+        assert_eq!(inventory.weight(&content, backpack), 15.0);
+        assert_eq!(inventory.carried_weight(&content), 30.0);
+    }
+
+    #[test]
+    fn variant_encumbrance_starts_at_5_and_10_times_strength() {
+        use Encumbrance::*;
+        let of = |rule, weight| Encumbrance::of(rule, weight, 10, &SizeCategory::Medium);
+
+        assert_eq!(of(EncumbranceRule::Variant, 50.0), Unencumbered);
+        assert_eq!(of(EncumbranceRule::Variant, 51.0), Encumbered);
+        assert_eq!(of(EncumbranceRule::Variant, 100.0), Encumbered);
+        assert_eq!(of(EncumbranceRule::Variant, 101.0), HeavilyEncumbered);
+        assert_eq!(of(EncumbranceRule::Variant, 151.0), OverCapacity);
+        assert_eq!(of(EncumbranceRule::Variant, 301.0), Immobile);
+
+        assert_eq!(of(EncumbranceRule::Standard, 150.0), Unencumbered);
+        assert_eq!(of(EncumbranceRule::Standard, 151.0), OverCapacity);
+        assert_eq!(of(EncumbranceRule::Standard, 300.0), OverCapacity);
+        assert_eq!(of(EncumbranceRule::Standard, 301.0), Immobile);
+    }
+
+    #[test]
+    fn size_changes_the_carrying_capacity_and_thresholds() {
+        assert_eq!(carrying_capacity(10, &SizeCategory::Tiny), 75.0);
+        assert_eq!(carrying_capacity(10, &SizeCategory::Small), 150.0);
+        assert_eq!(carrying_capacity(10, &SizeCategory::Large), 300.0);
+        assert_eq!(
+            Encumbrance::of(EncumbranceRule::Variant, 101.0, 10, &SizeCategory::Large),
+            Encumbrance::Encumbered
+        );
+        assert_eq!(Encumbrance::Encumbered.apply(30), 20);
+        assert_eq!(Encumbrance::HeavilyEncumbered.apply(15), 0);
+        assert_eq!(Encumbrance::OverCapacity.apply(30), 5);
+        assert_eq!(Encumbrance::Immobile.apply(30), 0);
+    }
+}
@@ -0,0 +1,322 @@
+//! Advancing a saved character by one level.
+//!
+//! The choices for the new level are made on a copy of the character, and the changelog
+//! entry is worked out by comparing that copy with the character before it gained the level.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::class::{ClassFeature, Preparation};
+
+use super::{
+    abilities::Improvement,
+    character::{proficiency_bonus, Character, MAX_LEVEL},
+    content::{ContentKind, ContentRegistry},
+    hit_points::HitPointGain,
+    multiclass::{ClassLevels, MulticlassError},
+    spellcasting::{ClassSpellcasting, SpellList},
+};
+
+/// What changed when a character gained a level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    /// The level the character reached.
+    pub level: u8,
+
+    /// A description of each change.
+    pub changes: Vec<String>,
+}
+
+/// Errors that can occur while levelling up a character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LevelUpError {
+    /// The character has no level to build on, such as a saved file with a level of 0.
+    NoLevel,
+
+    /// The character is already at [`MAX_LEVEL`].
+    MaxLevel,
+
+    /// Selections are still missing, either from the character or from the new level.
+    Incomplete(Vec<String>),
+}
+
+impl Display for LevelUpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelUpError::NoLevel => f.write_str("The character has no level"),
+            LevelUpError::MaxLevel => {
+                f.write_fmt(format_args!("The character is already level {}", MAX_LEVEL))
+            }
+            LevelUpError::Incomplete(missing) => {
+                f.write_fmt(format_args!("Still missing: {}", missing.join(", ")))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LevelUpError {}
+
+/// A character in the middle of gaining a level.
+#[derive(Debug, Clone)]
+pub struct LevelUp {
+    /// The character before gaining the level.
+    before: Character,
+
+    /// The character at the new level, where the choices for the level are made.
+    pub draft: Character,
+}
+
+impl LevelUp {
+    /// Starts levelling up a character.
+    ///
+    /// Only complete characters of at least level 1 and below [`MAX_LEVEL`] can gain a level.
+    pub fn new(draft: Character, content: &ContentRegistry) -> Result<Self, LevelUpError> {
+        if draft.level == 0 {
+            return Err(LevelUpError::NoLevel);
+        }
+        if draft.level >= MAX_LEVEL {
+            return Err(LevelUpError::MaxLevel);
+        }
+        let missing = draft.missing(content);
+        if !missing.is_empty() {
+            return Err(LevelUpError::Incomplete(missing));
+        }
+
+        let mut next = draft.clone();
+        next.level += 1;
+        Ok(Self {
+            before: draft,
+            draft: next,
+        })
+    }
+
+    /// The character before gaining the level.
+    pub fn before(&self) -> &Character {
+        &self.before
+    }
+
+    /// The level being gained.
+    pub fn level(&self) -> u8 {
+        self.draft.level
+    }
+
+    /// The id of the class the level is taken in.
+    pub fn class(&self) -> Option<&str> {
+        self.draft.class_at(self.level())
+    }
+
+    /// Takes the level in the class with the id `class` instead, multiclassing into it if
+    /// needed.
+    ///
+    /// The choices made for the level so far are discarded. Fails without changing anything if
+    /// the character doesn't meet the prerequisites for multiclassing into the class.
+    pub fn set_class(
+        &mut self,
+        content: &ContentRegistry,
+        class: &str,
+    ) -> Result<(), MulticlassError> {
+        let mut next = self.before.clone();
+        next.level += 1;
+        next.take_level(content, class)?;
+        self.draft = next;
+        Ok(())
+    }
+
+    /// Returns the class the level is taken in, with the number of levels the character will
+    /// have in it.
+    pub fn class_levels<'a>(&self, content: &'a ContentRegistry) -> Option<ClassLevels<'a>> {
+        let class = self.class()?;
+        self.draft
+            .class_levels(content)
+            .into_iter()
+            .find(|class_levels| class_levels.class.id == class)
+    }
+
+    /// Returns the class and subclass features gained at the new level.
+    pub fn features<'a>(&self, content: &'a ContentRegistry) -> Vec<&'a ClassFeature> {
+        self.class_levels(content)
+            .into_iter()
+            .flat_map(|class_levels| class_levels.features_at(class_levels.level))
+            .collect()
+    }
+
+    /// Returns how the class the level is taken in casts spells, if it does at the new level.
+    pub fn spellcasting<'a>(&self, content: &'a ContentRegistry) -> Option<ClassSpellcasting<'a>> {
+        let class = self.class()?;
+        self.draft
+            .spellcasting(content)
+            .into_iter()
+            .find(|casting| casting.class.id == class)
+    }
+
+    /// Returns the name and value of each column of the class table at the new level of the
+    /// class.
+    pub fn class_table(&self, content: &ContentRegistry) -> Vec<(String, String)> {
+        let Some(class_levels) = self.class_levels(content) else {
+            return vec![];
+        };
+        let table = &class_levels.class.class_table;
+        table
+            .columns
+            .iter()
+            .filter_map(|column| {
+                let value = table.value(column, class_levels.level)?;
+                Some((column.clone(), value.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns what still needs to be selected before the level can be gained.
+    pub fn missing(&self, content: &ContentRegistry) -> Vec<String> {
+        self.draft.missing(content)
+    }
+
+    /// Describes everything that changes by gaining the level with the current choices.
+    pub fn changes(&self, content: &ContentRegistry) -> Vec<String> {
+        let level = self.level();
+        let mut changes = vec![];
+
+        if let Some(class_levels) = self.class_levels(content) {
+            let class = class_levels.class;
+            if class_levels.level == 1 {
+                changes.push(format!("Multiclassed into {}", class.name));
+            } else if !self.draft.multiclasses.is_empty() {
+                changes.push(format!(
+                    "Advanced to {} level {}",
+                    class.name, class_levels.level
+                ));
+            }
+        }
+
+        if let (Some(before), Some(after)) = (
+            self.before.max_hit_points(content),
+            self.draft.max_hit_points(content),
+        ) {
+            let gain = self
+                .draft
+                .hit_point_gains
+                .get(level as usize - 2)
+                .copied()
+                .unwrap_or_default();
+            let how = match gain {
+                HitPointGain::Average => "took the average".to_string(),
+                HitPointGain::Rolled(value) => format!("rolled {value}"),
+            };
+            changes.push(format!(
+                "Maximum hit points increased from {before} to {after} ({how})"
+            ));
+        }
+
+        let (before, after) = (proficiency_bonus(level - 1), proficiency_bonus(level));
+        if before != after {
+            changes.push(format!(
+                "Proficiency bonus increased from +{before} to +{after}"
+            ));
+        }
+
+        if let Some(class) = self.class() {
+            if self.draft.subclass_of(class) != self.before.subclass_of(class) {
+                if let Some(subclass) = self
+                    .draft
+                    .subclass_of(class)
+                    .and_then(|id| content.subclass(id).ok())
+                {
+                    changes.push(format!("Chose the {} subclass", subclass.name));
+                }
+            }
+        }
+
+        let proficiencies = self.before.proficiencies(content);
+        for proficiency in self.draft.proficiencies(content) {
+            if !proficiencies.contains(&proficiency) {
+                changes.push(format!("Gained proficiency with {proficiency}"));
+            }
+        }
+
+        for feature in self.features(content) {
+            changes.push(format!("Gained {}", feature.name));
+        }
+
+        if let Some(improvement) = self.draft.improvements.get(&level) {
+            if self.draft.improvement_levels(content).contains(&level) {
+                changes.push(match improvement {
+                    Improvement::Feat(choice) if self.draft.campaign.feats => {
+                        let name = content.name_of(ContentKind::Feat, &choice.feat);
+                        match choice.ability {
+                            Some(ability) => format!("Took the {name} feat (+1 {ability})"),
+                            None => format!("Took the {name} feat"),
+                        }
+                    }
+                    improvement => format!("Ability Score Improvement: {improvement}"),
+                });
+            }
+        }
+
+        let expertise = self.before.expertise(content);
+        for proficiency in self.draft.expertise(content) {
+            if !expertise.contains(&proficiency) {
+                changes.push(format!("Gained expertise in {proficiency}"));
+            }
+        }
+
+        for feature in self.draft.features(content) {
+            let picked = self.before.feature_options(content, feature);
+            for option in self.draft.feature_options(content, feature) {
+                if !picked.contains(&option) {
+                    changes.push(format!("{}: {}", feature.name, option.name));
+                }
+            }
+        }
+
+        let slots = self.draft.spell_slots(content);
+        if slots != self.before.spell_slots(content) {
+            changes.push(format!("Spell slots: {slots}"));
+        }
+
+        if let Some(casting) = self.spellcasting(content) {
+            let class = &casting.class.id;
+            for list in casting.lists() {
+                let picked = self.before.spell_selection(content, class, list);
+                for spell in self.draft.spell_selection(content, class, list) {
+                    if !picked.contains(&spell) {
+                        changes.push(match list {
+                            SpellList::Cantrips => format!("Learned the {} cantrip", spell.name),
+                            SpellList::Spellbook => {
+                                format!("Copied {} into the spellbook", spell.name)
+                            }
+                            SpellList::Spells
+                                if casting.spellcasting.preparation == Preparation::Known =>
+                            {
+                                format!("Learned {}", spell.name)
+                            }
+                            SpellList::Spells => format!("Prepared {}", spell.name),
+                        });
+                    }
+                }
+            }
+        }
+
+        changes
+    }
+
+    /// Gains the level, returning the updated character with the changelog entry for the level
+    /// added to it.
+    pub fn finish(
+        self,
+        content: &ContentRegistry,
+    ) -> Result<(Character, ChangelogEntry), LevelUpError> {
+        let missing = self.missing(content);
+        if !missing.is_empty() {
+            return Err(LevelUpError::Incomplete(missing));
+        }
+
+        let entry = ChangelogEntry {
+            level: self.level(),
+            changes: self.changes(content),
+        };
+        let mut draft = self.draft;
+        draft.changelog.push(entry.clone());
+        Ok((draft, entry))
+    }
+}
@@ -1 +1,16 @@
+pub mod abilities;
+pub mod armor_class;
+pub mod attacks;
 pub mod character;
+pub mod content;
+pub mod currency;
+pub mod dice;
+pub mod equipment;
+pub mod hit_points;
+pub mod inventory;
+pub mod level_up;
+pub mod multiclass;
+pub mod proficiencies;
+pub mod skills;
+pub mod spellcasting;
+pub mod storage;
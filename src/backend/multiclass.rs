@@ -0,0 +1,115 @@
+//! Taking levels in more than one class.
+//!
+//! A character starts with the class of
+//! [`Character::class`](super::character::Character::class), and every level not taken in one
+//! of its [`Multiclass`]es is a level in that class.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::class::{AbilityPrerequisite, Class, ClassFeature, Subclass};
+
+/// A class a character has multiclassed into.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Multiclass {
+    /// The id of the class.
+    pub class: String,
+
+    /// The id of the selected subclass.
+    pub subclass: Option<String>,
+
+    /// The character levels at which a level in the class was taken, in ascending order.
+    pub levels: Vec<u8>,
+}
+
+/// One of a character's classes, along with the levels taken in it.
+#[derive(Debug, Clone, Copy)]
+pub struct ClassLevels<'a> {
+    pub class: &'a Class,
+
+    /// The selected subclass, if any.
+    pub subclass: Option<&'a Subclass>,
+
+    /// The number of levels taken in the class.
+    pub level: u8,
+}
+
+impl<'a> ClassLevels<'a> {
+    /// Returns the class and subclass features gained at or below the class level.
+    pub fn features(self) -> impl Iterator<Item = &'a ClassFeature> {
+        let level = self.level;
+        self.class.features_up_to(level).chain(
+            self.subclass
+                .into_iter()
+                .flat_map(move |subclass| subclass.features_up_to(level)),
+        )
+    }
+
+    /// Returns the class and subclass features gained at exactly `level` of the class.
+    pub fn features_at(self, level: u8) -> impl Iterator<Item = &'a ClassFeature> {
+        self.class.features_at(level).chain(
+            self.subclass
+                .into_iter()
+                .flat_map(move |subclass| subclass.features_at(level)),
+        )
+    }
+
+    /// Returns `true` if `feature` is one of the features of the class or its subclass.
+    pub fn provides(&self, feature: &ClassFeature) -> bool {
+        self.class.features.iter().any(|f| f.id == feature.id)
+            || self
+                .subclass
+                .is_some_and(|subclass| subclass.features.iter().any(|f| f.id == feature.id))
+    }
+}
+
+/// Why a character can't take a level in a class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MulticlassError {
+    /// No class with the given id is registered.
+    UnknownClass(String),
+
+    /// Not every base ability score has been assigned, so prerequisites can't be checked.
+    MissingScores,
+
+    /// The ability scores don't meet a prerequisite of the class being multiclassed into.
+    Into {
+        class: String,
+        prerequisite: AbilityPrerequisite,
+    },
+
+    /// The ability scores don't meet a prerequisite of one of the classes the character already
+    /// has, which is needed to leave it for another.
+    OutOf {
+        class: String,
+        prerequisite: AbilityPrerequisite,
+    },
+}
+
+impl Display for MulticlassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MulticlassError::UnknownClass(id) => {
+                f.write_fmt(format_args!("No class with the id `{id}` exists"))
+            }
+            MulticlassError::MissingScores => {
+                f.write_str("Assign every ability score before multiclassing")
+            }
+            MulticlassError::Into {
+                class,
+                prerequisite,
+            } => f.write_fmt(format_args!(
+                "Multiclassing into {class} requires {prerequisite}"
+            )),
+            MulticlassError::OutOf {
+                class,
+                prerequisite,
+            } => f.write_fmt(format_args!(
+                "Multiclassing out of {class} requires {prerequisite}"
+            )),
+        }
+    }
+}
+
+impl std::error::Error for MulticlassError {}
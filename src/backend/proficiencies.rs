@@ -0,0 +1,89 @@
+//! Collecting the proficiencies granted to a character and detecting duplicate grants.
+//!
+//! When the same skill or tool proficiency is granted by more than one source, the character may
+//! pick a different proficiency of the same kind to replace each extra grant.
+
+use crate::frontend::utils::{proficiency::Proficiency, Choices};
+
+/// A proficiency granted by a source, such as a race, class, or trait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grant {
+    pub proficiency: Proficiency,
+
+    /// The name of the source that grants the proficiency.
+    pub source: String,
+}
+
+/// Proficiency choices offered by a source, such as a race or class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProficiencyChoice<'a> {
+    /// The key the selection is stored under in the character.
+    pub key: String,
+
+    /// The name of the source that offers the choices.
+    pub source: &'a str,
+
+    pub choices: &'a Choices<Proficiency>,
+}
+
+/// A proficiency that is granted by more than one source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Duplicate {
+    pub proficiency: Proficiency,
+
+    /// The names of the sources that grant the proficiency, in the order they were granted.
+    pub sources: Vec<String>,
+}
+
+impl Duplicate {
+    /// Returns `true` if a replacement may be picked for each extra grant.
+    pub fn replaceable(&self) -> bool {
+        !Proficiency::replacements(self.proficiency.kind()).is_empty()
+    }
+
+    /// The keys that replacements for the extra grants are stored under.
+    ///
+    /// There is one slot for every source after the first, named `{id}#{n}`.
+    pub fn slots(&self) -> Vec<String> {
+        (1..self.sources.len())
+            .map(|n| format!("{}#{}", self.proficiency.id(), n))
+            .collect()
+    }
+}
+
+/// Returns every proficiency granted more than once, in the order they were first granted.
+pub fn duplicates(grants: &[Grant]) -> Vec<Duplicate> {
+    let mut duplicates: Vec<Duplicate> = vec![];
+    for (i, grant) in grants.iter().enumerate() {
+        let first = grants
+            .iter()
+            .position(|g| g.proficiency == grant.proficiency)
+            .unwrap_or(i);
+        if first == i {
+            continue;
+        }
+
+        match duplicates
+            .iter_mut()
+            .find(|d| d.proficiency == grant.proficiency)
+        {
+            Some(duplicate) => duplicate.sources.push(grant.source.clone()),
+            None => duplicates.push(Duplicate {
+                proficiency: grant.proficiency.clone(),
+                sources: vec![grants[first].source.clone(), grant.source.clone()],
+            }),
+        }
+    }
+    duplicates
+}
+
+/// Returns the unique proficiencies in `grants`, in the order they were first granted.
+pub fn unique(grants: &[Grant]) -> Vec<Proficiency> {
+    let mut proficiencies: Vec<Proficiency> = vec![];
+    for grant in grants {
+        if !proficiencies.contains(&grant.proficiency) {
+            proficiencies.push(grant.proficiency.clone());
+        }
+    }
+    proficiencies
+}
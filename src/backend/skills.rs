@@ -0,0 +1,175 @@
+//! Computing the modifiers of skill checks, saving throws, and passive scores.
+//!
+//! A character adds its proficiency bonus to checks and saves it is proficient in, and double its
+//! proficiency bonus to checks made with a proficiency it has expertise in.
+
+use std::fmt::Display;
+
+use crate::frontend::utils::{
+    proficiency::{Proficiency, Skill},
+    Effect, SituationalModifier,
+};
+
+use super::abilities::{Ability, AbilityScores};
+
+/// The skills that have a passive score listed on the character sheet.
+pub const PASSIVE_SKILLS: [Skill; 3] = [Skill::Perception, Skill::Investigation, Skill::Insight];
+
+/// How well trained a character is with a skill or saving throw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Training {
+    Untrained,
+    Proficient,
+
+    /// Proficient, with the proficiency bonus doubled.
+    Expertise,
+}
+
+impl Training {
+    /// The bonus added to rolls for the training level.
+    pub fn bonus(&self, proficiency_bonus: u8) -> i8 {
+        match self {
+            Training::Untrained => 0,
+            Training::Proficient => proficiency_bonus as i8,
+            Training::Expertise => 2 * proficiency_bonus as i8,
+        }
+    }
+}
+
+impl Display for Training {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Training::Untrained => f.write_str("Untrained"),
+            Training::Proficient => f.write_str("Proficient"),
+            Training::Expertise => f.write_str("Expertise"),
+        }
+    }
+}
+
+/// The modifier of checks made with a skill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillCheck {
+    pub skill: Skill,
+    pub training: Training,
+
+    /// The total modifier added to the check.
+    pub modifier: i8,
+
+    /// Bonuses that only apply to some checks made with the skill.
+    pub notes: Vec<String>,
+}
+
+impl SkillCheck {
+    /// The passive score of the skill, used when the character doesn't roll.
+    pub fn passive(&self) -> i8 {
+        10 + self.modifier
+    }
+}
+
+/// The modifier of saving throws made with an ability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SavingThrow {
+    pub ability: Ability,
+    pub proficient: bool,
+
+    /// The total modifier added to the saving throw.
+    pub modifier: i8,
+}
+
+/// Everything the modifiers of a character's checks and saves are computed from.
+#[derive(Debug, Clone)]
+pub struct Checks {
+    /// The final ability scores, with every increase applied.
+    pub scores: AbilityScores,
+
+    pub proficiency_bonus: u8,
+    pub proficiencies: Vec<Proficiency>,
+
+    /// The proficiencies the character has expertise in. Ones the character isn't proficient in
+    /// are ignored.
+    pub expertise: Vec<Proficiency>,
+
+    /// The situational bonuses provided by the character's traits.
+    pub situational: Vec<SituationalModifier>,
+}
+
+impl Checks {
+    /// Collects the situational bonuses out of `effects`.
+    pub fn situational<'a>(
+        effects: impl IntoIterator<Item = &'a Effect>,
+    ) -> Vec<SituationalModifier> {
+        effects
+            .into_iter()
+            .filter_map(|effect| match effect {
+                Effect::Situational(modifier) => Some(modifier.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns how well trained the character is with `proficiency`.
+    pub fn training(&self, proficiency: &Proficiency) -> Training {
+        if !self.proficiencies.contains(proficiency) {
+            Training::Untrained
+        } else if self.expertise.contains(proficiency) {
+            Training::Expertise
+        } else {
+            Training::Proficient
+        }
+    }
+
+    /// Returns the modifier of checks made with `skill`.
+    pub fn skill(&self, skill: Skill) -> SkillCheck {
+        let training = self.training(&Proficiency::Skill(skill));
+        SkillCheck {
+            skill,
+            training,
+            modifier: self.scores.modifier(skill.ability())
+                + training.bonus(self.proficiency_bonus),
+            notes: self
+                .situational
+                .iter()
+                .filter(|modifier| modifier.skill == skill)
+                .map(|modifier| modifier.note.clone())
+                .collect(),
+        }
+    }
+
+    /// Returns the modifiers of every skill, in alphabetical order.
+    pub fn skills(&self) -> Vec<SkillCheck> {
+        Skill::ALL
+            .into_iter()
+            .map(|skill| self.skill(skill))
+            .collect()
+    }
+
+    /// Returns the modifier of saving throws made with `ability`.
+    pub fn saving_throw(&self, ability: Ability) -> SavingThrow {
+        let proficient = self
+            .proficiencies
+            .contains(&Proficiency::SavingThrow(ability));
+        let bonus = if proficient {
+            self.proficiency_bonus as i8
+        } else {
+            0
+        };
+        SavingThrow {
+            ability,
+            proficient,
+            modifier: self.scores.modifier(ability) + bonus,
+        }
+    }
+
+    /// Returns the modifiers of every saving throw.
+    pub fn saving_throws(&self) -> Vec<SavingThrow> {
+        Ability::ALL
+            .into_iter()
+            .map(|ability| self.saving_throw(ability))
+            .collect()
+    }
+
+    /// Returns the passive score of `skill`.
+    pub fn passive(&self, skill: Skill) -> i8 {
+        self.skill(skill).passive()
+    }
+}
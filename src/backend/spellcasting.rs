@@ -0,0 +1,479 @@
+//! Spell slots, spell counts, and spellcasting modifiers of a character's spellcasting classes.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::frontend::class::{ordinal, Class, Preparation, Progression, Spellcasting};
+
+use super::abilities::AbilityScores;
+
+/// The spell slots of each level, 1st through 9th, that a full caster has at each class level,
+/// starting at 1st level.
+pub const FULL_CASTER_SLOTS: [[u8; 9]; 20] = [
+    [2, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 0, 0, 0, 0, 0, 0, 0, 0],
+    [4, 2, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 0, 0, 0, 0, 0, 0, 0],
+    [4, 3, 2, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 0, 0, 0, 0, 0, 0],
+    [4, 3, 3, 1, 0, 0, 0, 0, 0],
+    [4, 3, 3, 2, 0, 0, 0, 0, 0],
+    [4, 3, 3, 3, 1, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 0, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 0, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 0, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 0],
+    [4, 3, 3, 3, 2, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 1, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 1, 1, 1],
+    [4, 3, 3, 3, 3, 2, 2, 1, 1],
+];
+
+/// The number of Pact Magic slots and their level at each Warlock level, starting at 1st level.
+pub const PACT_MAGIC_SLOTS: [(u8, u8); 20] = [
+    (1, 1),
+    (2, 1),
+    (2, 2),
+    (2, 2),
+    (2, 3),
+    (2, 3),
+    (2, 4),
+    (2, 4),
+    (2, 5),
+    (2, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (3, 5),
+    (4, 5),
+    (4, 5),
+    (4, 5),
+    (4, 5),
+];
+
+/// Returns the spell slots of each level, 1st through 9th, that a class with the `progression`
+/// has at `level` when it is the character's only spellcasting class, or no slots for
+/// [`Progression::Pact`].
+///
+/// Half and third casters have the slots of a full caster of half or a third of their level,
+/// rounded up.
+pub fn spell_slots(progression: Progression, level: u8) -> [u8; 9] {
+    let caster_level = match progression {
+        Progression::Full => level,
+        Progression::Half if level >= 2 => level.div_ceil(2),
+        Progression::Third if level >= 3 => level.div_ceil(3),
+        Progression::Half | Progression::Third | Progression::Pact => 0,
+    };
+    match caster_level {
+        0 => [0; 9],
+        level => FULL_CASTER_SLOTS[level.min(20) as usize - 1],
+    }
+}
+
+/// Returns the number of Pact Magic slots and their level at the Warlock `level`, or `None`
+/// below 1st level.
+pub fn pact_slots(level: u8) -> Option<(u8, u8)> {
+    let index = (level.min(20) as usize).checked_sub(1)?;
+    Some(PACT_MAGIC_SLOTS[index])
+}
+
+/// Returns the spellcaster level that determines the spell slots of a character with more than
+/// one spellcasting class, given the progression and level of each class.
+///
+/// Following the multiclass spellcaster rules, this is every level in full casters, half the
+/// levels in each half caster, and a third of the levels in each third caster, each rounded
+/// down. Pact Magic levels don't count.
+pub fn multiclass_caster_level(classes: &[(Progression, u8)]) -> u8 {
+    classes
+        .iter()
+        .map(|&(progression, level)| match progression {
+            Progression::Full => level,
+            Progression::Half => level / 2,
+            Progression::Third => level / 3,
+            Progression::Pact => 0,
+        })
+        .sum()
+}
+
+/// The spell slots of a character, with the Pact Magic slots kept apart from the others.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpellSlots {
+    /// The spell slots of each level, 1st through 9th, regained on a long rest.
+    pub slots: [u8; 9],
+
+    /// The number of Pact Magic slots and their level, regained on a short or long rest.
+    pub pact_magic: Option<(u8, u8)>,
+}
+
+impl SpellSlots {
+    /// Returns the spell slots of a character, given the progression and level of each of its
+    /// spellcasting classes.
+    ///
+    /// A single class that isn't a Pact Magic caster uses its own slot table, while several of
+    /// them share the full caster slots of their [`multiclass_caster_level`].
+    pub fn new(classes: &[(Progression, u8)]) -> Self {
+        let casters = classes
+            .iter()
+            .filter(|(progression, _)| *progression != Progression::Pact)
+            .copied()
+            .collect::<Vec<_>>();
+        let slots = match casters.as_slice() {
+            [] => [0; 9],
+            [(progression, level)] => spell_slots(*progression, *level),
+            casters => match multiclass_caster_level(casters) {
+                0 => [0; 9],
+                level => FULL_CASTER_SLOTS[level.min(20) as usize - 1],
+            },
+        };
+        let pact_magic = classes
+            .iter()
+            .filter(|(progression, _)| *progression == Progression::Pact)
+            .find_map(|&(_, level)| pact_slots(level));
+        Self { slots, pact_magic }
+    }
+
+    /// Returns `true` if there are no spell slots of any kind.
+    pub fn is_empty(&self) -> bool {
+        self.slots.iter().all(|&count| count == 0) && self.pact_magic.is_none()
+    }
+}
+
+impl Display for SpellSlots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(i, count)| format!("{} {count}", ordinal(i as u8 + 1)))
+            .collect::<Vec<_>>();
+        if let Some((count, level)) = self.pact_magic {
+            parts.push(format!("{count} {}-level Pact Magic", ordinal(level)));
+        }
+        if parts.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&parts.join(", "))
+        }
+    }
+}
+
+/// The spell slots a character has expended since its last rest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpendedSlots {
+    /// The expended spell slots of each level, 1st through 9th.
+    pub slots: [u8; 9],
+
+    /// The number of expended Pact Magic slots.
+    pub pact_magic: u8,
+}
+
+impl ExpendedSlots {
+    /// Returns the spell slots that are still available out of `slots`.
+    pub fn remaining(&self, slots: &SpellSlots) -> SpellSlots {
+        let mut remaining = *slots;
+        for (count, expended) in remaining.slots.iter_mut().zip(self.slots) {
+            *count = count.saturating_sub(expended);
+        }
+        if let Some((count, _)) = &mut remaining.pact_magic {
+            *count = count.saturating_sub(self.pact_magic);
+        }
+        remaining
+    }
+
+    /// Expends a spell slot of `level` out of `slots`, returning `false` if none is left.
+    pub fn expend(&mut self, slots: &SpellSlots, level: u8) -> bool {
+        let Some(i) = (level as usize).checked_sub(1).filter(|&i| i < 9) else {
+            return false;
+        };
+        if self.remaining(slots).slots[i] == 0 {
+            return false;
+        }
+        self.slots[i] += 1;
+        true
+    }
+
+    /// Expends a Pact Magic slot out of `slots`, returning `false` if none is left.
+    pub fn expend_pact_magic(&mut self, slots: &SpellSlots) -> bool {
+        if self
+            .remaining(slots)
+            .pact_magic
+            .is_none_or(|(count, _)| count == 0)
+        {
+            return false;
+        }
+        self.pact_magic += 1;
+        true
+    }
+
+    /// Regains the slots recovered by a short rest: every Pact Magic slot.
+    pub fn short_rest(&mut self) {
+        self.pact_magic = 0;
+    }
+
+    /// Regains every spell slot.
+    pub fn long_rest(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The lists of spells a spellcasting class picks from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpellList {
+    /// The cantrips known.
+    Cantrips,
+
+    /// The spells known or prepared.
+    Spells,
+
+    /// The spells copied into a spellbook, which the prepared spells are picked from.
+    Spellbook,
+}
+
+/// The ids of the spells picked for one of the character's spellcasting classes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpellSelection {
+    /// The cantrips known.
+    pub cantrips: Vec<String>,
+
+    /// The spells known or prepared.
+    pub spells: Vec<String>,
+
+    /// The spells copied into a spellbook.
+    pub spellbook: Vec<String>,
+}
+
+impl SpellSelection {
+    /// The ids of the spells picked for `list`.
+    pub fn list(&self, list: SpellList) -> &[String] {
+        match list {
+            SpellList::Cantrips => &self.cantrips,
+            SpellList::Spells => &self.spells,
+            SpellList::Spellbook => &self.spellbook,
+        }
+    }
+
+    /// The ids of the spells picked for `list`, for changing them.
+    pub fn list_mut(&mut self, list: SpellList) -> &mut Vec<String> {
+        match list {
+            SpellList::Cantrips => &mut self.cantrips,
+            SpellList::Spells => &mut self.spells,
+            SpellList::Spellbook => &mut self.spellbook,
+        }
+    }
+}
+
+/// One of a character's classes that casts spells, either on its own or through its subclass.
+#[derive(Debug, Clone, Copy)]
+pub struct ClassSpellcasting<'a> {
+    pub class: &'a Class,
+
+    /// The rules the class casts spells by.
+    pub spellcasting: &'a Spellcasting,
+
+    /// The number of levels the character has in the class.
+    pub level: u8,
+}
+
+impl ClassSpellcasting<'_> {
+    /// The number of spells a wizard copies into the spellbook at 1st level.
+    pub const STARTING_SPELLBOOK: usize = 6;
+
+    /// The number of spells a wizard copies into the spellbook each level after the first.
+    pub const SPELLBOOK_PER_LEVEL: usize = 2;
+
+    /// The id of the class whose spell list the spells are picked from.
+    pub fn spell_list(&self) -> &str {
+        self.spellcasting
+            .spell_list
+            .as_deref()
+            .unwrap_or(&self.class.id)
+    }
+
+    /// The number of cantrips known.
+    pub fn cantrips_known(&self) -> usize {
+        self.count_at(&self.spellcasting.cantrips_known)
+    }
+
+    /// The spell slots of each level, 1st through 9th, not counting Pact Magic.
+    pub fn spell_slots(&self) -> [u8; 9] {
+        spell_slots(self.spellcasting.progression, self.level)
+    }
+
+    /// The number of Pact Magic slots and their level, if the class casts with Pact Magic.
+    pub fn pact_slots(&self) -> Option<(u8, u8)> {
+        match self.spellcasting.progression {
+            Progression::Pact => pact_slots(self.level),
+            _ => None,
+        }
+    }
+
+    /// The highest level of the spells that can be picked, or 0 if only cantrips can.
+    pub fn max_spell_level(&self) -> u8 {
+        if let Some((_, level)) = self.pact_slots() {
+            return level;
+        }
+        self.spell_slots()
+            .iter()
+            .rposition(|&slots| slots > 0)
+            .map_or(0, |i| i as u8 + 1)
+    }
+
+    /// The number of spells picked for `list`, given the character's final ability `scores`.
+    ///
+    /// Prepared spells number the spellcasting ability modifier plus the class level (halved or
+    /// divided by three for half and third casters, rounded down), but always at least one. The
+    /// modifier counts as 0 until every score is assigned.
+    pub fn count(&self, list: SpellList, scores: Option<&AbilityScores>) -> usize {
+        let preparation = self.spellcasting.preparation;
+        match list {
+            SpellList::Cantrips => self.cantrips_known(),
+            SpellList::Spells if preparation == Preparation::Known => {
+                self.count_at(&self.spellcasting.spells_known)
+            }
+            SpellList::Spells => {
+                let levels = match self.spellcasting.progression {
+                    Progression::Full | Progression::Pact => self.level,
+                    Progression::Half => self.level / 2,
+                    Progression::Third => self.level / 3,
+                };
+                let modifier =
+                    scores.map_or(0, |scores| scores.modifier(self.spellcasting.ability));
+                (modifier as i32 + levels as i32).max(1) as usize
+            }
+            SpellList::Spellbook if preparation == Preparation::Spellbook => {
+                Self::STARTING_SPELLBOOK + Self::SPELLBOOK_PER_LEVEL * (self.level as usize - 1)
+            }
+            SpellList::Spellbook => 0,
+        }
+    }
+
+    /// The lists spells are picked for, in the order they should be picked.
+    pub fn lists(&self) -> Vec<SpellList> {
+        let mut lists = vec![];
+        if !self.spellcasting.cantrips_known.is_empty() {
+            lists.push(SpellList::Cantrips);
+        }
+        if self.spellcasting.preparation == Preparation::Spellbook {
+            lists.push(SpellList::Spellbook);
+        }
+        lists.push(SpellList::Spells);
+        lists
+    }
+
+    /// The spell save DC: 8 + the proficiency bonus + the spellcasting ability modifier.
+    pub fn save_dc(&self, scores: &AbilityScores, proficiency_bonus: u8) -> i8 {
+        8 + self.attack_bonus(scores, proficiency_bonus)
+    }
+
+    /// The spell attack modifier: the proficiency bonus + the spellcasting ability modifier.
+    pub fn attack_bonus(&self, scores: &AbilityScores, proficiency_bonus: u8) -> i8 {
+        proficiency_bonus as i8 + scores.modifier(self.spellcasting.ability)
+    }
+
+    /// Returns the value of a per-level count at the class level, or 0 if it has no value.
+    fn count_at(&self, counts: &[u8]) -> usize {
+        (self.level as usize)
+            .checked_sub(1)
+            .and_then(|i| counts.get(i))
+            .map_or(0, |&count| count as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The slots of each level the multiclass spellcaster table gives at `caster_level`.
+    fn table(caster_level: u8) -> [u8; 9] {
+        FULL_CASTER_SLOTS[caster_level as usize - 1]
+    }
+
+    #[test]
+    fn full_caster_table_matches_the_multiclass_spellcaster_table() {
+        assert_eq!(table(1), [2, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(table(3), [4, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(table(5), [4, 3, 2, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(table(9), [4, 3, 3, 3, 1, 0, 0, 0, 0]);
+        assert_eq!(table(11), [4, 3, 3, 3, 2, 1, 0, 0, 0]);
+        assert_eq!(table(17), [4, 3, 3, 3, 2, 1, 1, 1, 1]);
+        assert_eq!(table(20), [4, 3, 3, 3, 3, 2, 2, 1, 1]);
+    }
+
+    #[test]
+    fn caster_level_rounds_half_and_third_casters_down() {
+        use Progression::*;
+        assert_eq!(multiclass_caster_level(&[(Full, 3), (Full, 2)]), 5);
+        assert_eq!(multiclass_caster_level(&[(Half, 3), (Full, 1)]), 2);
+        assert_eq!(multiclass_caster_level(&[(Half, 3), (Half, 3)]), 2);
+        assert_eq!(multiclass_caster_level(&[(Third, 8), (Full, 1)]), 3);
+        assert_eq!(multiclass_caster_level(&[(Third, 2), (Half, 1)]), 0);
+        assert_eq!(multiclass_caster_level(&[(Pact, 5), (Full, 3)]), 3);
+    }
+
+    #[test]
+    fn single_class_uses_its_own_table() {
+        use Progression::*;
+        assert_eq!(SpellSlots::new(&[(Half, 1)]).slots, [0; 9]);
+        assert_eq!(SpellSlots::new(&[(Half, 2)]).slots, table(1));
+        assert_eq!(SpellSlots::new(&[(Half, 5)]).slots, table(3));
+        assert_eq!(SpellSlots::new(&[(Third, 3)]).slots, table(1));
+        assert_eq!(SpellSlots::new(&[(Third, 7)]).slots, table(3));
+        assert_eq!(SpellSlots::new(&[(Full, 20)]).slots, table(20));
+    }
+
+    #[test]
+    fn multiclass_uses_the_combined_caster_level() {
+        use Progression::*;
+        assert_eq!(SpellSlots::new(&[(Full, 3), (Full, 2)]).slots, table(5));
+        assert_eq!(SpellSlots::new(&[(Half, 5), (Half, 5)]).slots, table(4));
+        assert_eq!(SpellSlots::new(&[(Half, 3), (Full, 1)]).slots, table(2));
+        assert_eq!(SpellSlots::new(&[(Third, 7), (Full, 1)]).slots, table(3));
+        assert_eq!(SpellSlots::new(&[(Half, 1), (Half, 1)]).slots, [0; 9]);
+        assert_eq!(SpellSlots::new(&[(Full, 10), (Half, 10)]).slots, table(15));
+    }
+
+    #[test]
+    fn pact_magic_is_kept_apart() {
+        use Progression::*;
+        let slots = SpellSlots::new(&[(Pact, 5), (Full, 3)]);
+        assert_eq!(slots.slots, table(3));
+        assert_eq!(slots.pact_magic, Some((2, 3)));
+
+        let slots = SpellSlots::new(&[(Pact, 2), (Half, 4)]);
+        assert_eq!(slots.slots, [3, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(slots.pact_magic, Some((2, 1)));
+
+        let slots = SpellSlots::new(&[(Pact, 11)]);
+        assert_eq!(slots.slots, [0; 9]);
+        assert_eq!(slots.pact_magic, Some((3, 5)));
+    }
+
+    #[test]
+    fn pact_magic_recharges_on_a_short_rest() {
+        use Progression::*;
+        let slots = SpellSlots::new(&[(Pact, 3), (Full, 1)]);
+        let mut expended = ExpendedSlots::default();
+        assert!(expended.expend(&slots, 1));
+        assert!(expended.expend_pact_magic(&slots));
+        assert!(expended.expend_pact_magic(&slots));
+        assert!(!expended.expend_pact_magic(&slots));
+        assert!(!expended.expend(&slots, 2));
+
+        expended.short_rest();
+        let remaining = expended.remaining(&slots);
+        assert_eq!(remaining.slots[0], 1);
+        assert_eq!(remaining.pact_magic, Some((2, 2)));
+
+        expended.long_rest();
+        assert_eq!(expended.remaining(&slots), slots);
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::character::Character;
+
+/// The current version of the on-disk character format.
+///
+/// Version history:
+/// 1. Initial format, with the race, subrace, and class stored by name.
+/// 2. The race, subrace, and class are stored by content id.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Environment variable that overrides the directory characters are saved in.
+pub const DATA_DIR_ENV: &str = "DREGG_DATA_DIR";
+
+/// The contents of a saved character file.
+#[derive(Debug, Serialize, Deserialize)]
+struct SaveFile {
+    /// The version of the format the file was written with.
+    version: u32,
+
+    /// The saved character.
+    character: Character,
+}
+
+/// A character that has been saved to disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedCharacter {
+    /// The identifier of the save, which is the name of its file.
+    pub id: String,
+
+    /// The saved character.
+    pub draft: Character,
+}
+
+/// Errors that can occur while saving or loading characters.
+#[derive(Debug)]
+pub enum StorageError {
+    /// No per-user data directory could be found.
+    NoDataDir,
+
+    /// Reading or writing a file failed.
+    Io { path: PathBuf, source: io::Error },
+
+    /// A file did not contain a valid character.
+    Parse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+
+    /// A file was written by a newer, unsupported version of the format.
+    UnsupportedVersion { path: PathBuf, version: u32 },
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::NoDataDir => f.write_str("Could not find a data directory"),
+            StorageError::Io { path, source } => {
+                f.write_fmt(format_args!("{}: {}", path.display(), source))
+            }
+            StorageError::Parse { path, source } => {
+                f.write_fmt(format_args!("{}: {}", path.display(), source))
+            }
+            StorageError::UnsupportedVersion { path, version } => f.write_fmt(format_args!(
+                "{}: unsupported format version {} (expected at most {})",
+                path.display(),
+                version,
+                FORMAT_VERSION
+            )),
+        }
+    }
+}
+
+impl std::error::Error for StorageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StorageError::Io { source, .. } => Some(source),
+            StorageError::Parse { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the directory characters are saved in.
+pub fn characters_dir() -> Result<PathBuf, StorageError> {
+    let base = match std::env::var_os(DATA_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => dirs::data_dir()
+            .ok_or(StorageError::NoDataDir)?
+            .join("dregg"),
+    };
+    Ok(base.join("characters"))
+}
+
+/// Lists all saved characters, sorted by name.
+///
+/// Files that can't be read are returned as errors alongside the valid characters.
+pub fn list() -> Result<Vec<Result<SavedCharacter, StorageError>>, StorageError> {
+    let dir = characters_dir()?;
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => return Err(StorageError::Io { path: dir, source }),
+    };
+
+    let mut characters = vec![];
+    for entry in entries {
+        let path = entry
+            .map_err(|source| StorageError::Io {
+                path: dir.clone(),
+                source,
+            })?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            characters.push(read(&path));
+        }
+    }
+
+    characters.sort_by(|a, b| match (a, b) {
+        (Ok(a), Ok(b)) => a.draft.name.cmp(&b.draft.name),
+        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+        (Err(_), Err(_)) => std::cmp::Ordering::Equal,
+    });
+    Ok(characters)
+}
+
+/// Loads the character with the given id.
+pub fn load(id: &str) -> Result<SavedCharacter, StorageError> {
+    read(&path_for(id)?)
+}
+
+/// Saves a character, returning the id of the save.
+///
+/// If `id` is `None`, a new id is generated from the character's name.
+pub fn save(id: Option<&str>, draft: &Character) -> Result<String, StorageError> {
+    let id = match id {
+        Some(id) => id.to_string(),
+        None => new_id(&draft.name)?,
+    };
+
+    let dir = characters_dir()?;
+    fs::create_dir_all(&dir).map_err(|source| StorageError::Io {
+        path: dir.clone(),
+        source,
+    })?;
+
+    let path = path_for(&id)?;
+    let file = SaveFile {
+        version: FORMAT_VERSION,
+        character: draft.clone(),
+    };
+    let contents = serde_json::to_string_pretty(&file).map_err(|source| StorageError::Parse {
+        path: path.clone(),
+        source,
+    })?;
+    fs::write(&path, contents).map_err(|source| StorageError::Io { path, source })?;
+
+    Ok(id)
+}
+
+/// Saves a copy of the character with the given id, returning the copy.
+pub fn duplicate(id: &str) -> Result<SavedCharacter, StorageError> {
+    let mut draft = load(id)?.draft;
+    draft.name = format!("{} (Copy)", draft.name);
+    let id = save(None, &draft)?;
+    Ok(SavedCharacter { id, draft })
+}
+
+/// Renames the character with the given id.
+pub fn rename(id: &str, name: &str) -> Result<(), StorageError> {
+    let mut draft = load(id)?.draft;
+    draft.name = name.into();
+    save(Some(id), &draft)?;
+    Ok(())
+}
+
+/// Deletes the character with the given id.
+pub fn delete(id: &str) -> Result<(), StorageError> {
+    let path = path_for(id)?;
+    fs::remove_file(&path).map_err(|source| StorageError::Io { path, source })
+}
+
+/// Reads a character file.
+fn read(path: &Path) -> Result<SavedCharacter, StorageError> {
+    let contents = fs::read_to_string(path).map_err(|source| StorageError::Io {
+        path: path.into(),
+        source,
+    })?;
+
+    // Check the version before parsing the rest, so newer files get a useful error
+    #[derive(Deserialize)]
+    struct Version {
+        version: u32,
+    }
+    let Version { version } =
+        serde_json::from_str(&contents).map_err(|source| StorageError::Parse {
+            path: path.into(),
+            source,
+        })?;
+    if version > FORMAT_VERSION {
+        return Err(StorageError::UnsupportedVersion {
+            path: path.into(),
+            version,
+        });
+    }
+
+    let mut file: SaveFile =
+        serde_json::from_str(&contents).map_err(|source| StorageError::Parse {
+            path: path.into(),
+            source,
+        })?;
+    migrate(&mut file);
+
+    let id = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    Ok(SavedCharacter {
+        id,
+        draft: file.character,
+    })
+}
+
+/// Upgrades a save file to the current format version.
+fn migrate(file: &mut SaveFile) {
+    if file.version < 2 {
+        // Content ids are the slugs of their names
+        let character = &mut file.character;
+        let fields = [
+            &mut character.race,
+            &mut character.subrace,
+            &mut character.class,
+        ];
+        for name in fields.into_iter().flatten() {
+            *name = slugify(name);
+        }
+    }
+    file.version = FORMAT_VERSION;
+}
+
+/// Returns the path of the file for the given id.
+fn path_for(id: &str) -> Result<PathBuf, StorageError> {
+    Ok(characters_dir()?.join(format!("{id}.json")))
+}
+
+/// Generates an unused id from a character name.
+fn new_id(name: &str) -> Result<String, StorageError> {
+    let mut slug = slugify(name);
+    if slug.is_empty() {
+        slug = "character".into();
+    }
+
+    let mut id = slug.clone();
+    let mut n = 2;
+    while path_for(&id)?.exists() {
+        id = format!("{slug}-{n}");
+        n += 1;
+    }
+    Ok(id)
+}
+
+/// Converts a name to lowercase words separated by dashes.
+fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
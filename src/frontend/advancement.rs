@@ -0,0 +1,314 @@
+//! Views for the choices a character makes as it gains levels, shared by the `New Character` and
+//! `Level Up` pages.
+
+use iced::{
+    widget::{button, checkbox, column, container, pick_list, row, text, Text},
+    Alignment, Element,
+};
+
+use crate::backend::{
+    abilities::{Ability, AbilityScores, Improvement},
+    character::Character,
+    content::{ContentRef, ContentRegistry},
+    spellcasting::{ClassSpellcasting, SpellList, SpellSlots},
+};
+
+use super::{
+    class::{ordinal, ClassFeature, FeatureOption, Preparation},
+    feat::Feat,
+    spell::Spell,
+    utils::styles,
+};
+
+const ABILITY_COLUMN_WIDTH: f32 = 120.0;
+
+/// Displays the ability score improvement gained at `level`, with buttons to spend and take back
+/// each point.
+///
+/// `scores` are the final ability scores, with the improvement already applied. `feat` is the
+/// [`feat_view`] to take a feat instead, if the campaign uses feats, and `on_clear` goes back to
+/// increasing ability scores once a feat is taken.
+pub fn improvement_view<'a, Msg: 'a + Clone>(
+    level: u8,
+    improvement: Option<&Improvement>,
+    scores: AbilityScores,
+    feat: Option<Element<'a, Msg>>,
+    on_change: &'a dyn Fn(u8, Ability, bool) -> Msg,
+    on_clear: Msg,
+) -> Element<'a, Msg> {
+    let heading = Text::new(format!(
+        "Ability Score Improvement ({} level)",
+        ordinal(level)
+    ))
+    .font(styles::bold_font());
+    let spent = match (improvement, feat) {
+        (Some(Improvement::Feat(_)), Some(feat)) => {
+            return container(
+                column![
+                    heading,
+                    row![
+                        Text::new("A feat is taken instead of increasing ability scores.")
+                            .style(text::secondary),
+                        button(Text::new("Increase ability scores instead")).on_press(on_clear),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                    feat,
+                ]
+                .spacing(styles::COLUMN_SPACING),
+            )
+            .padding(styles::indented_padding())
+            .into();
+        }
+        (Some(Improvement::Abilities(abilities)), feat) => (abilities.clone(), feat),
+        (Some(Improvement::Feat(_)) | None, feat) => (vec![], feat),
+    };
+    let (spent, feat) = spent;
+
+    let mut content = column![
+        heading,
+        Text::new(format!(
+            "Increase one ability score by 2, or two ability scores by 1. ({} of {} points spent)",
+            spent.len(),
+            Improvement::POINTS
+        ))
+        .style(text::secondary),
+    ];
+    for ability in Ability::ALL {
+        let points = spent.iter().filter(|&&a| a == ability).count();
+        let score = scores.get(ability);
+        content = content.push(
+            row![
+                container(Text::new(ability.to_string())).width(ABILITY_COLUMN_WIDTH),
+                container(Text::new(score.to_string())).width(40),
+                button(Text::new("-").center())
+                    .width(25)
+                    .on_press_maybe((points > 0).then(|| on_change(level, ability, false))),
+                Text::new(format!("+{points}")).width(30).center(),
+                button(Text::new("+").center()).width(25).on_press_maybe(
+                    (spent.len() < Improvement::POINTS && score < AbilityScores::MAX_SCORE)
+                        .then(|| on_change(level, ability, true))
+                ),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+        );
+    }
+    if let Some(feat) = feat {
+        content = content.push(Text::new("Or take a feat instead:").style(text::secondary));
+        content = content.push(feat);
+    }
+    container(content.spacing(styles::COLUMN_SPACING))
+        .padding(styles::indented_padding())
+        .into()
+}
+
+/// Displays a dropdown list to pick a feat out of `options`, along with the picked feat and a
+/// dropdown list to pick the ability it increases if it can increase more than one.
+///
+/// `race_name` returns the display name of a race or subrace from its id, to describe the
+/// prerequisites of the feat.
+pub fn feat_view<'a, Msg: 'a + Clone>(
+    picked: Option<(&Feat, Option<Ability>)>,
+    options: &[&Feat],
+    race_name: impl Fn(&str) -> String,
+    on_select: impl Fn(ContentRef) -> Msg + 'a,
+    on_ability: impl Fn(Ability) -> Msg + 'a,
+) -> Element<'a, Msg> {
+    let feats = options
+        .iter()
+        .map(|feat| ContentRef {
+            id: feat.id.clone(),
+            name: feat.name.clone(),
+        })
+        .collect::<Vec<_>>();
+    let selected = picked.map(|(feat, _)| ContentRef {
+        id: feat.id.clone(),
+        name: feat.name.clone(),
+    });
+    let mut content = column![pick_list(feats, selected, on_select).placeholder("Select a feat:")];
+    if let Some((feat, ability)) = picked {
+        if feat.ability_increase.len() > 1 {
+            content = content.push(
+                row![
+                    Text::new("Increase by 1:"),
+                    pick_list(feat.ability_increase.clone(), ability, on_ability)
+                        .placeholder("Select an ability:"),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        content = content.push(feat.view(race_name));
+    }
+    container(content.spacing(styles::COLUMN_SPACING)).into()
+}
+
+/// Displays the options of `feature`, with a checkbox to pick each one.
+///
+/// `picked` are the options already picked for the feature, `available` the ones that can still
+/// be picked, and `slots` the number that can be picked in total.
+pub fn feature_options_view<'a, Msg: 'a + Clone>(
+    feature: &ClassFeature,
+    picked: &[&FeatureOption],
+    available: &[&FeatureOption],
+    slots: usize,
+    on_toggle: &'a dyn Fn(String, String) -> Msg,
+) -> Element<'a, Msg> {
+    let mut content = column![
+        Text::new(feature.name.clone()).font(styles::bold_font()),
+        Text::new(format!(
+            "Choose {}. ({} selected)",
+            super::utils::number_word(slots as u8),
+            picked.len()
+        ))
+        .style(text::secondary),
+    ];
+    for option in &feature.options {
+        let checked = picked.contains(&option);
+        let enabled = checked || (picked.len() < slots && available.contains(&option));
+        let (feature_id, option_id) = (feature.id.clone(), option.id.clone());
+        content = content.push(
+            container(column![
+                checkbox(option.name.clone(), checked).on_toggle_maybe(
+                    enabled.then_some(move |_| on_toggle(feature_id.clone(), option_id.clone()))
+                ),
+                container(Text::new(option.summary.clone()).style(text::secondary))
+                    .padding(styles::indented_padding()),
+            ])
+            .padding(styles::radio_padding()),
+        );
+    }
+    container(content)
+        .padding(styles::indented_padding())
+        .into()
+}
+
+/// Displays the spellcasting ability, spell save DC, and spell attack modifier of a spellcasting
+/// class.
+///
+/// The DC and attack modifier are only shown once the final ability `scores` are known.
+pub fn spellcasting_view<'a, Msg: 'a>(
+    casting: &ClassSpellcasting,
+    scores: Option<&AbilityScores>,
+    proficiency_bonus: u8,
+) -> Element<'a, Msg> {
+    let ability = casting.spellcasting.ability;
+    let mut content =
+        column![
+            Text::new(format!("{} (level {})", casting.class.name, casting.level))
+                .font(styles::bold_font())
+        ];
+    content = content.push(Text::new(match scores {
+        Some(scores) => format!(
+            "Spellcasting ability: {ability} • Spell save DC {} • Spell attack {:+}",
+            casting.save_dc(scores, proficiency_bonus),
+            casting.attack_bonus(scores, proficiency_bonus)
+        ),
+        None => format!("Spellcasting ability: {ability}"),
+    }));
+    container(content.spacing(styles::COLUMN_SPACING))
+        .padding(styles::indented_padding())
+        .into()
+}
+
+/// Displays the character's spell slots, noting that Pact Magic slots are regained on a short
+/// rest while the others need a long rest.
+pub fn spell_slots_view<'a, Msg: 'a>(slots: &SpellSlots) -> Element<'a, Msg> {
+    let mut content = column![Text::new(format!("Spell slots: {slots}"))];
+    if slots.pact_magic.is_some() {
+        content = content.push(
+            Text::new(
+                "Pact Magic slots are regained on a short or long rest, and the other slots on a \
+                 long rest.",
+            )
+            .style(text::secondary),
+        );
+    }
+    container(content.spacing(styles::COLUMN_SPACING))
+        .padding(styles::indented_padding())
+        .into()
+}
+
+/// Displays the spells that can be picked for `list` of a spellcasting class, with a checkbox to
+/// pick each one.
+///
+/// `picked` are the spells already picked, `count` the number that can be picked in total, and
+/// `locked` the picked spells that can't be unpicked anymore.
+pub fn spell_list_view<'a, Msg: 'a + Clone>(
+    casting: &ClassSpellcasting,
+    list: SpellList,
+    options: &[&Spell],
+    picked: &[&Spell],
+    count: usize,
+    locked: &[&Spell],
+    on_toggle: &'a dyn Fn(String, SpellList, String) -> Msg,
+) -> Element<'a, Msg> {
+    let class = &casting.class.name;
+    let (heading, description) = match list {
+        SpellList::Cantrips => (
+            format!("{class} Cantrips"),
+            format!("Choose {count} cantrips."),
+        ),
+        SpellList::Spellbook => (
+            format!("{class} Spellbook"),
+            format!("Choose {count} spells to copy into your spellbook."),
+        ),
+        SpellList::Spells if casting.spellcasting.preparation == Preparation::Known => (
+            format!("{class} Spells Known"),
+            format!("Choose {count} spells known."),
+        ),
+        SpellList::Spells => (
+            format!("{class} Prepared Spells"),
+            format!("Choose {count} spells to prepare."),
+        ),
+    };
+    let mut content = column![
+        Text::new(heading).font(styles::bold_font()),
+        Text::new(format!("{description} ({} selected)", picked.len())).style(text::secondary),
+    ];
+    if options.is_empty() {
+        content = content.push(Text::new(match list {
+            SpellList::Spells if casting.spellcasting.preparation == Preparation::Spellbook => {
+                "Copy spells into the spellbook to prepare them."
+            }
+            _ => "There are no spells to choose from.",
+        }));
+    }
+    for spell in options {
+        let checked = picked.contains(spell);
+        let enabled = !locked.contains(spell) && (checked || picked.len() < count);
+        let (class, spell_id) = (casting.class.id.clone(), spell.id.clone());
+        content =
+            content.push(spell.view(checked, enabled.then(|| on_toggle(class, list, spell_id))));
+    }
+    container(content)
+        .padding(styles::indented_padding())
+        .into()
+}
+
+/// Displays the [`feat_view`] of the feat taken for the ability score improvement gained at
+/// `level`, or for the racial traits if `level` is `None`, or nothing if the campaign doesn't use
+/// feats.
+pub fn feat_choice_view<'a, Msg: 'a + Clone>(
+    draft: &'a Character,
+    content: &'a ContentRegistry,
+    level: Option<u8>,
+    on_select: &'a dyn Fn(Option<u8>, ContentRef) -> Msg,
+    on_ability: &'a dyn Fn(Option<u8>, Ability) -> Msg,
+) -> Option<Element<'a, Msg>> {
+    if !draft.campaign.feats {
+        return None;
+    }
+    let picked = draft.feat_choice(level).and_then(|choice| {
+        let feat = content.feat(&choice.feat).ok()?;
+        Some((feat, choice.ability))
+    });
+    Some(feat_view(
+        picked,
+        &draft.feat_options(content, level),
+        |id| draft.race_name(content, id),
+        move |feat| on_select(level, feat),
+        move |ability| on_ability(level, ability),
+    ))
+}
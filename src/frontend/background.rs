@@ -0,0 +1,315 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{
+        button, checkbox, column, container, horizontal_rule, radio, row, scrollable, text, Text,
+    },
+    Alignment, Element, Length,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    item::StartingEquipment,
+    utils::{choice_key, proficiency::Proficiency, styles, Choices, Selections, Summary},
+};
+
+/// Represents a background a character can have.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Background {
+    /// The stable id of the background.
+    pub id: String,
+
+    /// The name of the background.
+    pub name: String,
+
+    /// The description of the background.
+    pub summary: Summary,
+
+    /// The skill, tool, and language proficiencies granted by the background.
+    #[serde(default)]
+    pub proficiencies: Vec<Choices<Proficiency>>,
+
+    /// The starting equipment granted by the background.
+    #[serde(default)]
+    pub equipment: Vec<StartingEquipment>,
+
+    /// The gold pieces the background starts with.
+    #[serde(default)]
+    pub gold: u32,
+
+    pub feature: BackgroundFeature,
+
+    /// The personality traits suggested for the background.
+    pub personality_traits: Vec<String>,
+
+    /// The ideals suggested for the background.
+    pub ideals: Vec<String>,
+
+    /// The bonds suggested for the background.
+    pub bonds: Vec<String>,
+
+    /// The flaws suggested for the background.
+    pub flaws: Vec<String>,
+}
+
+impl Background {
+    /// The entries of one of the personality tables.
+    pub fn table(&self, table: PersonalityTable) -> &[String] {
+        match table {
+            PersonalityTable::Traits => &self.personality_traits,
+            PersonalityTable::Ideals => &self.ideals,
+            PersonalityTable::Bonds => &self.bonds,
+            PersonalityTable::Flaws => &self.flaws,
+        }
+    }
+
+    /// Displays the background.
+    ///
+    /// `item_name` returns the display name of an item from its id, to describe the starting
+    /// equipment.
+    pub fn view<'a, Msg: 'a + Clone>(
+        self,
+        selections: &Selections<Proficiency>,
+        on_choice_selected: &'a dyn Fn(String, Vec<Proficiency>) -> Msg,
+        personality: &Personality,
+        on_personality_selected: &'a dyn Fn(PersonalityTable, String) -> Msg,
+        on_roll: &'a dyn Fn(PersonalityTable) -> Msg,
+        item_name: impl Fn(&str) -> String,
+    ) -> Element<'a, Msg> {
+        let line = container(horizontal_rule(1.0)).padding(styles::HORIZONTAL_LINE_PADDING);
+
+        let title = container(
+            container(Text::new(self.name.clone()).size(styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(styles::TITLE_INNER_PAD)
+                .style(styles::title),
+        )
+        .padding(styles::TITLE_OUTER_PAD);
+
+        let summary = self.summary.clone().view(
+            styles::BASE_PADDING,
+            styles::SUMMARY_PADDING,
+            styles::SUMMARY_SUBSECTION_PADDING,
+        );
+
+        let proficiencies = if !self.proficiencies.is_empty() {
+            let mut content = column![Text::new("Proficiencies: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE)];
+
+            for (i, proficiency_list) in self.proficiencies.iter().enumerate() {
+                let key = choice_key(&format!("background:{}", self.id), "proficiencies", i);
+                content = content.push(
+                    container(proficiency_list.clone().view(
+                        "You gain proficiency with",
+                        key,
+                        selections,
+                        on_choice_selected,
+                    ))
+                    .padding(styles::indented_padding()),
+                );
+            }
+
+            container(content).padding(styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        let equipment = {
+            let mut items = self
+                .equipment
+                .iter()
+                .map(|equipment| equipment.describe(&item_name))
+                .collect::<Vec<_>>();
+            if self.gold > 0 {
+                items.push(format!("a pouch containing {} gp", self.gold));
+            }
+            let mut content = column![Text::new("Equipment: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE)];
+            for item in items {
+                content = content.push(
+                    container(Text::new(format!("• {item}"))).padding(styles::indented_padding()),
+                );
+            }
+            container(content).padding(styles::BASE_PADDING)
+        };
+
+        let feature = container(column![
+            Text::new(format!("Feature: {}", self.feature.name))
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(Text::new(self.feature.summary.clone())).padding(styles::indented_padding()),
+        ])
+        .padding(styles::BASE_PADDING);
+
+        let mut characteristics = column![Text::new("Suggested Characteristics: ")
+            .font(styles::bold_font())
+            .size(styles::SECTION_FONT_SIZE)];
+        for table in PersonalityTable::ALL {
+            characteristics = characteristics.push(
+                container(self.table_view(table, personality, on_personality_selected, on_roll))
+                    .padding(styles::indented_padding()),
+            );
+        }
+        let characteristics = container(characteristics).padding(styles::BASE_PADDING);
+
+        container(scrollable(column![
+            title,
+            summary,
+            line,
+            proficiencies,
+            equipment,
+            feature,
+            characteristics,
+        ]))
+        .into()
+    }
+
+    /// Displays one of the personality tables, with a control to select each entry and a button
+    /// to roll on the table.
+    fn table_view<'a, Msg: 'a + Clone>(
+        &self,
+        table: PersonalityTable,
+        personality: &Personality,
+        on_personality_selected: &'a dyn Fn(PersonalityTable, String) -> Msg,
+        on_roll: &'a dyn Fn(PersonalityTable) -> Msg,
+    ) -> Element<'a, Msg> {
+        let entries = self.table(table);
+        let selected = personality.selected(table);
+        let full = selected.len() >= table.count();
+
+        let header = row![
+            Text::new(format!("d{} {}", entries.len(), table)).font(styles::bold_font()),
+            button(Text::new("Roll on table"))
+                .on_press_maybe((!entries.is_empty()).then(|| on_roll(table)),),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+        let mut content = column![header];
+        if table.count() > 1 {
+            content = content
+                .push(Text::new(format!("Choose {}.", table.count())).style(text::secondary));
+        }
+
+        for (i, entry) in entries.iter().enumerate() {
+            let label = format!("{}. {}", i + 1, entry);
+            let option: Element<'a, Msg> = if table.count() > 1 {
+                let checked = selected.contains(entry);
+                let entry = entry.clone();
+                checkbox(label, checked)
+                    .on_toggle_maybe(
+                        (checked || !full)
+                            .then_some(move |_| on_personality_selected(table, entry.clone())),
+                    )
+                    .into()
+            } else {
+                let chosen = selected
+                    .first()
+                    .and_then(|s| entries.iter().position(|e| e == s));
+                let entry = entry.clone();
+                radio(label, i, chosen, move |_| {
+                    on_personality_selected(table, entry)
+                })
+                .into()
+            };
+            content = content.push(container(option).padding(styles::radio_padding()));
+        }
+        content.into()
+    }
+}
+
+/// The feature granted by a background.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackgroundFeature {
+    pub name: String,
+    pub summary: String,
+}
+
+/// The personality tables of a background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonalityTable {
+    Traits,
+    Ideals,
+    Bonds,
+    Flaws,
+}
+
+impl PersonalityTable {
+    pub const ALL: [PersonalityTable; 4] = [
+        PersonalityTable::Traits,
+        PersonalityTable::Ideals,
+        PersonalityTable::Bonds,
+        PersonalityTable::Flaws,
+    ];
+
+    /// The number of entries a character picks from the table.
+    pub fn count(&self) -> usize {
+        match self {
+            PersonalityTable::Traits => 2,
+            PersonalityTable::Ideals | PersonalityTable::Bonds | PersonalityTable::Flaws => 1,
+        }
+    }
+}
+
+impl Display for PersonalityTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersonalityTable::Traits => f.write_str("Personality Trait"),
+            PersonalityTable::Ideals => f.write_str("Ideal"),
+            PersonalityTable::Bonds => f.write_str("Bond"),
+            PersonalityTable::Flaws => f.write_str("Flaw"),
+        }
+    }
+}
+
+/// The personality traits, ideal, bond, and flaw picked for a character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Personality {
+    pub traits: Vec<String>,
+    pub ideal: Option<String>,
+    pub bond: Option<String>,
+    pub flaw: Option<String>,
+}
+
+impl Personality {
+    /// The entries picked from `table`.
+    pub fn selected(&self, table: PersonalityTable) -> Vec<String> {
+        match table {
+            PersonalityTable::Traits => self.traits.clone(),
+            PersonalityTable::Ideals => self.ideal.iter().cloned().collect(),
+            PersonalityTable::Bonds => self.bond.iter().cloned().collect(),
+            PersonalityTable::Flaws => self.flaw.iter().cloned().collect(),
+        }
+    }
+
+    /// Picks `entry` from `table`.
+    ///
+    /// Picking a personality trait that was already picked unpicks it instead, and a trait is
+    /// only added while fewer than [`PersonalityTable::count`] are picked.
+    pub fn select(&mut self, table: PersonalityTable, entry: String) {
+        match table {
+            PersonalityTable::Traits => {
+                if let Some(i) = self.traits.iter().position(|t| *t == entry) {
+                    self.traits.remove(i);
+                } else if self.traits.len() < table.count() {
+                    self.traits.push(entry);
+                }
+            }
+            PersonalityTable::Ideals => self.ideal = Some(entry),
+            PersonalityTable::Bonds => self.bond = Some(entry),
+            PersonalityTable::Flaws => self.flaw = Some(entry),
+        }
+    }
+
+    /// Replaces the entries picked from `table` with `entries`.
+    pub fn set(&mut self, table: PersonalityTable, entries: Vec<String>) {
+        match table {
+            PersonalityTable::Traits => self.traits = entries,
+            PersonalityTable::Ideals => self.ideal = entries.into_iter().next(),
+            PersonalityTable::Bonds => self.bond = entries.into_iter().next(),
+            PersonalityTable::Flaws => self.flaw = entries.into_iter().next(),
+        }
+    }
+}
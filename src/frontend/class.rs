@@ -1,10 +1,28 @@
-#![allow(unused)]
+use std::fmt::Display;
 
-use super::utils::{Choices, Summary};
+use iced::{
+    widget::{column, container, horizontal_rule, radio, row, scrollable, text, Text},
+    Element, Length, Padding,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::backend::{
+    abilities::{Ability, AbilityScores},
+    character::proficiency_bonus,
+    dice::{Expression, Roller},
+};
+
+use super::{
+    item::StartingEquipment,
+    utils::{choice_key, proficiency::Proficiency, styles, Choices, Effect, Selections, Summary},
+};
 
 /// Represents a class a character can be.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Class {
+    /// The stable id of the class.
+    pub id: String,
+
     /// The name of the class.
     pub name: String,
 
@@ -14,24 +32,643 @@ pub struct Class {
     /// The description of the class.
     pub summary: Summary,
 
-    // FIXME: Add the class table
-    pub class_table: (),
+    /// The class-specific columns of the class table, for each level.
+    pub class_table: ClassTable,
 
     /// The hit points info for the class.
     pub hit_points: HitPoints,
 
     /// The proficiencies the class provides.
-    pub proficiencies: Vec<Choices<String>>,
+    pub proficiencies: Vec<Choices<Proficiency>>,
+
+    /// The features the class provides.
+    pub features: Vec<ClassFeature>,
+
+    /// How the class casts spells, if it does.
+    #[serde(default)]
+    pub spellcasting: Option<Spellcasting>,
+
+    /// Subclasses that a character may choose.
+    pub subclasses: Vec<Subclass>,
+
+    /// The prerequisites and proficiencies for multiclassing into or out of the class.
+    pub multiclassing: Multiclassing,
+
+    /// The starting equipment granted by the class to a character that starts with it.
+    #[serde(default)]
+    pub starting_equipment: Vec<StartingEquipment>,
+
+    /// The gold pieces a character can start with instead of the starting equipment of its
+    /// class and background, as a dice expression such as `5d4*10`.
+    #[serde(default)]
+    pub starting_gold: Option<String>,
+}
+
+impl Class {
+    /// Returns the features gained at exactly `level`.
+    pub fn features_at(&self, level: u8) -> impl Iterator<Item = &ClassFeature> {
+        self.features
+            .iter()
+            .filter(move |feature| feature.levels.contains(&level))
+    }
+
+    /// Returns the features gained at or below `level`.
+    pub fn features_up_to(&self, level: u8) -> impl Iterator<Item = &ClassFeature> {
+        self.features
+            .iter()
+            .filter(move |feature| feature.first_level() <= level)
+    }
+
+    pub fn view<'a, Msg: 'a + Clone>(
+        self,
+        level: u8,
+        on_subclass_selected: &'a dyn Fn(Subclass) -> Msg,
+        selected_subclass: Option<&Subclass>,
+        selections: &Selections<Proficiency>,
+        on_choice_selected: &'a dyn Fn(String, Vec<Proficiency>) -> Msg,
+    ) -> Element<'a, Msg> {
+        let line = container(horizontal_rule(1.0)).padding(styles::HORIZONTAL_LINE_PADDING);
+
+        let title = container(
+            container(Text::new(self.name.clone()).size(styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(styles::TITLE_INNER_PAD)
+                .style(styles::title),
+        )
+        .padding(styles::TITLE_OUTER_PAD);
+
+        let summary = self.summary.clone().view(
+            styles::BASE_PADDING,
+            styles::SUMMARY_PADDING,
+            styles::SUMMARY_SUBSECTION_PADDING,
+        );
+
+        let hit_points = {
+            let die = self.hit_points.hit_dice();
+            let content = column![
+                Text::new("Hit Points: ")
+                    .font(styles::bold_font())
+                    .size(styles::SECTION_FONT_SIZE),
+                container(Text::new(format!(
+                    "Hit Dice: 1{} per {} level",
+                    die,
+                    self.name.to_lowercase()
+                )))
+                .padding(styles::indented_padding()),
+                container(Text::new(format!(
+                    "Hit Points at 1st Level: {} + your Constitution modifier",
+                    die.num_sides
+                )))
+                .padding(styles::indented_padding()),
+                container(Text::new(format!(
+                    "Hit Points at Higher Levels: 1{} (or {}) + your Constitution modifier per {} level after 1st",
+                    die,
+                    die.num_sides / 2 + 1,
+                    self.name.to_lowercase()
+                )))
+                .padding(styles::indented_padding()),
+            ];
+            container(content).padding(styles::BASE_PADDING)
+        };
+
+        let proficiencies = if !self.proficiencies.is_empty() {
+            let mut content = column![Text::new("Proficiencies: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE)];
+
+            for (i, proficiency_list) in self.proficiencies.into_iter().enumerate() {
+                let key = choice_key(&format!("class:{}", self.id), "proficiencies", i);
+                content = content.push(
+                    container(proficiency_list.view(
+                        "You gain proficiency with",
+                        key,
+                        selections,
+                        on_choice_selected,
+                    ))
+                    .padding(styles::indented_padding()),
+                );
+            }
+
+            container(content).padding(styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        let class_table = container(column![
+            Text::new(format!("The {}: ", self.name))
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(self.class_table.view(&self.features, level))
+                .padding(styles::indented_padding()),
+        ])
+        .padding(styles::BASE_PADDING);
+
+        let features = if !self.features.is_empty() {
+            let mut content = column![Text::new("Class Features: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE)];
+            for feature in self.features {
+                content = content.push(feature.view());
+            }
+            container(content).padding(styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        let subclasses = if !self.subclasses.is_empty() {
+            let mut content = column![Text::new("Select a subclass: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE)];
+
+            // Create radio option for each subclass, greying out the ones that are still locked
+            for subclass in &self.subclasses {
+                let option: Element<'a, Msg> = if subclass.level <= level {
+                    radio(&subclass.name, subclass, selected_subclass, |v| {
+                        on_subclass_selected(v.clone())
+                    })
+                    .into()
+                } else {
+                    Text::new(format!(
+                        "{} (available at {} level)",
+                        subclass.name,
+                        ordinal(subclass.level)
+                    ))
+                    .style(text::secondary)
+                    .into()
+                };
+                content = content.push(container(option).padding(styles::radio_padding()));
+            }
+
+            // Display subclass info
+            if let Some(selected_subclass) = selected_subclass {
+                content = content.push(selected_subclass.clone().view());
+            }
+
+            container(content).padding(styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        container(scrollable(column![
+            title,
+            summary,
+            line,
+            hit_points,
+            proficiencies,
+            class_table,
+            features,
+            subclasses,
+        ]))
+        .padding(Padding {
+            bottom: 10.0,
+            ..Default::default()
+        })
+        .into()
+    }
+}
+
+/// The class-specific columns of a class table, such as Rages or Sneak Attack.
+///
+/// The level, proficiency bonus, and features columns are derived from the level and the
+/// class's features, so only the columns that differ between classes are listed here.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClassTable {
+    /// The names of the columns.
+    pub columns: Vec<String>,
+
+    /// The values of the columns at each level, starting at 1st level.
+    pub rows: Vec<Vec<String>>,
+}
+
+impl ClassTable {
+    const LEVEL_WIDTH: f32 = 60.0;
+    const BONUS_WIDTH: f32 = 100.0;
+    const FEATURES_WIDTH: f32 = 320.0;
+    const COLUMN_WIDTH: f32 = 100.0;
+
+    /// Returns the value of the column named `column` at `level`.
+    pub fn value(&self, column: &str, level: u8) -> Option<&str> {
+        let index = self.columns.iter().position(|c| c == column)?;
+        let row = self.rows.get((level as usize).checked_sub(1)?)?;
+        row.get(index).map(String::as_str)
+    }
+
+    /// Displays the table, with the row for `level` in bold.
+    pub fn view<'a, Msg: 'a>(&self, features: &[ClassFeature], level: u8) -> Element<'a, Msg> {
+        let bold = |text: Text<'a>| text.font(styles::bold_font());
+
+        let mut header = row![
+            container(bold(Text::new("Level"))).width(Self::LEVEL_WIDTH),
+            container(bold(Text::new("Proficiency Bonus"))).width(Self::BONUS_WIDTH),
+            container(bold(Text::new("Features"))).width(Self::FEATURES_WIDTH),
+        ];
+        for column in &self.columns {
+            header =
+                header.push(container(bold(Text::new(column.clone()))).width(Self::COLUMN_WIDTH));
+        }
+
+        let mut table = column![header, horizontal_rule(1.0)].spacing(styles::COLUMN_SPACING);
+        for (i, values) in self.rows.iter().enumerate() {
+            let row_level = i as u8 + 1;
+            let cell = |text: String| {
+                let text = Text::new(text);
+                if row_level == level {
+                    bold(text)
+                } else {
+                    text
+                }
+            };
+
+            let names = features
+                .iter()
+                .filter(|feature| feature.levels.contains(&row_level))
+                .map(|feature| feature.name.as_str())
+                .collect::<Vec<_>>();
+            let names = if names.is_empty() {
+                "-".to_string()
+            } else {
+                names.join(", ")
+            };
+
+            let mut content = row![
+                container(cell(ordinal(row_level))).width(Self::LEVEL_WIDTH),
+                container(cell(format!("+{}", proficiency_bonus(row_level))))
+                    .width(Self::BONUS_WIDTH),
+                container(cell(names)).width(Self::FEATURES_WIDTH),
+            ];
+            for value in values {
+                content = content.push(container(cell(value.clone())).width(Self::COLUMN_WIDTH));
+            }
+            table = table.push(content);
+        }
+
+        table.into()
+    }
+}
+
+/// Represents a feature provided by a class.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassFeature {
+    /// The stable id of the feature.
+    pub id: String,
+
+    /// The name of the feature.
+    pub name: String,
+
+    /// The levels at which the feature is gained or improved.
+    pub levels: Vec<u8>,
+
+    /// The feature's description.
+    pub summary: String,
+
+    /// The expertise granted at each of the feature's levels, if any.
+    #[serde(default)]
+    pub expertise: Option<Expertise>,
+
+    /// Whether each of the feature's levels grants an ability score improvement.
+    #[serde(default)]
+    pub ability_score_improvement: bool,
+
+    /// The options one of which is picked at each of the feature's levels, such as the styles of
+    /// Fighting Style.
+    #[serde(default)]
+    pub options: Vec<FeatureOption>,
+
+    /// The effects the feature has on the character, such as Unarmored Defense's Armor Class.
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+impl ClassFeature {
+    /// The level at which the feature is first gained.
+    pub fn first_level(&self) -> u8 {
+        self.levels.iter().copied().min().unwrap_or_default()
+    }
+
+    /// Returns the number of the feature's levels at or below `level`.
+    pub fn times_gained(&self, level: u8) -> usize {
+        self.levels.iter().filter(|&&l| l <= level).count()
+    }
+
+    /// Returns the number of proficiencies the feature grants expertise in by `level`.
+    pub fn expertise_up_to(&self, level: u8) -> usize {
+        let Some(expertise) = &self.expertise else {
+            return 0;
+        };
+        expertise.count as usize * self.times_gained(level)
+    }
+
+    /// Returns the number of options picked from the feature by `level`.
+    pub fn options_up_to(&self, level: u8) -> usize {
+        if self.options.is_empty() {
+            0
+        } else {
+            self.times_gained(level)
+        }
+    }
+
+    pub fn view<'a, Msg: 'a>(self) -> Element<'a, Msg> {
+        let levels = self
+            .levels
+            .iter()
+            .map(|&level| ordinal(level))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let name = Text::new(format!("{} ({}): ", self.name, levels))
+            .font(styles::bold_font())
+            .size(styles::SECTION_FONT_SIZE);
+        let summary = container(Text::new(self.summary)).padding(styles::row_adjusted_padding());
+        row![name, summary]
+            .padding(Padding {
+                right: 0.0,
+                left: 0.0,
+                ..styles::BASE_PADDING
+            })
+            .into()
+    }
+}
+
+/// One of the options of a class feature, such as a fighting style.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureOption {
+    /// The stable id of the option.
+    pub id: String,
+
+    /// The name of the option.
+    pub name: String,
+
+    /// The option's description.
+    pub summary: String,
+}
+
+impl Display for FeatureOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
 }
 
-#[derive(Debug)]
+/// Expertise granted by a class feature, which doubles the proficiency bonus for ability checks
+/// made with the chosen proficiencies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expertise {
+    /// The number of proficiencies chosen each time the feature is gained.
+    pub count: u8,
+
+    /// The ids of the tools that can be chosen alongside skills.
+    #[serde(default)]
+    pub tools: Vec<String>,
+}
+
+/// The rules for taking levels in a class other than the one a character started with.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Multiclassing {
+    /// The ability score minimums a character needs to meet, both to take a level in the class and
+    /// to take a level in another class while having levels in this one.
+    pub prerequisites: Vec<AbilityPrerequisite>,
+
+    /// The proficiencies gained when multiclassing into the class, a subset of the ones gained
+    /// when starting with it.
+    pub proficiencies: Vec<Choices<Proficiency>>,
+}
+
+impl Multiclassing {
+    /// Returns the prerequisites that `scores` don't meet.
+    pub fn unmet(&self, scores: &AbilityScores) -> Vec<&AbilityPrerequisite> {
+        self.prerequisites
+            .iter()
+            .filter(|prerequisite| !prerequisite.is_met(scores))
+            .collect()
+    }
+}
+
+/// An ability score minimum, met if any of the abilities reaches the score, such as Strength 13
+/// or Dexterity 13 for the Fighter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbilityPrerequisite {
+    /// The abilities any of which can meet the prerequisite.
+    pub abilities: Vec<Ability>,
+
+    /// The minimum score.
+    pub score: u8,
+}
+
+impl AbilityPrerequisite {
+    /// Returns `true` if one of the abilities reaches the score in `scores`.
+    pub fn is_met(&self, scores: &AbilityScores) -> bool {
+        self.abilities
+            .iter()
+            .any(|&ability| scores.get(ability) >= self.score)
+    }
+}
+
+impl Display for AbilityPrerequisite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let abilities = self
+            .abilities
+            .iter()
+            .map(|ability| format!("{ability} {}", self.score))
+            .collect::<Vec<_>>();
+        f.write_str(&abilities.join(" or "))
+    }
+}
+
+/// How a class or subclass casts spells.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Spellcasting {
+    /// The class level at which spellcasting is gained.
+    pub level: u8,
+
+    /// The ability spells are cast with.
+    pub ability: Ability,
+
+    /// How the spells that can be cast are chosen.
+    pub preparation: Preparation,
+
+    /// How quickly spell slots are gained.
+    pub progression: Progression,
+
+    /// The number of cantrips known at each class level, starting at 1st level. Empty if no
+    /// cantrips are learned.
+    #[serde(default)]
+    pub cantrips_known: Vec<u8>,
+
+    /// The number of spells known at each class level, starting at 1st level. Only used with
+    /// [`Preparation::Known`].
+    #[serde(default)]
+    pub spells_known: Vec<u8>,
+
+    /// The id of the class whose spell list the spells are chosen from, if it isn't the class
+    /// itself.
+    #[serde(default)]
+    pub spell_list: Option<String>,
+}
+
+/// How the spells a character can cast are chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Preparation {
+    /// A fixed number of spells are learned, and changed only when gaining a level.
+    Known,
+
+    /// Spells are prepared from the whole spell list after each long rest.
+    Prepared,
+
+    /// Spells are copied into a spellbook, and prepared from it after each long rest.
+    Spellbook,
+}
+
+/// How quickly a class gains spell slots as it gains levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Progression {
+    /// Spell slots of every level, such as the Wizard.
+    Full,
+
+    /// Spell slots at half the rate, up to 5th level, such as the Paladin.
+    Half,
+
+    /// Spell slots at a third of the rate, up to 4th level, such as the Eldritch Knight.
+    Third,
+
+    /// A few spell slots of a single level that are regained on a short rest, such as the
+    /// Warlock's Pact Magic.
+    Pact,
+}
+
+/// Represents a subclass of a class.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subclass {
+    /// The stable id of the subclass.
+    pub id: String,
+
+    /// The name of the subclass.
+    pub name: String,
+
+    /// The description of the subclass.
+    pub summary: Summary,
+
+    /// The class level at which the subclass can be chosen.
+    pub level: u8,
+
+    /// The features the subclass provides.
+    pub features: Vec<ClassFeature>,
+
+    /// How the subclass casts spells, for subclasses of classes that don't, such as the Eldritch
+    /// Knight.
+    #[serde(default)]
+    pub spellcasting: Option<Spellcasting>,
+}
+
+impl Subclass {
+    /// Returns the features gained at exactly `level`.
+    pub fn features_at(&self, level: u8) -> impl Iterator<Item = &ClassFeature> {
+        self.features
+            .iter()
+            .filter(move |feature| feature.levels.contains(&level))
+    }
+
+    /// Returns the features gained at or below `level`.
+    pub fn features_up_to(&self, level: u8) -> impl Iterator<Item = &ClassFeature> {
+        self.features
+            .iter()
+            .filter(move |feature| feature.first_level() <= level)
+    }
+
+    pub fn view<'a, Msg: 'a>(self) -> Element<'a, Msg> {
+        let line = container(horizontal_rule(1.0));
+
+        let title = container(
+            container(Text::new(self.name).size(styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(styles::TITLE_INNER_PAD)
+                .style(styles::title),
+        )
+        .padding(styles::SUBRACE_TITLE_PADDING);
+
+        let summary = self.summary.view(
+            Padding::default(),
+            Padding {
+                bottom: 20.0,
+                ..Default::default()
+            },
+            Padding::default(),
+        );
+
+        let features = if !self.features.is_empty() {
+            let mut content = column![];
+            for feature in self.features {
+                content = content.push(feature.view());
+            }
+            container(content).padding(styles::SUBRACE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        container(column![title, summary, line, features]).into()
+    }
+}
+
+/// Formats a level as an ordinal, such as `1st` or `12th`.
+pub fn ordinal(level: u8) -> String {
+    let suffix = match (level % 10, level % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{level}{suffix}")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HitPoints {
     hit_dice: Die,
 }
 
+impl HitPoints {
+    pub fn new(hit_dice: Die) -> Self {
+        Self { hit_dice }
+    }
+
+    /// The hit die of the class.
+    pub fn hit_dice(&self) -> &Die {
+        &self.hit_dice
+    }
+}
+
 /// Represents a die.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Die {
     pub num_sides: usize,
     pub value: usize,
 }
+
+impl Die {
+    /// Creates an unrolled die with the given number of sides.
+    pub fn new(num_sides: usize) -> Self {
+        Self {
+            num_sides,
+            value: 0,
+        }
+    }
+
+    /// Rolls the die, storing and returning the value rolled.
+    pub fn roll(&mut self, roller: &mut Roller) -> usize {
+        self.value = roller.roll_die(self.num_sides as u32) as usize;
+        self.value
+    }
+
+    /// The average value of the die.
+    pub fn average(&self) -> f64 {
+        (self.num_sides as f64 + 1.0) / 2.0
+    }
+
+    /// An expression that rolls `count` of this die.
+    pub fn expression(&self, count: u32) -> Expression {
+        Expression::dice(count, self.num_sides as u32)
+    }
+}
+
+impl Display for Die {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("d{}", self.num_sides))
+    }
+}
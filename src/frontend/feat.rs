@@ -0,0 +1,119 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{column, container, text, Text},
+    Element,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::backend::abilities::Ability;
+
+use super::{
+    class::AbilityPrerequisite,
+    utils::{proficiency::Proficiency, styles, Choices, Effect},
+};
+
+/// Represents a feat a character can take instead of an ability score improvement.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Feat {
+    /// The stable id of the feat.
+    pub id: String,
+
+    /// The name of the feat.
+    pub name: String,
+
+    /// The feat's description.
+    pub summary: String,
+
+    /// What a character needs before it can take the feat.
+    #[serde(default)]
+    pub prerequisites: Vec<FeatPrerequisite>,
+
+    /// The abilities one of which the feat increases by 1, picked by the character if there is
+    /// more than one.
+    #[serde(default)]
+    pub ability_increase: Vec<Ability>,
+
+    /// The proficiencies the feat grants.
+    #[serde(default)]
+    pub proficiencies: Vec<Choices<Proficiency>>,
+
+    /// The effects the feat has on the character.
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+impl Feat {
+    /// Displays the feat's prerequisites, ability score increase, and description.
+    ///
+    /// `race_name` returns the display name of a race or subrace from its id.
+    pub fn view<'a, Msg: 'a>(&self, race_name: impl Fn(&str) -> String) -> Element<'a, Msg> {
+        let mut content = column![];
+        if !self.prerequisites.is_empty() {
+            let prerequisites = self
+                .prerequisites
+                .iter()
+                .map(|prerequisite| prerequisite.describe(&race_name))
+                .collect::<Vec<_>>();
+            content = content.push(
+                Text::new(format!("Prerequisite: {}", prerequisites.join(", ")))
+                    .font(styles::bold_font()),
+            );
+        }
+        if !self.ability_increase.is_empty() {
+            let abilities = self
+                .ability_increase
+                .iter()
+                .map(Ability::to_string)
+                .collect::<Vec<_>>();
+            content = content.push(Text::new(format!(
+                "Increase your {} score by 1.",
+                abilities.join(" or ")
+            )));
+        }
+        content = content.push(Text::new(self.summary.clone()).style(text::secondary));
+        container(content.spacing(styles::COLUMN_SPACING)).into()
+    }
+}
+
+impl Display for Feat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Something a character needs before it can take a feat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeatPrerequisite {
+    /// An ability score minimum.
+    Ability(AbilityPrerequisite),
+
+    /// Proficiency with the item, such as heavy armor.
+    Proficiency(Proficiency),
+
+    /// The ability to cast at least one spell.
+    Spellcasting,
+
+    /// Being one of the races or subraces, given by their ids.
+    Race(Vec<String>),
+}
+
+impl FeatPrerequisite {
+    /// Describes the prerequisite, such as `Strength 13` or `Dwarf`.
+    ///
+    /// `race_name` returns the display name of a race or subrace from its id.
+    pub fn describe(&self, race_name: impl Fn(&str) -> String) -> String {
+        match self {
+            FeatPrerequisite::Ability(prerequisite) => prerequisite.to_string(),
+            FeatPrerequisite::Proficiency(proficiency) => {
+                format!("Proficiency with {proficiency}")
+            }
+            FeatPrerequisite::Spellcasting => "The ability to cast at least one spell".into(),
+            FeatPrerequisite::Race(ids) => ids
+                .iter()
+                .map(|id| race_name(id))
+                .collect::<Vec<_>>()
+                .join(" or "),
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use super::utils::{
+    proficiency::{self, ArmorProficiency, ToolCategory},
+    DamageType,
+};
+
+/// Represents a weapon, a suit of armor, or a piece of adventuring gear a character can carry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    /// The stable id of the item.
+    pub id: String,
+
+    /// The name of the item.
+    pub name: String,
+
+    /// The price of the item, for a bundle of [`Item::bundle`] items.
+    #[serde(default)]
+    pub cost: Cost,
+
+    /// The weight of the item in pounds, for a bundle of [`Item::bundle`] items.
+    #[serde(default)]
+    pub weight: f32,
+
+    /// The number of items the cost and weight are given for, such as 20 arrows.
+    #[serde(default = "one")]
+    pub bundle: u32,
+
+    /// What kind of item it is.
+    #[serde(default)]
+    pub kind: ItemKind,
+
+    /// Whether the item is a magic item that has to be attuned to for its magic to work.
+    #[serde(default)]
+    pub attunement: bool,
+}
+
+impl Item {
+    /// The weight of `quantity` of the item in pounds.
+    pub fn weight_of(&self, quantity: u32) -> f32 {
+        self.weight * quantity as f32 / self.bundle.max(1) as f32
+    }
+
+    /// Returns `true` if the item belongs to `category`.
+    pub fn is_in(&self, category: &ItemCategory) -> bool {
+        match (category, &self.kind) {
+            (ItemCategory::SimpleWeapons, ItemKind::Weapon(weapon)) => {
+                weapon.category == WeaponCategory::Simple
+            }
+            (ItemCategory::SimpleMeleeWeapons, ItemKind::Weapon(weapon)) => {
+                weapon.category == WeaponCategory::Simple && weapon.range == WeaponRange::Melee
+            }
+            (ItemCategory::MartialWeapons, ItemKind::Weapon(weapon)) => {
+                weapon.category == WeaponCategory::Martial
+            }
+            (ItemCategory::MartialMeleeWeapons, ItemKind::Weapon(weapon)) => {
+                weapon.category == WeaponCategory::Martial && weapon.range == WeaponRange::Melee
+            }
+            (ItemCategory::Tools(category), ItemKind::Tool) => proficiency::tool(&self.id)
+                .is_some_and(|tool| category.is_none_or(|category| tool.category == category)),
+            (ItemCategory::Foci(kind), ItemKind::Focus(focus)) => kind == focus,
+            _ => false,
+        }
+    }
+
+    /// Describes the kind of the item and its statistics, such as
+    /// `Martial melee weapon • 1d8 slashing • Versatile (1d10)`.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![];
+        match &self.kind {
+            ItemKind::Weapon(weapon) => {
+                parts.push(format!("{} {} weapon", weapon.category, weapon.range));
+                if let Some(damage) = &weapon.damage {
+                    parts.push(damage.to_string());
+                }
+                parts.extend(weapon.properties.iter().map(WeaponProperty::to_string));
+            }
+            ItemKind::Armor(armor) => {
+                parts.push(match armor.category {
+                    ArmorProficiency::Shields => "Shield".into(),
+                    category => category.to_string(),
+                });
+                parts.push(armor.armor_class());
+                if let Some(strength) = armor.strength {
+                    parts.push(format!("Strength {strength}"));
+                }
+                if armor.stealth_disadvantage {
+                    parts.push("Stealth disadvantage".into());
+                }
+            }
+            ItemKind::Ammunition => parts.push("Ammunition".into()),
+            ItemKind::Focus(focus) => parts.push(focus.to_string()),
+            ItemKind::Tool => parts.push(
+                match proficiency::tool(&self.id).map(|tool| tool.category) {
+                    Some(ToolCategory::Artisan) => "Artisan's tools",
+                    Some(ToolCategory::Gaming) => "Gaming set",
+                    Some(ToolCategory::Instrument) => "Musical instrument",
+                    Some(ToolCategory::Other) | None => "Tool",
+                }
+                .into(),
+            ),
+            ItemKind::Container(container) => {
+                parts.push("Container".into());
+                parts.push(format!("Holds {}", pounds(container.capacity)));
+                if container.weightless {
+                    parts.push("Contents weigh nothing".into());
+                }
+            }
+            ItemKind::Pack(_) => parts.push("Equipment pack".into()),
+            ItemKind::Gear => parts.push("Adventuring gear".into()),
+        }
+        if self.attunement {
+            parts.push("Requires attunement".into());
+        }
+        parts.join(" • ")
+    }
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+fn one() -> u32 {
+    1
+}
+
+/// The kinds of items, with the statistics specific to each.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ItemKind {
+    Weapon(Weapon),
+    Armor(Armor),
+
+    /// Arrows, bolts, and other ammunition fired by weapons with the
+    /// [`WeaponProperty::Ammunition`] property.
+    Ammunition,
+
+    /// A spellcasting focus.
+    Focus(Focus),
+
+    /// A tool, whose id is the id of one of the [`TOOLS`](proficiency::TOOLS).
+    Tool,
+
+    /// An item other items can be carried in, such as a backpack.
+    Container(Container),
+
+    /// A bundle of items sold together, such as an explorer's pack.
+    Pack(Vec<ItemStack>),
+
+    /// Any other adventuring gear.
+    #[default]
+    Gear,
+}
+
+/// The statistics of a weapon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Weapon {
+    pub category: WeaponCategory,
+    pub range: WeaponRange,
+
+    /// The damage the weapon deals on a hit, or `None` if it deals none, such as a net.
+    #[serde(default)]
+    pub damage: Option<Damage>,
+
+    #[serde(default)]
+    pub properties: Vec<WeaponProperty>,
+
+    /// The id of the ammunition the weapon fires, if it has the [`WeaponProperty::Ammunition`]
+    /// property.
+    #[serde(default)]
+    pub ammunition: Option<String>,
+}
+
+/// Whether a weapon is a simple or a martial weapon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeaponCategory {
+    Simple,
+    Martial,
+}
+
+impl Display for WeaponCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeaponCategory::Simple => f.write_str("Simple"),
+            WeaponCategory::Martial => f.write_str("Martial"),
+        }
+    }
+}
+
+/// Whether a weapon is a melee or a ranged weapon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeaponRange {
+    Melee,
+    Ranged,
+}
+
+impl Display for WeaponRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeaponRange::Melee => f.write_str("melee"),
+            WeaponRange::Ranged => f.write_str("ranged"),
+        }
+    }
+}
+
+/// The damage dealt by a weapon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Damage {
+    /// The dice rolled for the damage, as a dice expression such as `1d8`.
+    pub dice: String,
+
+    pub kind: DamageType,
+}
+
+impl Display for Damage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{} {}",
+            self.dice,
+            self.kind.to_string().to_lowercase()
+        ))
+    }
+}
+
+/// The normal and long range of a weapon, in feet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub normal: u16,
+    pub long: u16,
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("range {}/{}", self.normal, self.long))
+    }
+}
+
+/// The special rules that apply to a weapon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeaponProperty {
+    /// Fires ammunition out to the range.
+    Ammunition(Range),
+
+    /// Uses the higher of Strength and Dexterity for attack and damage rolls.
+    Finesse,
+
+    /// Small creatures have disadvantage on attack rolls with the weapon.
+    Heavy,
+
+    /// Small and easy to handle, so it can be used for two-weapon fighting.
+    Light,
+
+    /// Fires only one piece of ammunition per action, bonus action, or reaction.
+    Loading,
+
+    /// Adds 5 feet to the reach of its wielder.
+    Reach,
+
+    /// Has unusual rules described with the weapon.
+    Special,
+
+    /// Can be thrown out to the range to make a ranged attack.
+    Thrown(Range),
+
+    /// Requires two hands to attack with.
+    TwoHanded,
+
+    /// Can be used with two hands to deal the damage given as a dice expression instead.
+    Versatile(String),
+}
+
+impl Display for WeaponProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeaponProperty::Ammunition(range) => f.write_fmt(format_args!("Ammunition ({range})")),
+            WeaponProperty::Finesse => f.write_str("Finesse"),
+            WeaponProperty::Heavy => f.write_str("Heavy"),
+            WeaponProperty::Light => f.write_str("Light"),
+            WeaponProperty::Loading => f.write_str("Loading"),
+            WeaponProperty::Reach => f.write_str("Reach"),
+            WeaponProperty::Special => f.write_str("Special"),
+            WeaponProperty::Thrown(range) => f.write_fmt(format_args!("Thrown ({range})")),
+            WeaponProperty::TwoHanded => f.write_str("Two-handed"),
+            WeaponProperty::Versatile(dice) => f.write_fmt(format_args!("Versatile ({dice})")),
+        }
+    }
+}
+
+/// The statistics of a suit of armor or a shield.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Armor {
+    /// Whether the armor is light, medium, or heavy armor, or a shield.
+    pub category: ArmorProficiency,
+
+    /// The base armor class of the armor, or the bonus to it of a shield.
+    pub base_ac: u8,
+
+    /// The most the Dexterity modifier can add to the armor class, or `None` if it adds all of
+    /// it. Heavy armor has a cap of 0.
+    #[serde(default)]
+    pub dex_cap: Option<u8>,
+
+    /// The Strength score needed to wear the armor without a speed penalty.
+    #[serde(default)]
+    pub strength: Option<u8>,
+
+    /// Whether the armor imposes disadvantage on Dexterity (Stealth) checks.
+    #[serde(default)]
+    pub stealth_disadvantage: bool,
+}
+
+impl Armor {
+    /// Describes the armor class granted by the armor, such as `AC 14 + Dex modifier (max 2)`.
+    pub fn armor_class(&self) -> String {
+        match (self.category, self.dex_cap) {
+            (ArmorProficiency::Shields, _) => format!("AC +{}", self.base_ac),
+            (_, Some(0)) => format!("AC {}", self.base_ac),
+            (_, Some(cap)) => format!("AC {} + Dex modifier (max {cap})", self.base_ac),
+            (_, None) => format!("AC {} + Dex modifier", self.base_ac),
+        }
+    }
+}
+
+/// The statistics of an item other items can be carried in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    /// The most weight the container can hold, in pounds.
+    pub capacity: f32,
+
+    /// Whether the weight of the contents doesn't count towards the weight carried, such as
+    /// for a bag of holding.
+    #[serde(default)]
+    pub weightless: bool,
+}
+
+/// The kinds of spellcasting foci.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Focus {
+    /// An arcane focus, used by sorcerers, warlocks, and wizards.
+    Arcane,
+
+    /// A druidic focus, used by druids.
+    Druidic,
+
+    /// A holy symbol, used by clerics and paladins.
+    Holy,
+}
+
+impl Display for Focus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Focus::Arcane => f.write_str("Arcane focus"),
+            Focus::Druidic => f.write_str("Druidic focus"),
+            Focus::Holy => f.write_str("Holy symbol"),
+        }
+    }
+}
+
+/// The given number of an item, identified by its id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub item: String,
+
+    #[serde(default = "one")]
+    pub quantity: u32,
+}
+
+/// The price of an item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cost {
+    pub amount: u32,
+    pub coin: Coin,
+}
+
+impl Cost {
+    /// The price in copper pieces.
+    pub fn in_copper(&self) -> u64 {
+        self.amount as u64 * self.coin.value() as u64
+    }
+}
+
+impl Display for Cost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.amount == 0 {
+            f.write_str("—")
+        } else {
+            f.write_fmt(format_args!("{} {}", self.amount, self.coin))
+        }
+    }
+}
+
+/// The coins prices are given in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Coin {
+    /// Copper pieces.
+    Cp,
+
+    /// Silver pieces, worth 10 cp.
+    Sp,
+
+    /// Electrum pieces, worth 5 sp.
+    Ep,
+
+    /// Gold pieces, worth 10 sp.
+    #[default]
+    Gp,
+
+    /// Platinum pieces, worth 10 gp.
+    Pp,
+}
+
+impl Coin {
+    /// The value of the coin in copper pieces.
+    pub fn value(&self) -> u32 {
+        match self {
+            Coin::Cp => 1,
+            Coin::Sp => 10,
+            Coin::Ep => 50,
+            Coin::Gp => 100,
+            Coin::Pp => 1000,
+        }
+    }
+}
+
+impl Display for Coin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Coin::Cp => f.write_str("cp"),
+            Coin::Sp => f.write_str("sp"),
+            Coin::Ep => f.write_str("ep"),
+            Coin::Gp => f.write_str("gp"),
+            Coin::Pp => f.write_str("pp"),
+        }
+    }
+}
+
+/// The categories an item of starting equipment can be picked from with
+/// [`EquipmentItem::Any`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ItemCategory {
+    SimpleWeapons,
+    SimpleMeleeWeapons,
+    MartialWeapons,
+    MartialMeleeWeapons,
+
+    /// Every tool, or only the tools of the given category.
+    Tools(Option<ToolCategory>),
+
+    /// The spellcasting foci of the given kind.
+    Foci(Focus),
+}
+
+impl Display for ItemCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ItemCategory::SimpleWeapons => f.write_str("simple weapon"),
+            ItemCategory::SimpleMeleeWeapons => f.write_str("simple melee weapon"),
+            ItemCategory::MartialWeapons => f.write_str("martial weapon"),
+            ItemCategory::MartialMeleeWeapons => f.write_str("martial melee weapon"),
+            ItemCategory::Tools(None) => f.write_str("tool"),
+            ItemCategory::Tools(Some(ToolCategory::Artisan)) => f.write_str("artisan's tools"),
+            ItemCategory::Tools(Some(ToolCategory::Gaming)) => f.write_str("gaming set"),
+            ItemCategory::Tools(Some(ToolCategory::Instrument)) => {
+                f.write_str("musical instrument")
+            }
+            ItemCategory::Tools(Some(ToolCategory::Other)) => f.write_str("tool"),
+            ItemCategory::Foci(focus) => f.write_str(&focus.to_string().to_lowercase()),
+        }
+    }
+}
+
+/// An item granted as starting equipment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EquipmentItem {
+    /// The given number of a specific item, such as 20 arrows.
+    Item {
+        item: String,
+
+        #[serde(default = "one")]
+        quantity: u32,
+    },
+
+    /// The given number of any one item in the category, picked by the character, such as a
+    /// martial weapon.
+    Any {
+        category: ItemCategory,
+
+        #[serde(default = "one")]
+        quantity: u32,
+    },
+}
+
+impl EquipmentItem {
+    /// Describes the item, such as `Arrow (20)` or `any martial weapon`.
+    ///
+    /// `item_name` returns the display name of an item from its id.
+    pub fn describe(&self, item_name: impl Fn(&str) -> String) -> String {
+        let (name, quantity) = match self {
+            EquipmentItem::Item { item, quantity } => (item_name(item), *quantity),
+            EquipmentItem::Any { category, quantity } => (format!("any {category}"), *quantity),
+        };
+        if quantity == 1 {
+            name
+        } else {
+            format!("{name} ({quantity})")
+        }
+    }
+}
+
+/// A line of the starting equipment of a class or background.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StartingEquipment {
+    /// Items that are all granted.
+    All(Vec<EquipmentItem>),
+
+    /// Alternative groups of items, only one of which is granted, such as a martial weapon and
+    /// a shield or two martial weapons.
+    Either(Vec<Vec<EquipmentItem>>),
+}
+
+impl StartingEquipment {
+    /// Describes the line, such as `Chain Mail, or Leather Armor, Longbow, and Arrow (20)`.
+    ///
+    /// `item_name` returns the display name of an item from its id.
+    pub fn describe(&self, item_name: impl Fn(&str) -> String) -> String {
+        match self {
+            StartingEquipment::All(items) => describe_items(items, &item_name),
+            StartingEquipment::Either(alternatives) => alternatives
+                .iter()
+                .map(|items| describe_items(items, &item_name))
+                .collect::<Vec<_>>()
+                .join(", or "),
+        }
+    }
+}
+
+/// Describes a group of items of starting equipment, such as `Longbow and Arrow (20)`.
+pub fn describe_items(items: &[EquipmentItem], item_name: impl Fn(&str) -> String) -> String {
+    let names = items
+        .iter()
+        .map(|item| item.describe(&item_name))
+        .collect::<Vec<_>>();
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => names.join(""),
+    }
+}
+
+/// Formats a weight in pounds, such as `2.5 lb.`, rounded to two decimals.
+pub fn pounds(weight: f32) -> String {
+    format!("{} lb.", (weight * 100.0).round() / 100.0)
+}
@@ -0,0 +1,596 @@
+use std::sync::Arc;
+
+use iced::{
+    widget::{button, checkbox, column, container, pick_list, radio, row, scrollable, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::backend::{
+    abilities::Ability,
+    character::{proficiency_bonus, Character},
+    content::{ContentRef, ContentRegistry},
+    dice::Roller,
+    hit_points::{self, HitPointGain},
+    level_up::LevelUp,
+    spellcasting::SpellList,
+};
+
+use super::{
+    advancement,
+    utils::{self, proficiency::Proficiency, styles},
+};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// The class to take the level in has been selected.
+    ClassSelected(ContentRef),
+
+    /// Proficiencies have been selected for the choice stored under the given key.
+    ProficiencyChosen(String, Vec<Proficiency>),
+
+    /// The fixed average has been picked for the hit die.
+    HitPointsAverageSelected,
+
+    /// `Roll` button pressed to roll the hit die.
+    HitPointsRollButtonPressed,
+
+    /// Subclass has been selected.
+    SubclassSelected(ContentRef),
+
+    /// A point of the ability score improvement gained at the given level has been spent on an
+    /// ability, or taken back if `false`.
+    ImprovementChanged(u8, Ability, bool),
+
+    /// A feat has been picked for the ability score improvement gained at the given level.
+    FeatSelected(Option<u8>, ContentRef),
+
+    /// The ability increased by the feat taken at the given level has been picked.
+    FeatAbilitySelected(Option<u8>, Ability),
+
+    /// `Increase ability scores instead` button pressed to drop the feat taken at the given
+    /// level.
+    FeatCleared(u8),
+
+    /// An option has been picked or unpicked for a class feature, given by their ids.
+    FeatureOptionToggled(String, String),
+
+    /// A proficiency has been picked or unpicked for expertise.
+    ExpertiseToggled(Proficiency),
+
+    /// A spell has been picked or unpicked for one of the lists of a spellcasting class, given
+    /// by the ids of the class and the spell.
+    SpellToggled(String, SpellList, String),
+
+    /// `Level Up` button pressed to gain the level.
+    FinishButtonPressed,
+
+    /// `Cancel` button pressed to leave without gaining the level.
+    CancelButtonPressed,
+}
+
+/// Commands returned by the `update` function.
+#[derive(Debug, Clone)]
+pub enum Command {
+    None,
+
+    /// Leave the page without changing the character.
+    Cancel,
+
+    /// Save the character with the given id, now at its new level.
+    Finish(String, Box<Character>),
+}
+
+#[derive(Debug)]
+pub struct LevelUpPage {
+    /// The id of the save the character was loaded from.
+    id: String,
+
+    /// The character gaining the level.
+    level_up: LevelUp,
+
+    /// Why the last class selected couldn't be multiclassed into, if it couldn't.
+    class_error: Option<String>,
+
+    /// The error from the last attempt to gain the level, if any.
+    error: Option<String>,
+
+    /// The content the character is built from.
+    content: Arc<ContentRegistry>,
+
+    /// Rolls the hit die.
+    roller: Roller,
+}
+
+impl LevelUpPage {
+    pub fn new(id: String, level_up: LevelUp, content: Arc<ContentRegistry>) -> Self {
+        Self {
+            id,
+            level_up,
+            class_error: None,
+            error: None,
+            content,
+            roller: Roller::new(),
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Command {
+        let level = self.level_up.level();
+        let draft = &mut self.level_up.draft;
+        match message {
+            Message::ClassSelected(class) => {
+                self.class_error = self
+                    .level_up
+                    .set_class(&self.content, &class.id)
+                    .err()
+                    .map(|err| err.to_string());
+            }
+            Message::ProficiencyChosen(key, selected) => {
+                utils::select(&mut draft.proficiency_selections, key, selected)
+            }
+            Message::HitPointsAverageSelected => {
+                draft.set_hit_point_gain(level, HitPointGain::Average)
+            }
+            Message::HitPointsRollButtonPressed => {
+                draft.roll_hit_points(&self.content, level, &mut self.roller)
+            }
+            Message::SubclassSelected(subclass) => {
+                if let Some(class) = draft.class_at(level).map(str::to_string) {
+                    draft.set_subclass(&class, Some(subclass.id));
+                }
+            }
+            Message::ImprovementChanged(level, ability, increase) => {
+                draft.change_improvement(&self.content, level, ability, increase)
+            }
+            Message::FeatSelected(level, feat) => draft.choose_feat(&self.content, level, &feat.id),
+            Message::FeatAbilitySelected(level, ability) => {
+                draft.choose_feat_ability(&self.content, level, ability)
+            }
+            Message::FeatCleared(level) => draft.clear_feat(level),
+            Message::FeatureOptionToggled(feature, option) => {
+                draft.toggle_feature_option(&self.content, &feature, &option)
+            }
+            Message::ExpertiseToggled(proficiency) => {
+                let before = self.level_up.before().expertise(&self.content);
+                if !before.contains(&proficiency) {
+                    self.level_up
+                        .draft
+                        .toggle_expertise(&self.content, proficiency);
+                }
+            }
+            Message::SpellToggled(class, list, spell) => {
+                let before = self.level_up.before().spell_selection(
+                    &self.content,
+                    &class,
+                    SpellList::Spellbook,
+                );
+                if list != SpellList::Spellbook || !before.iter().any(|s| s.id == spell) {
+                    self.level_up
+                        .draft
+                        .toggle_spell(&self.content, &class, list, &spell);
+                }
+            }
+            Message::FinishButtonPressed => {
+                return match self.level_up.clone().finish(&self.content) {
+                    Ok((draft, _)) => Command::Finish(self.id.clone(), Box::new(draft)),
+                    Err(err) => {
+                        self.error = Some(err.to_string());
+                        Command::None
+                    }
+                };
+            }
+            Message::CancelButtonPressed => return Command::Cancel,
+        }
+        Command::None
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let title = container(
+            container(
+                Text::new(format!("{}: Level {}", draft.name, self.level_up.level()))
+                    .size(styles::TITLE_FONT_SIZE),
+            )
+            .center_x(Length::Fill)
+            .padding(styles::TITLE_INNER_PAD)
+            .style(styles::title),
+        )
+        .padding(styles::TITLE_OUTER_PAD);
+
+        let mut choices = column![
+            self.class(),
+            self.grants(),
+            self.proficiencies(),
+            self.hit_points(),
+            self.subclass(),
+            self.improvement(),
+            self.feature_options(),
+            self.expertise(),
+            self.spells(),
+        ];
+
+        let changes = self.level_up.changes(&self.content);
+        let mut summary = column![Text::new("Changes: ")
+            .font(styles::bold_font())
+            .size(styles::SECTION_FONT_SIZE)];
+        for change in changes {
+            summary = summary.push(
+                container(Text::new(format!("• {change}"))).padding(styles::indented_padding()),
+            );
+        }
+        choices = choices.push(container(summary).padding(styles::BASE_PADDING));
+
+        let missing = self.level_up.missing(&self.content);
+        let mut actions = column![row![
+            button(Text::new("Level Up"))
+                .on_press_maybe(missing.is_empty().then_some(Message::FinishButtonPressed)),
+            button(Text::new("Cancel")).on_press(Message::CancelButtonPressed),
+        ]
+        .spacing(10)];
+        if !missing.is_empty() {
+            actions = actions.push(Text::new("Incomplete:").font(styles::bold_font()));
+            for item in missing {
+                actions = actions.push(Text::new(format!("• {item}")).style(text::secondary));
+            }
+        }
+        if let Some(error) = &self.error {
+            actions = actions.push(Text::new(error).style(text::danger));
+        }
+        choices = choices.push(container(actions).padding(styles::BASE_PADDING));
+
+        container(scrollable(column![title, choices]))
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+impl LevelUpPage {
+    /// Displays a dropdown list of classes to take the level in, along with why the last class
+    /// selected couldn't be multiclassed into.
+    fn class(&self) -> Element<'_, Message> {
+        let classes = self
+            .content
+            .classes()
+            .map(|class| ContentRef {
+                id: class.id.clone(),
+                name: class.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        let selected = classes
+            .iter()
+            .find(|class| self.level_up.class() == Some(class.id.as_str()))
+            .cloned();
+
+        let mut content = column![
+            Text::new("Class: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(pick_list(classes, selected, Message::ClassSelected))
+                .padding(styles::indented_padding()),
+            container(
+                Text::new(
+                    "Taking a level in a new class requires meeting the ability score \
+                     prerequisites of both the new class and every class you already have."
+                )
+                .style(text::secondary)
+            )
+            .padding(styles::indented_padding()),
+        ];
+        if let Some(error) = &self.class_error {
+            content = content.push(
+                container(Text::new(error).style(text::danger)).padding(styles::indented_padding()),
+            );
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+
+    /// Displays the proficiencies gained by multiclassing into the class the level is taken in,
+    /// if it is a new one.
+    fn proficiencies(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let Some(class_levels) = self
+            .level_up
+            .class_levels(&self.content)
+            .filter(|class_levels| {
+                class_levels.level == 1 && draft.class.as_ref() != Some(&class_levels.class.id)
+            })
+        else {
+            return container(column![]).into();
+        };
+
+        let class = class_levels.class;
+        let mut content = column![Text::new("Proficiencies: ")
+            .font(styles::bold_font())
+            .size(styles::SECTION_FONT_SIZE)];
+        if class.multiclassing.proficiencies.is_empty() {
+            content = content.push(
+                container(Text::new(format!(
+                    "Multiclassing into {} grants no proficiencies.",
+                    class.name
+                )))
+                .padding(styles::indented_padding()),
+            );
+        }
+        for (i, choices) in class.multiclassing.proficiencies.iter().enumerate() {
+            let key = utils::choice_key(&format!("multiclass:{}", class.id), "proficiencies", i);
+            content = content.push(
+                container(choices.clone().view(
+                    "You gain proficiency with",
+                    key,
+                    &draft.proficiency_selections,
+                    &Message::ProficiencyChosen,
+                ))
+                .padding(styles::indented_padding()),
+            );
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+
+    /// Displays what the new level grants: the proficiency bonus, the class table columns, and
+    /// the features gained.
+    fn grants(&self) -> Element<'_, Message> {
+        let level = self.level_up.level();
+        let mut content = column![
+            Text::new("You Gain: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(Text::new(format!(
+                "Proficiency Bonus: +{}",
+                proficiency_bonus(level)
+            )))
+            .padding(styles::indented_padding()),
+        ];
+        for (column, value) in self.level_up.class_table(&self.content) {
+            content = content.push(
+                container(Text::new(format!("{column}: {value}")))
+                    .padding(styles::indented_padding()),
+            );
+        }
+        for feature in self.level_up.features(&self.content) {
+            content =
+                content.push(container(feature.clone().view()).padding(styles::indented_padding()));
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+
+    /// Displays the hit points gained, with a choice between rolling and the fixed average.
+    fn hit_points(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let level = self.level_up.level();
+        let Some(class) = draft
+            .class_at(level)
+            .and_then(|id| self.content.class(id).ok())
+        else {
+            return container(column![]).into();
+        };
+        let die = class.hit_points.hit_dice();
+        let gained = draft
+            .hit_point_levels(&self.content)
+            .and_then(|levels| levels.last().copied());
+
+        let gain = draft
+            .hit_point_gains
+            .get(level as usize - 2)
+            .copied()
+            .unwrap_or_default();
+        let rolled = matches!(gain, HitPointGain::Rolled(_));
+        let mut content = column![
+            Text::new("Hit Points: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(
+                row![
+                    radio(
+                        format!("Take the average ({})", hit_points::average(die)),
+                        false,
+                        Some(rolled),
+                        |_| Message::HitPointsAverageSelected
+                    ),
+                    button(Text::new(format!(
+                        "{} 1{}",
+                        if rolled { "Reroll" } else { "Roll" },
+                        die
+                    )))
+                    .on_press(Message::HitPointsRollButtonPressed),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center)
+            )
+            .padding(styles::indented_padding()),
+        ];
+        if let Some(gained) = gained {
+            content = content.push(
+                container(Text::new(format!(
+                    "{} from the hit die, {:+} from Constitution, +{} from traits: {} hit points",
+                    gained.die,
+                    gained.constitution,
+                    gained.bonus,
+                    gained.total()
+                )))
+                .padding(styles::indented_padding()),
+            );
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+
+    /// Displays a dropdown list of subclasses, if the character can choose one for the class the
+    /// level is taken in.
+    fn subclass(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let Some(class_levels) = self.level_up.class_levels(&self.content) else {
+            return container(column![]).into();
+        };
+        let (class, level) = (class_levels.class, class_levels.level);
+        if self.level_up.before().subclass_of(&class.id).is_some()
+            || !class.subclasses.iter().any(|s| s.level <= level)
+        {
+            return container(column![]).into();
+        }
+
+        let subclasses = class
+            .subclasses
+            .iter()
+            .filter(|subclass| subclass.level <= level)
+            .map(|subclass| ContentRef {
+                id: subclass.id.clone(),
+                name: subclass.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        let selected = subclasses
+            .iter()
+            .find(|subclass| draft.subclass_of(&class.id) == Some(subclass.id.as_str()))
+            .cloned();
+        container(column![
+            Text::new("Subclass: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(
+                pick_list(subclasses, selected, Message::SubclassSelected)
+                    .placeholder("Select your subclass:")
+            )
+            .padding(styles::indented_padding()),
+        ])
+        .padding(styles::BASE_PADDING)
+        .into()
+    }
+
+    /// Displays the ability score improvement gained at the new level, if any.
+    fn improvement(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let level = self.level_up.level();
+        match draft.final_ability_scores(&self.content) {
+            Some(scores) if draft.improvement_levels(&self.content).contains(&level) => {
+                container(advancement::improvement_view(
+                    level,
+                    draft.improvements.get(&level),
+                    scores,
+                    advancement::feat_choice_view(
+                        draft,
+                        &self.content,
+                        Some(level),
+                        &Message::FeatSelected,
+                        &Message::FeatAbilitySelected,
+                    ),
+                    &Message::ImprovementChanged,
+                    Message::FeatCleared(level),
+                ))
+                .padding(styles::BASE_PADDING)
+                .into()
+            }
+            _ => container(column![]).into(),
+        }
+    }
+
+    /// Displays the options of the features that grant a new option at the new level of the
+    /// class.
+    fn feature_options(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let Some(class_levels) = self.level_up.class_levels(&self.content) else {
+            return container(column![]).into();
+        };
+        let level = class_levels.level;
+        let features = class_levels
+            .features()
+            .filter(|feature| feature.options_up_to(level) > feature.options_up_to(level - 1))
+            .collect::<Vec<_>>();
+        if features.is_empty() {
+            return container(column![]).into();
+        }
+
+        let mut content = column![Text::new("Feature Options: ")
+            .font(styles::bold_font())
+            .size(styles::SECTION_FONT_SIZE)];
+        for feature in features {
+            content = content.push(advancement::feature_options_view(
+                feature,
+                &draft.feature_options(&self.content, feature),
+                &draft.available_options(&self.content, feature),
+                feature.options_up_to(level),
+                &Message::FeatureOptionToggled,
+            ));
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+
+    /// Displays the proficiencies that can be picked for the expertise gained at the new level.
+    fn expertise(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let before = self.level_up.before().expertise(&self.content);
+        let slots = draft.expertise_slots(&self.content);
+        if slots <= before.len() {
+            return container(column![]).into();
+        }
+
+        let picked = draft.expertise(&self.content);
+        let mut content = column![
+            Text::new("Expertise: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            container(
+                Text::new(format!(
+                    "Choose {} more of your proficiencies to double your proficiency bonus \
+                     for. ({} selected)",
+                    slots - before.len(),
+                    picked.len() - before.len()
+                ))
+                .style(text::secondary)
+            )
+            .padding(styles::indented_padding()),
+        ];
+        for option in draft.expertise_options(&self.content) {
+            let checked = picked.contains(&option);
+            let enabled = !before.contains(&option) && (checked || picked.len() < slots);
+            let toggle = enabled.then(|| {
+                let option = option.clone();
+                move |_| Message::ExpertiseToggled(option.clone())
+            });
+            content = content.push(
+                container(checkbox(option.to_string(), checked).on_toggle_maybe(toggle))
+                    .padding(styles::radio_padding()),
+            );
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+
+    /// Displays the spellcasting of the class the level is taken in, with the cantrips and
+    /// spells to pick for it. Spells copied into the spellbook before can't be removed.
+    fn spells(&self) -> Element<'_, Message> {
+        let draft = &self.level_up.draft;
+        let Some(casting) = self.level_up.spellcasting(&self.content) else {
+            return container(column![]).into();
+        };
+        let class = &casting.class.id;
+        let scores = draft.final_ability_scores(&self.content);
+        let spellbook =
+            self.level_up
+                .before()
+                .spell_selection(&self.content, class, SpellList::Spellbook);
+
+        let mut content = column![
+            Text::new("Spells: ")
+                .font(styles::bold_font())
+                .size(styles::SECTION_FONT_SIZE),
+            advancement::spell_slots_view(&draft.spell_slots(&self.content)),
+            advancement::spellcasting_view(
+                &casting,
+                scores.as_ref(),
+                proficiency_bonus(self.level_up.level())
+            ),
+        ];
+        for list in casting.lists() {
+            let locked = match list {
+                SpellList::Spellbook => spellbook.as_slice(),
+                SpellList::Cantrips | SpellList::Spells => &[],
+            };
+            content = content.push(advancement::spell_list_view(
+                &casting,
+                list,
+                &draft.spell_options(&self.content, class, list),
+                &draft.spell_selection(&self.content, class, list),
+                draft.spell_count(&self.content, class, list),
+                locked,
+                &Message::SpellToggled,
+            ));
+        }
+        container(content).padding(styles::BASE_PADDING).into()
+    }
+}
@@ -0,0 +1,317 @@
+use iced::{
+    widget::{button, column, container, horizontal_rule, row, scrollable, text_input, Text},
+    Alignment, Element, Length,
+};
+
+use std::sync::Arc;
+
+use crate::backend::{
+    character::MAX_LEVEL,
+    content::{ContentKind, ContentRegistry},
+    level_up::LevelUp,
+    storage::{self, SavedCharacter},
+};
+
+use super::utils::styles;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    /// `Open` button pressed for the character with the given id.
+    OpenButtonPressed(String),
+
+    /// `Level Up` button pressed for the character with the given id.
+    LevelUpButtonPressed(String),
+
+    /// `Duplicate` button pressed for the character with the given id.
+    DuplicateButtonPressed(String),
+
+    /// `Rename` button pressed for the character with the given id.
+    RenameButtonPressed(String),
+
+    /// The new name of the character being renamed has been edited.
+    RenameChanged(String),
+
+    /// The new name of the character being renamed has been confirmed.
+    RenameSubmitted,
+
+    /// Renaming the character has been cancelled.
+    RenameCancelled,
+
+    /// `Delete` button pressed for the character with the given id.
+    DeleteButtonPressed(String),
+
+    /// Deleting the character has been confirmed.
+    DeleteConfirmed,
+
+    /// Deleting the character has been cancelled.
+    DeleteCancelled,
+}
+
+/// Commands returned by the `update` function.
+#[derive(Debug, Clone)]
+pub enum Command {
+    None,
+    Open(Box<SavedCharacter>),
+
+    /// Level up the character with the given id.
+    LevelUp(String, Box<LevelUp>),
+
+    /// The character with the given id has been deleted.
+    Deleted(String),
+}
+
+/// A character that is being renamed.
+#[derive(Debug)]
+struct Rename {
+    /// The id of the character.
+    id: String,
+
+    /// The new name of the character.
+    name: String,
+}
+
+#[derive(Debug, Default)]
+pub struct LoadCharacterPage {
+    /// The saved characters.
+    characters: Vec<SavedCharacter>,
+
+    /// Errors from files that couldn't be read, or from the last action.
+    errors: Vec<String>,
+
+    /// The character being renamed, if any.
+    renaming: Option<Rename>,
+
+    /// The id of the character waiting for its deletion to be confirmed, if any.
+    deleting: Option<String>,
+
+    /// The content used to display the names of races and classes.
+    content: Arc<ContentRegistry>,
+}
+
+impl LoadCharacterPage {
+    const ROW_SPACING: f32 = 10.0;
+    const COLUMN_WIDTH: f32 = 150.0;
+
+    pub fn new(content: Arc<ContentRegistry>) -> Self {
+        let mut page = Self {
+            content,
+            ..Default::default()
+        };
+        page.refresh();
+        page
+    }
+
+    /// Shows an error under the list of characters.
+    pub fn push_error(&mut self, error: String) {
+        self.errors.push(error);
+    }
+
+    pub fn update(&mut self, message: Message) -> Command {
+        match message {
+            Message::OpenButtonPressed(id) => match storage::load(&id) {
+                Ok(character) => return Command::Open(Box::new(character)),
+                Err(err) => self.errors.push(err.to_string()),
+            },
+            Message::LevelUpButtonPressed(id) => match storage::load(&id) {
+                Ok(character) => match LevelUp::new(character.draft, &self.content) {
+                    Ok(level_up) => return Command::LevelUp(id, Box::new(level_up)),
+                    Err(err) => self.errors.push(format!("{}: {}", character.id, err)),
+                },
+                Err(err) => self.errors.push(err.to_string()),
+            },
+            Message::DuplicateButtonPressed(id) => {
+                if let Err(err) = storage::duplicate(&id) {
+                    self.errors.push(err.to_string());
+                } else {
+                    self.refresh();
+                }
+            }
+            Message::RenameButtonPressed(id) => {
+                let name = self
+                    .characters
+                    .iter()
+                    .find(|character| character.id == id)
+                    .map(|character| character.draft.name.clone())
+                    .unwrap_or_default();
+                self.deleting = None;
+                self.renaming = Some(Rename { id, name });
+            }
+            Message::RenameChanged(name) => {
+                if let Some(renaming) = &mut self.renaming {
+                    renaming.name = name;
+                }
+            }
+            Message::RenameSubmitted => {
+                if let Some(renaming) = self.renaming.take() {
+                    if let Err(err) = storage::rename(&renaming.id, renaming.name.trim()) {
+                        self.errors.push(err.to_string());
+                    } else {
+                        self.refresh();
+                    }
+                }
+            }
+            Message::RenameCancelled => self.renaming = None,
+            Message::DeleteButtonPressed(id) => {
+                self.renaming = None;
+                self.deleting = Some(id);
+            }
+            Message::DeleteConfirmed => {
+                if let Some(id) = self.deleting.take() {
+                    if let Err(err) = storage::delete(&id) {
+                        self.errors.push(err.to_string());
+                    } else {
+                        self.refresh();
+                        return Command::Deleted(id);
+                    }
+                }
+            }
+            Message::DeleteCancelled => self.deleting = None,
+        }
+        Command::None
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Saved Characters").size(styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(styles::TITLE_INNER_PAD)
+                .style(styles::title),
+        )
+        .padding(styles::TITLE_OUTER_PAD);
+
+        let header = row![
+            Self::cell(Text::new("Name").font(styles::bold_font())),
+            Self::cell(Text::new("Race").font(styles::bold_font())),
+            Self::cell(Text::new("Class").font(styles::bold_font())),
+            Self::cell(Text::new("Level").font(styles::bold_font())),
+            Self::cell(Text::new("Status").font(styles::bold_font())),
+        ]
+        .spacing(Self::ROW_SPACING);
+
+        let mut characters = column![header, horizontal_rule(1.0)].spacing(Self::ROW_SPACING);
+        if self.characters.is_empty() {
+            characters = characters.push(Text::new("No saved characters."));
+        }
+        for character in &self.characters {
+            characters = characters.push(self.character_row(character));
+        }
+
+        let mut errors = column![];
+        for error in &self.errors {
+            errors = errors.push(Text::new(error).style(iced::widget::text::danger));
+        }
+
+        container(scrollable(column![
+            title,
+            container(characters).padding(styles::BASE_PADDING),
+            container(errors).padding(styles::BASE_PADDING),
+        ]))
+        .height(Length::Fill)
+        .into()
+    }
+}
+
+impl LoadCharacterPage {
+    /// Reloads the list of saved characters.
+    fn refresh(&mut self) {
+        self.characters.clear();
+        self.errors.clear();
+        match storage::list() {
+            Ok(characters) => {
+                for character in characters {
+                    match character {
+                        Ok(character) => self.characters.push(character),
+                        Err(err) => self.errors.push(err.to_string()),
+                    }
+                }
+            }
+            Err(err) => self.errors.push(err.to_string()),
+        }
+    }
+
+    /// Displays a saved character and the actions that can be taken on it.
+    fn character_row<'a>(&'a self, character: &'a SavedCharacter) -> Element<'a, Message> {
+        let draft = &character.draft;
+
+        let name: Element<Message> = match &self.renaming {
+            Some(renaming) if renaming.id == character.id => container(
+                text_input("Character name", &renaming.name)
+                    .on_input(Message::RenameChanged)
+                    .on_submit(Message::RenameSubmitted),
+            )
+            .width(Self::COLUMN_WIDTH)
+            .into(),
+            _ => Self::cell(Text::new(&draft.name)),
+        };
+        let race = draft
+            .race
+            .as_ref()
+            .map(|race| self.content.name_of(ContentKind::Race, race))
+            .unwrap_or_else(|| "-".into());
+        let class = if draft.multiclasses.is_empty() {
+            draft
+                .class
+                .as_ref()
+                .map(|class| self.content.name_of(ContentKind::Class, class))
+                .unwrap_or_else(|| "-".into())
+        } else {
+            draft
+                .class_levels(&self.content)
+                .iter()
+                .map(|class_levels| format!("{} {}", class_levels.class.name, class_levels.level))
+                .collect::<Vec<_>>()
+                .join(" / ")
+        };
+
+        let status = if draft.is_complete(&self.content) {
+            Text::new("Complete")
+        } else {
+            Text::new("Incomplete").style(iced::widget::text::secondary)
+        };
+
+        let actions = match (&self.renaming, &self.deleting) {
+            (Some(renaming), _) if renaming.id == character.id => row![
+                button("Confirm").on_press(Message::RenameSubmitted),
+                button("Cancel").on_press(Message::RenameCancelled),
+            ],
+            (_, Some(deleting)) if deleting == &character.id => row![
+                Text::new("Delete for good?"),
+                button("Delete")
+                    .style(button::danger)
+                    .on_press(Message::DeleteConfirmed),
+                button("Cancel").on_press(Message::DeleteCancelled),
+            ]
+            .align_y(Alignment::Center),
+            _ => row![
+                button("Open").on_press(Message::OpenButtonPressed(character.id.clone())),
+                button("Level Up").on_press_maybe(
+                    (draft.level < MAX_LEVEL && draft.is_complete(&self.content))
+                        .then(|| Message::LevelUpButtonPressed(character.id.clone()))
+                ),
+                button("Duplicate").on_press(Message::DuplicateButtonPressed(character.id.clone())),
+                button("Rename").on_press(Message::RenameButtonPressed(character.id.clone())),
+                button("Delete")
+                    .style(button::danger)
+                    .on_press(Message::DeleteButtonPressed(character.id.clone())),
+            ],
+        }
+        .spacing(5);
+
+        row![
+            name,
+            Self::cell(Text::new(race)),
+            Self::cell(Text::new(class)),
+            Self::cell(Text::new(draft.level.to_string())),
+            Self::cell(status),
+            actions,
+        ]
+        .spacing(Self::ROW_SPACING)
+        .align_y(Alignment::Center)
+        .into()
+    }
+
+    /// Creates a fixed width cell of the character table.
+    fn cell<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+        container(content).width(Self::COLUMN_WIDTH).into()
+    }
+}
@@ -1,7 +1,13 @@
+pub mod level_up_page;
+pub mod load_character_page;
 pub mod new_character_page;
 pub mod race;
-pub mod races;
 
+pub mod advancement;
+pub mod background;
 pub mod class;
+pub mod feat;
+pub mod item;
+pub mod spell;
 
 pub mod utils;
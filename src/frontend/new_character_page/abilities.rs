@@ -0,0 +1,227 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{button, checkbox, column, container, pick_list, radio, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::abilities::{self, Ability, GenerationMethod, POINT_BUY_BUDGET},
+    frontend::utils::{self, styles as utils_styles},
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+/// A value in the pool of scores that can be assigned to an ability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolValue {
+    /// The index of the value in the pool.
+    pub index: usize,
+
+    /// The score.
+    pub value: u8,
+}
+
+impl Display for PoolValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}", self.value))
+    }
+}
+
+impl NewCharacterPage {
+    const ABILITY_COLUMN_WIDTH: f32 = 120.0;
+
+    /// Displays the ability score generation step.
+    pub(super) fn abilities_info(&self) -> Element<'_, Message> {
+        let generation = &self.draft.abilities;
+
+        let title = container(
+            container(Text::new("Ability Scores").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let mut methods = column![Text::new("Method: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        for method in GenerationMethod::ALL {
+            methods = methods.push(
+                container(radio(
+                    method.to_string(),
+                    method,
+                    Some(generation.method),
+                    Message::AbilityMethodSelected,
+                ))
+                .padding(utils_styles::radio_padding()),
+            );
+        }
+
+        let method_info: Element<Message> = match generation.method {
+            GenerationMethod::StandardArray => {
+                Text::new("Assign each of the values 15, 14, 13, 12, 10, and 8 to an ability.")
+                    .into()
+            }
+            GenerationMethod::PointBuy => Text::new(format!(
+                "Points spent: {} / {}",
+                generation.points_spent(),
+                POINT_BUY_BUDGET
+            ))
+            .font(utils_styles::bold_font())
+            .into(),
+            GenerationMethod::Rolled => {
+                let roll_button = button(Text::new(if generation.rolls.is_empty() {
+                    "Roll"
+                } else {
+                    "Reroll"
+                }))
+                .style(styles::menu_button)
+                .on_press(Message::AbilityRollButtonPressed);
+
+                let mut log = column![Text::new("Roll log:").font(utils_styles::bold_font())];
+                let num_current = generation.current_rolls().len();
+                let first_current = generation.rolls.len() - num_current;
+                for (i, roll) in generation.rolls.iter().enumerate() {
+                    let text = Text::new(format!("{}. {}", i + 1, roll));
+                    log = log.push(if i >= first_current {
+                        text
+                    } else {
+                        text.style(iced::widget::text::secondary)
+                    });
+                }
+                column![roll_button, log].spacing(10).into()
+            }
+        };
+
+        let header = row![
+            Self::ability_cell(Text::new("Ability").font(utils_styles::bold_font())),
+            Self::ability_cell(Text::new("Base").font(utils_styles::bold_font())),
+            Self::ability_cell(Text::new("Bonus").font(utils_styles::bold_font())),
+            Self::ability_cell(Text::new("Total").font(utils_styles::bold_font())),
+            Self::ability_cell(Text::new("Modifier").font(utils_styles::bold_font())),
+        ];
+
+        let bonuses = self.draft.asi(&self.content);
+        let mut table = column![header].spacing(utils_styles::COLUMN_SPACING);
+        for ability in Ability::ALL {
+            let base = generation.score(ability);
+            let bonus = bonuses.get(ability);
+            let (total, modifier) = match base {
+                Some(base) => {
+                    let total = base + bonus;
+                    (
+                        total.to_string(),
+                        format!("{:+}", abilities::modifier(total)),
+                    )
+                }
+                None => ("-".into(), "-".into()),
+            };
+
+            table = table.push(
+                row![
+                    Self::ability_cell(Text::new(ability.to_string())),
+                    Self::ability_cell(self.base_score_control(ability)),
+                    Self::ability_cell(Text::new(format!("+{bonus}"))),
+                    Self::ability_cell(Text::new(total)),
+                    Self::ability_cell(Text::new(modifier)),
+                ]
+                .align_y(Alignment::Center),
+            );
+        }
+
+        container(column![
+            title,
+            container(methods).padding(utils_styles::BASE_PADDING),
+            container(method_info).padding(utils_styles::BASE_PADDING),
+            self.racial_increases_info(),
+            container(table).padding(utils_styles::BASE_PADDING),
+        ])
+        .into()
+    }
+
+    /// Displays a checkbox for each ability to pick the ones increased by the character's race,
+    /// if the race lets the character choose them.
+    fn racial_increases_info(&self) -> Element<'_, Message> {
+        let count = self.draft.racial_increase_count(&self.content);
+        if count == 0 {
+            return column![].into();
+        }
+
+        let picked = self.draft.racial_increases(&self.content);
+        let mut content = column![
+            Text::new("Racial Ability Score Increases: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE),
+            Text::new(format!(
+                "Choose {} different abilities to increase by 1. ({} selected)",
+                utils::number_word(count as u8),
+                picked.len()
+            ))
+            .style(text::secondary),
+        ];
+        for ability in Ability::ALL {
+            let checked = picked.contains(&ability);
+            content = content.push(
+                checkbox(ability.to_string(), checked).on_toggle_maybe(
+                    (checked || picked.len() < count)
+                        .then_some(move |_| Message::RacialIncreaseToggled(ability)),
+                ),
+            );
+        }
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Creates the control used to set the base score of an ability.
+    fn base_score_control(&self, ability: Ability) -> Element<'_, Message> {
+        let generation = &self.draft.abilities;
+
+        match generation.method {
+            GenerationMethod::PointBuy => row![
+                button(Text::new("-").center()).width(25).on_press_maybe(
+                    generation
+                        .can_decrease(ability)
+                        .then_some(Message::AbilityDecreased(ability))
+                ),
+                Text::new(generation.point_buy.get(ability).to_string())
+                    .width(30)
+                    .center(),
+                button(Text::new("+").center()).width(25).on_press_maybe(
+                    generation
+                        .can_increase(ability)
+                        .then_some(Message::AbilityIncreased(ability))
+                ),
+            ]
+            .align_y(Alignment::Center)
+            .into(),
+            GenerationMethod::StandardArray | GenerationMethod::Rolled => {
+                let pool = generation
+                    .pool()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| PoolValue { index, value })
+                    .collect::<Vec<_>>();
+                let selected = generation
+                    .assignments
+                    .get(&ability)
+                    .and_then(|&index| pool.get(index))
+                    .copied();
+
+                pick_list(pool, selected, move |value| {
+                    Message::AbilityAssigned(ability, value)
+                })
+                .style(styles::dropdown)
+                .menu_style(styles::dropdown_item)
+                .placeholder("-")
+                .into()
+            }
+        }
+    }
+
+    /// Creates a fixed width cell of the ability score table.
+    fn ability_cell<'a>(content: impl Into<Element<'a, Message>>) -> Element<'a, Message> {
+        container(content).width(Self::ABILITY_COLUMN_WIDTH).into()
+    }
+}
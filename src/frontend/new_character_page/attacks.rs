@@ -0,0 +1,210 @@
+use iced::{
+    widget::{button, checkbox, column, container, pick_list, radio, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::{abilities::Ability, attacks},
+    frontend::{
+        item::{Weapon, WeaponProperty},
+        utils::styles as utils_styles,
+    },
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    const ATTACK_COLUMN_WIDTH: f32 = 160.0;
+
+    /// The number of recent rolls shown below the attacks.
+    const RECENT_ROLLS: usize = 5;
+
+    /// Displays controls to pick the weapons the character wields and how, and the attack and
+    /// damage rolls of each of them.
+    pub(super) fn attacks_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Attacks").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let mut weapons = column![Text::new("Weapons: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        let owned = self.draft.weapon_options(&self.content);
+        if owned.is_empty() {
+            weapons = weapons.push(
+                container(Text::new("No weapons in the equipment.").style(text::secondary))
+                    .padding(utils_styles::indented_padding()),
+            );
+        }
+        let wielded = self.draft.wielded_weapons(&self.content);
+        for (item, quantity) in owned {
+            let held = wielded
+                .iter()
+                .filter(|(_, other, ..)| other.id == item.id)
+                .count();
+            weapons = weapons.push(
+                container(
+                    row![
+                        container(Text::new(format!(
+                            "{} ({held}/{quantity} wielded)",
+                            item.name
+                        )))
+                        .width(Self::ATTACK_COLUMN_WIDTH * 2.0),
+                        button(Text::new("Wield"))
+                            .style(styles::menu_button)
+                            .on_press_maybe(
+                                ((held as u32) < quantity)
+                                    .then(|| Message::WeaponWielded(item.id.clone())),
+                            ),
+                    ]
+                    .spacing(10)
+                    .align_y(Alignment::Center),
+                )
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        for (i, item, weapon, wielded) in wielded {
+            weapons = weapons.push(
+                container(column![
+                    Text::new(item.name.clone()).font(utils_styles::bold_font()),
+                    self.wielded_weapon_controls(i, weapon, wielded),
+                ])
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        let weapons = container(weapons.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING);
+
+        column![title, weapons, self.attacks_list()].into()
+    }
+
+    /// Displays controls for how the weapon at index `i` of the wielded weapons is held.
+    fn wielded_weapon_controls<'a>(
+        &'a self,
+        i: usize,
+        weapon: &'a Weapon,
+        wielded: &'a attacks::WieldedWeapon,
+    ) -> Element<'a, Message> {
+        let mut controls = row![
+            Text::new("Magic bonus: +"),
+            pick_list(
+                (0..=attacks::MAX_MAGIC_BONUS).collect::<Vec<_>>(),
+                Some(wielded.magic_bonus),
+                move |bonus| Message::WeaponMagicBonusChanged(i, bonus),
+            )
+            .style(styles::dropdown)
+            .menu_style(styles::dropdown_item),
+            checkbox("Off hand", wielded.off_hand)
+                .on_toggle(move |off_hand| Message::WeaponOffHandToggled(i, off_hand)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let versatile = (weapon.properties.iter())
+            .any(|property| matches!(property, WeaponProperty::Versatile(_)));
+        if versatile {
+            controls = controls.push(
+                checkbox("Two hands", wielded.two_handed)
+                    .on_toggle(move |two_handed| Message::WeaponTwoHandedToggled(i, two_handed)),
+            );
+        }
+        if weapon.properties.contains(&WeaponProperty::Finesse) {
+            let picked = self
+                .draft
+                .attacks(&self.content)
+                .and_then(|attacks| attacks.into_iter().find(|(index, _)| *index == i))
+                .map(|(_, attack)| attack.ability);
+            for ability in [Ability::Strength, Ability::Dexterity] {
+                controls = controls.push(radio(
+                    ability.abbreviation(),
+                    ability,
+                    picked,
+                    move |ability| Message::WeaponFinesseSelected(i, ability),
+                ));
+            }
+        }
+        controls
+            .push(
+                button(Text::new("Drop"))
+                    .style(styles::menu_button)
+                    .on_press(Message::WeaponDropped(i)),
+            )
+            .into()
+    }
+
+    /// Displays the attack and damage rolls of the wielded weapons, with buttons to roll them,
+    /// and the latest rolls.
+    fn attacks_list(&self) -> Element<'_, Message> {
+        let mut content = column![Text::new("Attack Rolls: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+
+        match self.draft.attacks(&self.content) {
+            None => {
+                content = content.push(
+                    container(Text::new("Assign the ability scores to see the attacks."))
+                        .padding(utils_styles::indented_padding()),
+                );
+            }
+            Some(attacks) if attacks.is_empty() => {
+                content = content.push(
+                    container(Text::new("Wield a weapon to see its attacks."))
+                        .padding(utils_styles::indented_padding()),
+                );
+            }
+            Some(attacks) => {
+                let bold = |label: &'static str| {
+                    container(Text::new(label).font(utils_styles::bold_font()))
+                        .width(Self::ATTACK_COLUMN_WIDTH)
+                };
+                let mut table = column![row![
+                    bold("Weapon"),
+                    bold("To Hit"),
+                    bold("Damage"),
+                    bold("Notes")
+                ]]
+                .spacing(utils_styles::COLUMN_SPACING);
+                for (_, attack) in attacks {
+                    let roll = |label: String, expression: String| {
+                        container(
+                            button(Text::new(label))
+                                .style(styles::menu_button)
+                                .on_press(Message::AttackRollButtonPressed(expression)),
+                        )
+                        .width(Self::ATTACK_COLUMN_WIDTH)
+                    };
+                    table = table.push(
+                        row![
+                            container(Text::new(attack.name.clone()))
+                                .width(Self::ATTACK_COLUMN_WIDTH),
+                            roll(format!("{:+}", attack.to_hit), attack.attack_roll()),
+                            roll(
+                                format!("{} {}", attack.damage_roll(), attack.damage_type),
+                                attack.damage_roll(),
+                            ),
+                            Text::new(attack.notes.join(", ")).style(text::secondary),
+                        ]
+                        .align_y(Alignment::Center),
+                    );
+                }
+                content = content.push(container(table).padding(utils_styles::indented_padding()));
+            }
+        }
+
+        if !self.dice_rolls.is_empty() {
+            let mut rolls = column![Text::new("Latest rolls:").font(utils_styles::bold_font())];
+            for roll in self.dice_rolls.iter().rev().take(Self::RECENT_ROLLS) {
+                rolls = rolls.push(Text::new(roll.to_string()));
+            }
+            content = content.push(container(rolls).padding(utils_styles::indented_padding()));
+        }
+
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+}
@@ -0,0 +1,72 @@
+use iced::{
+    widget::{button, column, container, row, text, text_input, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::dice::{DiceError, Expression},
+    frontend::utils::styles as utils_styles,
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    /// Displays the dice roller.
+    pub(super) fn dice_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Dice Roller").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let parsed = self.dice_expression.trim().parse::<Expression>();
+        let input = row![
+            text_input(
+                "e.g. 2d6+3, 4d6kh3, 1d20adv, 2d6r2, 8d6/2",
+                &self.dice_expression
+            )
+            .on_input(Message::DiceExpressionChanged)
+            .on_submit(Message::DiceRollButtonPressed)
+            .padding(10),
+            button(Text::new("Roll"))
+                .style(styles::menu_button)
+                .padding(10)
+                .on_press_maybe(parsed.is_ok().then_some(Message::DiceRollButtonPressed)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        let stats: Element<Message> = match &parsed {
+            Ok(expression) => {
+                let average = match expression.average() {
+                    Ok(average) => format!("{average:.2}"),
+                    Err(DiceError::TooComplex) => "-".into(),
+                    Err(err) => err.to_string(),
+                };
+                Text::new(format!(
+                    "{}: range {} to {}, average {}",
+                    expression,
+                    expression.min(),
+                    expression.max(),
+                    average
+                ))
+                .into()
+            }
+            Err(_) if self.dice_expression.trim().is_empty() => column![].into(),
+            Err(err) => Text::new(err.to_string()).style(text::danger).into(),
+        };
+
+        let mut log = column![Text::new("Roll log:").font(utils_styles::bold_font())];
+        for (i, roll) in self.dice_rolls.iter().enumerate().rev() {
+            log = log.push(Text::new(format!("{}. {}", i + 1, roll)));
+        }
+
+        container(column![
+            title,
+            container(column![input, stats, log].spacing(10)).padding(utils_styles::BASE_PADDING),
+        ])
+        .into()
+    }
+}
@@ -0,0 +1,379 @@
+use iced::{
+    widget::{button, checkbox, column, container, pick_list, radio, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::{
+        armor_class,
+        content::{ContentKind, ContentRef},
+        equipment::{EquipmentSource, StartingWealth},
+    },
+    frontend::{
+        item::{self, EquipmentItem, Item, StartingEquipment},
+        utils::{styles as utils_styles, Speed},
+    },
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    const EQUIPMENT_COLUMN_WIDTH: f32 = 160.0;
+
+    /// Displays the starting equipment offered by the character's class and background, with
+    /// controls to make its choices or take the starting gold instead, and the resulting list
+    /// of items.
+    pub(super) fn equipment_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Equipment").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let Some(class) = (self.draft.class.as_ref()).and_then(|id| self.content.class(id).ok())
+        else {
+            return container(column![
+                title,
+                container(Text::new("Select a class to see its starting equipment."))
+                    .padding(utils_styles::BASE_PADDING),
+            ])
+            .into();
+        };
+
+        let wealth = self.draft.equipment.wealth;
+        let gold_taken = wealth != StartingWealth::Equipment;
+        let mut options = column![
+            Text::new("Starting Wealth: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE),
+            container(radio(
+                "Take the starting equipment of your class and background",
+                false,
+                Some(gold_taken),
+                Message::StartingWealthSelected,
+            ))
+            .padding(utils_styles::radio_padding()),
+        ];
+        if let Some(dice) = &class.starting_gold {
+            let mut gold = row![radio(
+                format!("Take {dice} gp instead to buy your equipment"),
+                true,
+                Some(gold_taken),
+                Message::StartingWealthSelected,
+            )]
+            .spacing(10)
+            .align_y(Alignment::Center);
+            if let StartingWealth::Gold(rolled) = wealth {
+                gold = gold.push(
+                    button(Text::new(if rolled.is_some() { "Reroll" } else { "Roll" }))
+                        .style(styles::menu_button)
+                        .on_press(Message::StartingGoldRollButtonPressed),
+                );
+                if let Some(rolled) = rolled {
+                    gold = gold.push(Text::new(format!("Rolled {rolled} gp")));
+                }
+            }
+            options = options.push(container(gold).padding(utils_styles::radio_padding()));
+        }
+        let options = container(options).padding(utils_styles::BASE_PADDING);
+
+        let mut content = column![title, options];
+        if !gold_taken {
+            for source in self.draft.equipment_sources(&self.content) {
+                content = content.push(self.equipment_source_view(source));
+            }
+        }
+        content
+            .push(self.equipment_list())
+            .push(self.armor_class_view())
+            .into()
+    }
+
+    /// Displays the starting equipment offered by a class or background, with controls to
+    /// choose between its alternatives and pick items out of categories.
+    fn equipment_source_view<'a>(&'a self, source: EquipmentSource<'a>) -> Element<'a, Message> {
+        let item_name = |id: &str| self.content.name_of(ContentKind::Item, id);
+        let mut content = column![Text::new(format!("{} Starting Equipment: ", source.name))
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        if source.lines.is_empty() && source.gold == 0 {
+            content = content.push(
+                container(Text::new("No starting equipment.").style(text::secondary))
+                    .padding(utils_styles::indented_padding()),
+            );
+        }
+
+        for (key, line) in source.lines {
+            let line: Element<'a, Message> = match line {
+                StartingEquipment::All(items) => column![
+                    Text::new(format!("• {}", item::describe_items(items, item_name))),
+                    self.equipment_picks(&key, line),
+                ]
+                .into(),
+                StartingEquipment::Either(alternatives) => {
+                    let chosen = self.draft.equipment.alternatives.get(&key).copied();
+                    let mut choice = column![Text::new("• Choose one of the following:")];
+                    for (i, items) in alternatives.iter().enumerate() {
+                        let radio_key = key.clone();
+                        choice = choice.push(
+                            container(radio(
+                                item::describe_items(items, item_name),
+                                i,
+                                chosen,
+                                move |i| Message::EquipmentAlternativeChosen(radio_key.clone(), i),
+                            ))
+                            .padding(utils_styles::radio_padding()),
+                        );
+                        if chosen == Some(i) {
+                            choice = choice.push(self.equipment_picks(&key, line));
+                        }
+                    }
+                    choice.into()
+                }
+            };
+            content = content.push(container(line).padding(utils_styles::indented_padding()));
+        }
+        if source.gold > 0 {
+            content = content.push(
+                container(Text::new(format!("• {} gp", source.gold)))
+                    .padding(utils_styles::indented_padding()),
+            );
+        }
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Displays a dropdown list to pick an item for each item of the chosen alternative of a
+    /// line of starting equipment that offers any item of a category.
+    fn equipment_picks<'a>(
+        &'a self,
+        key: &str,
+        line: &'a StartingEquipment,
+    ) -> Element<'a, Message> {
+        let mut picks = column![];
+        let granted = self.draft.equipment.granted(line, key).unwrap_or_default();
+        for (key, item) in granted {
+            let EquipmentItem::Any { category, .. } = item else {
+                continue;
+            };
+            let options = self
+                .content
+                .items_in(category)
+                .into_iter()
+                .map(|item| ContentRef {
+                    id: item.id.clone(),
+                    name: item.name.clone(),
+                })
+                .collect::<Vec<_>>();
+            let selected = self
+                .draft
+                .equipment
+                .items
+                .get(&key)
+                .and_then(|id| options.iter().find(|option| &option.id == id))
+                .cloned();
+            picks = picks.push(
+                row![
+                    Text::new(format!("Any {category}:")),
+                    pick_list(options, selected, move |item| {
+                        Message::EquipmentItemPicked(key.clone(), item)
+                    })
+                    .style(styles::dropdown)
+                    .menu_style(styles::dropdown_item)
+                    .placeholder("Select an item:"),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        container(picks.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::indented_padding())
+            .into()
+    }
+
+    /// Displays the items the character starts with, along with their total weight and the
+    /// starting gold.
+    fn equipment_list(&self) -> Element<'_, Message> {
+        let mut content = column![Text::new("Equipment List: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+
+        let items = self.draft.starting_items(&self.content);
+        let bold = |label: &'static str| {
+            container(Text::new(label).font(utils_styles::bold_font()))
+                .width(Self::EQUIPMENT_COLUMN_WIDTH)
+        };
+        let mut table = column![row![
+            bold("Item"),
+            bold("Quantity"),
+            bold("Weight"),
+            bold("Details")
+        ]]
+        .spacing(utils_styles::COLUMN_SPACING);
+        let mut total = 0.0;
+        for stack in &items {
+            let Ok(item) = self.content.item(&stack.item) else {
+                continue;
+            };
+            let weight = item.weight_of(stack.quantity);
+            total += weight;
+            let cell =
+                |content: String| container(Text::new(content)).width(Self::EQUIPMENT_COLUMN_WIDTH);
+            table = table.push(row![
+                cell(item.name.clone()),
+                cell(stack.quantity.to_string()),
+                cell(item::pounds(weight)),
+                Text::new(item.describe()).style(text::secondary),
+            ]);
+        }
+        if items.is_empty() {
+            table = table.push(Text::new("No items yet."));
+        }
+        if self.draft.inventory.is_some() {
+            table = table.push(
+                Text::new(
+                    "Your inventory has been edited: reset it in the Inventory step to carry \
+                     these items instead.",
+                )
+                .style(text::secondary),
+            );
+        }
+
+        content = content.push(container(table).padding(utils_styles::indented_padding()));
+        content = content.push(
+            container(column![
+                Text::new(format!("Total weight: {}", item::pounds(total))),
+                Text::new(format!(
+                    "Gold: {} gp",
+                    self.draft.starting_gold(&self.content)
+                )),
+            ])
+            .padding(utils_styles::indented_padding()),
+        );
+        if self.draft.wealth.is_some() {
+            content = content.push(
+                container(
+                    Text::new(
+                        "Your purse has changed since: reset it in the Wealth step to start with \
+                         this gold instead.",
+                    )
+                    .style(text::secondary),
+                )
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Displays controls to pick the armor and shield the character wears, the resulting Armor
+    /// Class and speed, and warnings about the armor.
+    fn armor_class_view(&self) -> Element<'_, Message> {
+        let mut content = column![Text::new("Armor Class: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+
+        let armor = self.draft.armor_options(&self.content);
+        let shields = self.draft.shield_options(&self.content);
+        let worn = self
+            .draft
+            .worn_armor(&self.content)
+            .map(|(item, _)| &item.id);
+        let wielded = self
+            .draft
+            .worn_shield(&self.content)
+            .map(|(item, _)| &item.id);
+        let mut pickers = row![
+            self.worn_item_picker("Armor", "No armor", armor, worn, Message::ArmorWorn),
+            self.worn_item_picker(
+                "Shield",
+                "No shield",
+                shields,
+                wielded,
+                Message::ShieldWielded
+            ),
+        ]
+        .spacing(utils_styles::COLUMN_SPACING);
+        if self.draft.knows_mage_armor() {
+            pickers = pickers.push(
+                checkbox("Mage Armor is active", self.draft.worn.mage_armor)
+                    .on_toggle(Message::MageArmorToggled),
+            );
+        }
+        content = content.push(container(pickers).padding(utils_styles::indented_padding()));
+
+        let mut details = column![];
+        match self.draft.armor_class_options(&self.content) {
+            Some(options) => {
+                let best = armor_class::best(&options);
+                if let Some(best) = best {
+                    details = details.push(
+                        Text::new(format!("AC {}: {}", best.total(), best.breakdown()))
+                            .font(utils_styles::bold_font()),
+                    );
+                }
+                for option in options.iter().filter(|&option| Some(option) != best) {
+                    details = details.push(
+                        Text::new(format!("AC {}: {}", option.total(), option.breakdown()))
+                            .style(text::secondary),
+                    );
+                }
+            }
+            None => {
+                details = details.push(Text::new(
+                    "Assign the ability scores to see the Armor Class.",
+                ))
+            }
+        }
+        let speeds = self.draft.speeds(&self.content);
+        if !speeds.is_empty() {
+            let speeds = speeds.iter().map(Speed::to_string).collect::<Vec<_>>();
+            details = details.push(Text::new(speeds.join(" ")));
+        }
+        for warning in self.draft.armor_warnings(&self.content) {
+            details = details.push(Text::new(warning).style(text::danger));
+        }
+        content = content.push(
+            container(details.spacing(utils_styles::COLUMN_SPACING))
+                .padding(utils_styles::indented_padding()),
+        );
+
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Displays radio buttons to pick one of `items` to wear, or none of them.
+    fn worn_item_picker<'a>(
+        &'a self,
+        label: &'a str,
+        none: &'a str,
+        items: Vec<&'a Item>,
+        worn: Option<&String>,
+        on_pick: fn(Option<String>) -> Message,
+    ) -> Element<'a, Message> {
+        let selected = worn
+            .and_then(|id| items.iter().position(|item| &item.id == id))
+            .map_or(0, |i| i + 1);
+        let ids = std::iter::once(None)
+            .chain(items.iter().map(|item| Some(item.id.clone())))
+            .collect::<Vec<_>>();
+        let names = std::iter::once(none).chain(items.iter().map(|item| item.name.as_str()));
+
+        let mut picker = column![Text::new(format!("{label}:")).font(utils_styles::bold_font())];
+        for (i, name) in names.enumerate() {
+            let ids = ids.clone();
+            picker = picker.push(
+                container(radio(name, i, Some(selected), move |i| {
+                    on_pick(ids[i].clone())
+                }))
+                .padding(utils_styles::radio_padding()),
+            );
+        }
+        container(picker).width(Self::EQUIPMENT_COLUMN_WIDTH).into()
+    }
+}
@@ -0,0 +1,116 @@
+use iced::{
+    widget::{checkbox, column, container, Text},
+    Element, Length,
+};
+
+use crate::frontend::{advancement, utils::styles as utils_styles};
+
+use super::{Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    /// Displays the options of the character's class features, its ability score improvements,
+    /// and the feat granted by its race, along with the campaign option allowing feats.
+    pub(super) fn features_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Features").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let features = self
+            .draft
+            .features(&self.content)
+            .into_iter()
+            .filter(|feature| {
+                feature.options_up_to(self.draft.feature_level(&self.content, feature)) > 0
+            })
+            .collect::<Vec<_>>();
+        let levels = self.draft.improvement_levels(&self.content);
+        let campaign = container(
+            checkbox("Allow feats (campaign option)", self.draft.campaign.feats)
+                .on_toggle(Message::FeatsToggled),
+        )
+        .padding(utils_styles::BASE_PADDING);
+        let bonus_feat = self.draft.has_bonus_feat(&self.content);
+        if features.is_empty() && levels.is_empty() && !bonus_feat {
+            return container(column![
+                title,
+                campaign,
+                container(Text::new(
+                    "None of the character's features need a choice at this level."
+                ))
+                .padding(utils_styles::BASE_PADDING),
+            ])
+            .into();
+        }
+
+        let feat = match advancement::feat_choice_view(
+            &self.draft,
+            &self.content,
+            None,
+            &Message::FeatSelected,
+            &Message::FeatAbilitySelected,
+        ) {
+            Some(feat) if bonus_feat => container(column![
+                Text::new("Bonus Feat: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(feat).padding(utils_styles::indented_padding()),
+            ])
+            .padding(utils_styles::BASE_PADDING),
+            _ => container(column![]),
+        };
+
+        let options = if !features.is_empty() {
+            let mut content = column![Text::new("Feature Options: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE)];
+            for feature in features {
+                content = content.push(advancement::feature_options_view(
+                    feature,
+                    &self.draft.feature_options(&self.content, feature),
+                    &self.draft.available_options(&self.content, feature),
+                    feature.options_up_to(self.draft.feature_level(&self.content, feature)),
+                    &Message::FeatureOptionToggled,
+                ));
+            }
+            container(content).padding(utils_styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        let improvements = match self.draft.final_ability_scores(&self.content) {
+            Some(scores) if !levels.is_empty() => {
+                let mut content = column![Text::new("Ability Score Improvements: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE)];
+                for level in levels {
+                    content = content.push(advancement::improvement_view(
+                        level,
+                        self.draft.improvements.get(&level),
+                        scores,
+                        advancement::feat_choice_view(
+                            &self.draft,
+                            &self.content,
+                            Some(level),
+                            &Message::FeatSelected,
+                            &Message::FeatAbilitySelected,
+                        ),
+                        &Message::ImprovementChanged,
+                        Message::FeatCleared(level),
+                    ));
+                }
+                container(content).padding(utils_styles::BASE_PADDING)
+            }
+            Some(_) => container(column![]),
+            None => container(Text::new(
+                "Assign every ability score to spend the ability score improvements.",
+            ))
+            .padding(utils_styles::BASE_PADDING),
+        };
+
+        container(column![title, campaign, feat, options, improvements]).into()
+    }
+}
@@ -0,0 +1,149 @@
+use iced::{
+    widget::{button, column, container, radio, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::hit_points::{HitPointGain, LevelHitPoints},
+    frontend::{class::ordinal, utils::styles as utils_styles},
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    const HIT_POINTS_COLUMN_WIDTH: f32 = 110.0;
+
+    /// Displays the character's maximum hit points, and lets the hit die be rolled or averaged
+    /// for each level after the first.
+    pub(super) fn hit_points_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Hit Points").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let Some(levels) = self.draft.hit_point_levels(&self.content) else {
+            return container(column![
+                title,
+                container(Text::new(
+                    "Select a class and assign every ability score to see the hit points."
+                ))
+                .padding(utils_styles::BASE_PADDING),
+            ])
+            .into();
+        };
+        let hit_dice = self
+            .draft
+            .hit_dice(&self.content)
+            .iter()
+            .map(|(count, die)| format!("{count}{die}"))
+            .collect::<Vec<_>>()
+            .join(" + ");
+
+        let total: u16 = levels.iter().map(LevelHitPoints::total).sum();
+        let current = self
+            .draft
+            .current_hit_points(&self.content)
+            .unwrap_or(total);
+        let summary = container(column![
+            row![
+                Text::new("Maximum Hit Points: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                Text::new(total.to_string()).size(utils_styles::SECTION_FONT_SIZE),
+            ],
+            row![
+                Text::new("Current Hit Points: ").font(utils_styles::bold_font()),
+                button(Text::new("-"))
+                    .style(styles::menu_button)
+                    .on_press_maybe(
+                        (current > 0).then(|| Message::CurrentHitPointsChanged(current - 1))
+                    ),
+                Text::new(current.to_string()),
+                button(Text::new("+"))
+                    .style(styles::menu_button)
+                    .on_press_maybe(
+                        (current < total).then(|| Message::CurrentHitPointsChanged(current + 1))
+                    ),
+                button(Text::new("Heal fully"))
+                    .style(styles::menu_button)
+                    .on_press_maybe(
+                        (current < total).then_some(Message::CurrentHitPointsChanged(total))
+                    ),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+            container(Text::new(format!("Hit Dice: {hit_dice}")).style(text::secondary))
+                .padding(utils_styles::indented_padding()),
+        ])
+        .padding(utils_styles::BASE_PADDING);
+
+        let bold = |label: &'static str| {
+            container(Text::new(label).font(utils_styles::bold_font()))
+                .width(Self::HIT_POINTS_COLUMN_WIDTH)
+        };
+        let header = row![
+            bold("Level"),
+            bold("Hit Die"),
+            bold("Constitution"),
+            bold("Bonus"),
+            bold("Total"),
+        ];
+        let mut table = column![header].spacing(utils_styles::COLUMN_SPACING);
+        for level in &levels {
+            table = table.push(Self::hit_points_row(level));
+        }
+
+        container(column![
+            title,
+            summary,
+            container(table).padding(utils_styles::BASE_PADDING),
+        ])
+        .into()
+    }
+
+    /// Creates the row of the hit points table for one level.
+    fn hit_points_row<'a>(level: &LevelHitPoints) -> Element<'a, Message> {
+        let cell =
+            |content: Element<'a, Message>| container(content).width(Self::HIT_POINTS_COLUMN_WIDTH);
+
+        let number = level.level;
+        let hit_die = level.hit_die;
+        let die: Element<'a, Message> = match level.gain {
+            None => Text::new(format!("{} ({hit_die} max)", level.die)).into(),
+            Some(gain) => Text::new(match gain {
+                HitPointGain::Average => format!("{} ({hit_die} average)", level.die),
+                HitPointGain::Rolled(_) => format!("{} ({hit_die} rolled)", level.die),
+            })
+            .into(),
+        };
+
+        let mut content = row![
+            cell(Text::new(ordinal(number)).into()),
+            cell(die),
+            cell(Text::new(format!("{:+}", level.constitution)).into()),
+            cell(Text::new(format!("+{}", level.bonus)).into()),
+            cell(Text::new(level.total().to_string()).into()),
+        ]
+        .align_y(Alignment::Center);
+
+        if let Some(gain) = level.gain {
+            let rolled = matches!(gain, HitPointGain::Rolled(_));
+            content = content.push(
+                row![
+                    radio("Average", false, Some(rolled), move |_| {
+                        Message::HitPointsAverageSelected(number)
+                    }),
+                    button(Text::new(if rolled { "Reroll" } else { "Roll" }))
+                        .style(styles::menu_button)
+                        .on_press(Message::HitPointsRollButtonPressed(number)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        content.into()
+    }
+}
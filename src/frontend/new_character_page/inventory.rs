@@ -0,0 +1,287 @@
+use std::fmt::Display;
+
+use iced::{
+    padding,
+    widget::{button, checkbox, column, container, pick_list, radio, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::{
+        content::ContentRef,
+        inventory::{EncumbranceRule, Inventory, InventoryEntry, MAX_ATTUNED},
+    },
+    frontend::{
+        item::{self, ItemKind},
+        utils::{styles as utils_styles, Speed},
+    },
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+/// A container an inventory entry can be moved into, or the character itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ContainerOption {
+    /// The id of the container entry, or `None` for the character.
+    id: Option<u32>,
+
+    /// The name displayed in the dropdown list.
+    name: String,
+}
+
+impl Display for ContainerOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+impl NewCharacterPage {
+    const INVENTORY_COLUMN_WIDTH: f32 = 140.0;
+
+    /// How far each level of nesting in containers is indented.
+    const NESTING_INDENT: f32 = 20.0;
+
+    /// Displays the items the character carries, with controls to add, remove, equip, and move
+    /// them between containers, and how much their weight slows the character down.
+    pub(super) fn inventory_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Inventory").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        column![title, self.encumbrance_view(), self.inventory_list()].into()
+    }
+
+    /// Displays the campaign option for encumbrance, the weight the character carries against
+    /// its carrying capacity, and the resulting speeds.
+    fn encumbrance_view(&self) -> Element<'_, Message> {
+        let rule = self.draft.campaign.encumbrance;
+        let mut content = column![
+            Text::new("Encumbrance: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE),
+            container(radio(
+                "Standard: slowed down only past the carrying capacity (campaign option)",
+                EncumbranceRule::Standard,
+                Some(rule),
+                Message::EncumbranceRuleSelected,
+            ))
+            .padding(utils_styles::radio_padding()),
+            container(radio(
+                "Variant: slowed down past 5 and 10 times the Strength score (campaign option)",
+                EncumbranceRule::Variant,
+                Some(rule),
+                Message::EncumbranceRuleSelected,
+            ))
+            .padding(utils_styles::radio_padding()),
+        ];
+
+        let weight = self.draft.carried_weight(&self.content);
+        let mut details = column![];
+        match self.draft.carrying_capacity(&self.content) {
+            Some(capacity) => {
+                details = details.push(Text::new(format!(
+                    "Carrying {} of {}",
+                    item::pounds(weight),
+                    item::pounds(capacity)
+                )))
+            }
+            None => {
+                details = details.push(Text::new(format!(
+                    "Carrying {}. Assign the ability scores to see the carrying capacity.",
+                    item::pounds(weight)
+                )))
+            }
+        }
+        if let Some(description) =
+            (self.draft.encumbrance(&self.content)).and_then(|encumbrance| encumbrance.describe())
+        {
+            details = details.push(Text::new(description).style(text::danger));
+        }
+        let speeds = self.draft.speeds(&self.content);
+        if !speeds.is_empty() {
+            let speeds = speeds.iter().map(Speed::to_string).collect::<Vec<_>>();
+            details = details.push(Text::new(speeds.join(" ")));
+        }
+        for warning in self.draft.inventory_warnings(&self.content) {
+            details = details.push(Text::new(warning).style(text::danger));
+        }
+        content = content.push(
+            container(details.spacing(utils_styles::COLUMN_SPACING))
+                .padding(utils_styles::indented_padding()),
+        );
+
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Displays the items the character carries, nested under the containers they are in, with
+    /// controls to edit each of them.
+    fn inventory_list(&self) -> Element<'_, Message> {
+        let mut content = column![Text::new("Items: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+
+        let items = (self.content.items())
+            .map(|item| ContentRef {
+                id: item.id.clone(),
+                name: item.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        let controls = row![
+            pick_list(items, None::<ContentRef>, Message::InventoryItemAdded)
+                .style(styles::dropdown)
+                .menu_style(styles::dropdown_item)
+                .placeholder("Add an item:"),
+            button(Text::new("Reset to starting equipment"))
+                .style(styles::menu_button)
+                .on_press_maybe(
+                    (self.draft.inventory.is_some())
+                        .then_some(Message::InventoryResetButtonPressed),
+                ),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+        content = content.push(container(controls).padding(utils_styles::indented_padding()));
+
+        let inventory = self.draft.inventory(&self.content);
+        let bold = |label: &'static str| {
+            container(Text::new(label).font(utils_styles::bold_font()))
+                .width(Self::INVENTORY_COLUMN_WIDTH)
+        };
+        let mut table = column![row![
+            bold("Item"),
+            bold("Quantity"),
+            bold("Weight"),
+            bold("Equipped"),
+            bold("Attuned"),
+            bold("Carried In"),
+        ]]
+        .spacing(utils_styles::COLUMN_SPACING);
+        let tree = inventory.tree();
+        if tree.is_empty() {
+            table = table.push(Text::new("No items yet.").style(text::secondary));
+        }
+        for (depth, entry) in tree {
+            table = table.push(self.inventory_row(&inventory, depth, entry));
+        }
+        content = content.push(container(table).padding(utils_styles::indented_padding()));
+        content = content.push(
+            container(
+                Text::new(format!(
+                    "Attuned to {} of {MAX_ATTUNED} items.",
+                    inventory.attuned()
+                ))
+                .style(text::secondary),
+            )
+            .padding(utils_styles::indented_padding()),
+        );
+
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Displays an entry of the inventory, indented by how deeply it is nested in containers,
+    /// with controls to edit it.
+    fn inventory_row(
+        &self,
+        inventory: &Inventory,
+        depth: usize,
+        entry: &InventoryEntry,
+    ) -> Element<'_, Message> {
+        let id = entry.id;
+        let Ok(item) = self.content.item(&entry.item) else {
+            return Text::new(format!("Unknown item: {}", entry.item))
+                .style(text::danger)
+                .into();
+        };
+        let cell = |content: Element<'static, Message>| {
+            container(content).width(Self::INVENTORY_COLUMN_WIDTH)
+        };
+
+        let name = container(Text::new(item.name.clone()))
+            .padding(padding::left(Self::NESTING_INDENT * depth as f32));
+        let quantity = row![
+            button(Text::new("-"))
+                .style(styles::menu_button)
+                .on_press(Message::InventoryQuantityChanged(id, entry.quantity - 1)),
+            Text::new(entry.quantity.to_string()),
+            button(Text::new("+"))
+                .style(styles::menu_button)
+                .on_press(Message::InventoryQuantityChanged(id, entry.quantity + 1)),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center);
+        let mut weight = column![Text::new(item::pounds(inventory.weight(&self.content, id)))];
+        if let ItemKind::Container(container) = &item.kind {
+            weight = weight.push(
+                Text::new(format!(
+                    "Holds {}/{}",
+                    item::pounds(inventory.contents_weight(&self.content, id)),
+                    item::pounds(container.capacity * entry.quantity as f32),
+                ))
+                .style(text::secondary),
+            );
+        }
+        let attuned: Element<'static, Message> = if item.attunement {
+            checkbox("", entry.attuned)
+                .on_toggle(move |attuned| Message::InventoryItemAttuned(id, attuned))
+                .into()
+        } else {
+            Text::new("").into()
+        };
+
+        let mut containers = vec![ContainerOption {
+            id: None,
+            name: "Carried".to_string(),
+        }];
+        for other in &inventory.entries {
+            let is_container = (self.content.item(&other.item))
+                .is_ok_and(|item| matches!(item.kind, ItemKind::Container(_)));
+            if is_container && other.id != id && !inventory.is_inside(other.id, id) {
+                containers.push(ContainerOption {
+                    id: Some(other.id),
+                    name: self.content.item(&other.item).map_or_else(
+                        |_| other.item.clone(),
+                        |item| format!("{} #{}", item.name, other.id),
+                    ),
+                });
+            }
+        }
+        let selected = containers
+            .iter()
+            .find(|option| option.id == entry.container)
+            .cloned();
+
+        row![
+            cell(name.into()),
+            cell(quantity.into()),
+            cell(weight.into()),
+            cell(
+                checkbox("", entry.equipped)
+                    .on_toggle(move |equipped| Message::InventoryItemEquipped(id, equipped))
+                    .into()
+            ),
+            cell(attuned),
+            container(
+                pick_list(containers, selected, move |option| {
+                    Message::InventoryItemMoved(id, option.id)
+                })
+                .style(styles::dropdown)
+                .menu_style(styles::dropdown_item)
+            )
+            .width(Self::INVENTORY_COLUMN_WIDTH),
+            button(Text::new("Remove"))
+                .style(styles::menu_button)
+                .on_press(Message::InventoryItemRemoved(id)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center)
+        .into()
+    }
+}
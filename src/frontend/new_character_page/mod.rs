@@ -0,0 +1,1194 @@
+use std::sync::Arc;
+
+use iced::{
+    widget::{
+        button, column, container, pane_grid, pick_list, row, scrollable, text, text_input,
+        PaneGrid, Text,
+    },
+    Alignment, Element, Length,
+};
+
+use crate::backend::{
+    abilities::{Ability, GenerationMethod},
+    character::{Character, MAX_LEVEL},
+    content::{ContentKind, ContentRef, ContentRegistry},
+    currency,
+    dice::{RollResult, Roller},
+    hit_points::HitPointGain,
+    inventory::EncumbranceRule,
+    spellcasting::SpellList,
+};
+
+use super::{
+    background::{Personality, PersonalityTable},
+    class::Subclass,
+    item::Coin,
+    race::Subrace,
+    utils::{self, proficiency::Proficiency, styles as utils_styles},
+};
+
+mod abilities;
+mod attacks;
+mod dice;
+mod equipment;
+mod features;
+mod hit_points;
+mod inventory;
+mod proficiencies;
+mod skills;
+mod spells;
+mod wealth;
+
+pub use abilities::PoolValue;
+
+#[derive(Debug, Clone, Default)]
+pub enum Message {
+    /// `Race` button pressed to choose race.
+    #[default]
+    RaceButtonPressed,
+
+    /// `Class` button pressed to choose class.
+    ClassButtonPressed,
+
+    /// `Background` button pressed to choose background.
+    BackgroundButtonPressed,
+
+    /// `Abilities` button pressed to generate ability scores.
+    AbilitiesButtonPressed,
+
+    /// `Features` button pressed to pick class feature options and ability score improvements.
+    FeaturesButtonPressed,
+
+    /// `Spells` button pressed to pick cantrips and spells.
+    SpellsButtonPressed,
+
+    /// `Hit Points` button pressed to review hit points.
+    HitPointsButtonPressed,
+
+    /// `Proficiencies` button pressed to review proficiencies.
+    ProficienciesButtonPressed,
+
+    /// `Equipment` button pressed to choose the starting equipment.
+    EquipmentButtonPressed,
+
+    /// `Inventory` button pressed to manage the items the character carries.
+    InventoryButtonPressed,
+
+    /// `Wealth` button pressed to manage the coins the character carries.
+    WealthButtonPressed,
+    AttacksButtonPressed,
+
+    /// `Skills` button pressed to review skill and saving throw modifiers.
+    SkillsButtonPressed,
+
+    /// `Dice` button pressed to roll dice.
+    DiceButtonPressed,
+
+    /// Race has been selected.
+    RaceSelected(ContentRef),
+
+    /// Subrace has been selected.
+    SubraceSelected(Subrace),
+
+    /// Class has been selected.
+    ClassSelected(ContentRef),
+
+    /// Subclass has been selected.
+    SubclassSelected(Subclass),
+
+    /// The character's level has been selected.
+    LevelSelected(u8),
+
+    /// Background has been selected.
+    BackgroundSelected(ContentRef),
+
+    /// An entry has been picked from one of the background's personality tables.
+    PersonalitySelected(PersonalityTable, String),
+
+    /// `Roll on table` button pressed to roll on one of the background's personality tables.
+    PersonalityRollButtonPressed(PersonalityTable),
+
+    /// The character's name has been edited.
+    NameChanged(String),
+
+    /// `Save` button pressed to save the character.
+    SaveButtonPressed,
+
+    /// Ability score generation method has been selected.
+    AbilityMethodSelected(GenerationMethod),
+
+    /// A value from the pool has been assigned to an ability.
+    AbilityAssigned(Ability, PoolValue),
+
+    /// `+` button pressed to increase a point buy score.
+    AbilityIncreased(Ability),
+
+    /// `-` button pressed to decrease a point buy score.
+    AbilityDecreased(Ability),
+
+    /// `Roll` button pressed to roll ability scores.
+    AbilityRollButtonPressed,
+
+    /// An option has been picked or unpicked for a class feature, given by their ids.
+    FeatureOptionToggled(String, String),
+
+    /// A point of the ability score improvement gained at the given level has been spent on an
+    /// ability, or taken back if `false`.
+    ImprovementChanged(u8, Ability, bool),
+
+    /// A feat has been picked for the ability score improvement gained at the given level, or
+    /// for the racial traits if `None`.
+    FeatSelected(Option<u8>, ContentRef),
+
+    /// The ability increased by the feat taken at the given level, or for the racial traits if
+    /// `None`, has been picked.
+    FeatAbilitySelected(Option<u8>, Ability),
+
+    /// `Increase ability scores instead` button pressed to drop the feat taken at the given
+    /// level.
+    FeatCleared(u8),
+
+    /// The campaign option allowing feats has been toggled.
+    FeatsToggled(bool),
+
+    /// An ability has been picked or unpicked for the racial ability score increases chosen by
+    /// the character.
+    RacialIncreaseToggled(Ability),
+
+    /// A spell has been picked or unpicked for one of the lists of a spellcasting class, given
+    /// by the ids of the class and the spell.
+    SpellToggled(String, SpellList, String),
+
+    /// `Expend` button pressed to expend a spell slot of the given level.
+    SpellSlotExpended(u8),
+
+    /// `Expend` button pressed to expend a Pact Magic slot.
+    PactSlotExpended,
+
+    /// `Short Rest` button pressed to regain the Pact Magic slots.
+    ShortRestButtonPressed,
+
+    /// `Long Rest` button pressed to regain every hit point and spell slot.
+    LongRestButtonPressed,
+
+    /// The fixed average has been picked for the hit die of the given level.
+    HitPointsAverageSelected(u8),
+
+    /// `Roll` button pressed to roll the hit die of the given level.
+    HitPointsRollButtonPressed(u8),
+
+    /// The current hit points have been changed to the given value.
+    CurrentHitPointsChanged(u16),
+
+    /// Proficiencies have been selected for the choices with the given key.
+    ProficiencyChosen(String, Vec<Proficiency>),
+
+    /// A replacement has been picked for the duplicate proficiency in the given slot.
+    ProficiencyReplaced(String, Proficiency),
+
+    /// A proficiency has been picked or unpicked for expertise.
+    ExpertiseToggled(Proficiency),
+
+    /// The starting gold of the class has been picked instead of the starting equipment if
+    /// `true`, or the starting equipment if `false`.
+    StartingWealthSelected(bool),
+
+    /// `Roll` button pressed to roll the starting gold.
+    StartingGoldRollButtonPressed,
+
+    /// An alternative has been chosen for the line of starting equipment with the given key.
+    EquipmentAlternativeChosen(String, usize),
+
+    /// An item has been picked for the item of starting equipment with the given key that
+    /// offers any item of a category.
+    EquipmentItemPicked(String, ContentRef),
+
+    /// The suit of armor with the given id has been put on, or armor has been taken off if
+    /// `None`.
+    ArmorWorn(Option<String>),
+
+    /// The shield with the given id has been picked up, or the shield has been put down if
+    /// `None`.
+    ShieldWielded(Option<String>),
+
+    /// Mage Armor has been marked as active on the character or not.
+    MageArmorToggled(bool),
+
+    /// The campaign option deciding how much weight slows characters down has been picked.
+    EncumbranceRuleSelected(EncumbranceRule),
+
+    /// `Reset` button pressed to replace the inventory with the starting equipment.
+    InventoryResetButtonPressed,
+
+    /// The item has been picked to add one of it to the inventory.
+    InventoryItemAdded(ContentRef),
+
+    /// The quantity of the inventory entry with the given id has been changed.
+    InventoryQuantityChanged(u32, u32),
+
+    /// `Remove` button pressed to remove the inventory entry with the given id.
+    InventoryItemRemoved(u32),
+
+    /// The inventory entry with the given id has been marked as equipped or not.
+    InventoryItemEquipped(u32, bool),
+
+    /// The inventory entry with the given id has been marked as attuned to or not.
+    InventoryItemAttuned(u32, bool),
+
+    /// The inventory entry with the given id has been moved into the container entry with the
+    /// given id, or out of every container if `None`.
+    InventoryItemMoved(u32, Option<u32>),
+
+    /// The amount of coins to receive or pay has been edited.
+    CoinAmountChanged(String),
+
+    /// The coin to receive or pay has been picked.
+    CoinSelected(Coin),
+
+    /// `Receive` button pressed to add the coins to the purse.
+    CoinsReceiveButtonPressed,
+
+    /// `Pay` button pressed to pay the coins out of the purse.
+    CoinsPayButtonPressed,
+
+    /// `Break` button pressed to exchange one of the given coin for smaller coins.
+    CoinBreakButtonPressed(Coin),
+
+    /// `Consolidate` button pressed to exchange the coins for as few coins as possible.
+    CoinsConsolidateButtonPressed,
+
+    /// The item has been picked to buy it.
+    ItemBought(ContentRef),
+
+    /// `Reset` button pressed to replace the purse with the starting gold.
+    WealthResetButtonPressed,
+
+    /// `Wield` button pressed to wield another one of the weapon with the given id.
+    WeaponWielded(String),
+
+    /// `Drop` button pressed to stop wielding the weapon at the given index.
+    WeaponDropped(usize),
+
+    /// The versatile weapon at the given index has been marked as held in two hands or not.
+    WeaponTwoHandedToggled(usize, bool),
+
+    /// The weapon at the given index has been marked as held in the off hand or not.
+    WeaponOffHandToggled(usize, bool),
+
+    /// The magic bonus of the weapon at the given index has been changed.
+    WeaponMagicBonusChanged(usize, u8),
+
+    /// An ability has been picked for the finesse weapon at the given index.
+    WeaponFinesseSelected(usize, Ability),
+
+    /// `Roll` button pressed to roll the given attack or damage roll.
+    AttackRollButtonPressed(String),
+
+    /// The dice expression in the dice roller has been edited.
+    DiceExpressionChanged(String),
+
+    /// `Roll` button pressed to roll the dice expression.
+    DiceRollButtonPressed,
+}
+
+/// Represents the menu and info panes of this page.
+#[derive(Debug, Clone)]
+enum Pane {
+    Menu,
+    Info,
+}
+
+/// Commands returned by the `update` function.
+#[derive(Debug, Clone)]
+pub enum Command {
+    None,
+    Save(Box<Character>),
+}
+
+/// Menu options for the `New Character` page.
+#[derive(Debug, Clone, PartialEq)]
+enum MenuOpts {
+    Race,
+    Class,
+    Background,
+    Abilities,
+    Features,
+    Spells,
+    HitPoints,
+    Proficiencies,
+    Equipment,
+    Inventory,
+    Wealth,
+    Attacks,
+    Skills,
+    Dice,
+}
+
+impl From<Message> for MenuOpts {
+    fn from(value: Message) -> Self {
+        match value {
+            Message::RaceButtonPressed => Self::Race,
+            Message::ClassButtonPressed => Self::Class,
+            Message::BackgroundButtonPressed => Self::Background,
+            Message::AbilitiesButtonPressed => Self::Abilities,
+            Message::FeaturesButtonPressed => Self::Features,
+            Message::SpellsButtonPressed => Self::Spells,
+            Message::HitPointsButtonPressed => Self::HitPoints,
+            Message::ProficienciesButtonPressed => Self::Proficiencies,
+            Message::EquipmentButtonPressed => Self::Equipment,
+            Message::InventoryButtonPressed => Self::Inventory,
+            Message::WealthButtonPressed => Self::Wealth,
+            Message::AttacksButtonPressed => Self::Attacks,
+            Message::SkillsButtonPressed => Self::Skills,
+            Message::DiceButtonPressed => Self::Dice,
+            _ => unreachable!("Not a menu button message"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NewCharacterPage {
+    /// Represents the two panes (menu pane and info pane).
+    panes: pane_grid::State<Pane>,
+
+    /// Currently selected menu option.
+    menu_option: MenuOpts,
+
+    /// The selections made for the character.
+    draft: Character,
+
+    /// The result of the last save, if any.
+    status: Option<String>,
+
+    /// The content the character is built from.
+    content: Arc<ContentRegistry>,
+
+    /// Rolls every die on this page.
+    roller: Roller,
+
+    /// The expression typed into the dice roller.
+    dice_expression: String,
+
+    /// The rolls made with the dice roller, oldest first.
+    dice_rolls: Vec<RollResult>,
+
+    /// The amount typed in to receive or pay coins.
+    coin_amount: String,
+
+    /// The coin picked to receive or pay.
+    coin: Coin,
+
+    /// Why the last payment or exchange failed, if it did.
+    wealth_status: Option<String>,
+}
+
+impl NewCharacterPage {
+    const SPLIT_RATIO: f32 = 0.2;
+
+    pub fn new(draft: Character, content: Arc<ContentRegistry>) -> Self {
+        let (mut panes, pane) = pane_grid::State::new(Pane::Menu);
+        let split = panes.split(pane_grid::Axis::Vertical, pane, Pane::Info);
+        panes.resize(split.expect("Invalid split").1, Self::SPLIT_RATIO);
+        Self {
+            panes,
+            menu_option: MenuOpts::Race,
+            draft,
+            status: None,
+            content,
+            roller: Roller::new(),
+            dice_expression: String::new(),
+            dice_rolls: vec![],
+            coin_amount: String::new(),
+            coin: Coin::Gp,
+            wealth_status: None,
+        }
+    }
+
+    /// The selections made for the character.
+    pub fn draft(&self) -> &Character {
+        &self.draft
+    }
+
+    /// Sets the status text displayed under the `Save` button.
+    pub fn set_status(&mut self, status: impl Into<String>) {
+        self.status = Some(status.into());
+    }
+
+    pub fn update(&mut self, message: Message) -> Command {
+        match message {
+            Message::RaceButtonPressed => {
+                self.menu_option = MenuOpts::Race;
+                Command::None
+            }
+            Message::ClassButtonPressed => {
+                self.menu_option = MenuOpts::Class;
+                Command::None
+            }
+            Message::BackgroundButtonPressed => {
+                self.menu_option = MenuOpts::Background;
+                Command::None
+            }
+            Message::AbilitiesButtonPressed => {
+                self.menu_option = MenuOpts::Abilities;
+                Command::None
+            }
+            Message::FeaturesButtonPressed => {
+                self.menu_option = MenuOpts::Features;
+                Command::None
+            }
+            Message::SpellsButtonPressed => {
+                self.menu_option = MenuOpts::Spells;
+                Command::None
+            }
+            Message::HitPointsButtonPressed => {
+                self.menu_option = MenuOpts::HitPoints;
+                Command::None
+            }
+            Message::ProficienciesButtonPressed => {
+                self.menu_option = MenuOpts::Proficiencies;
+                Command::None
+            }
+            Message::EquipmentButtonPressed => {
+                self.menu_option = MenuOpts::Equipment;
+                Command::None
+            }
+            Message::InventoryButtonPressed => {
+                self.menu_option = MenuOpts::Inventory;
+                Command::None
+            }
+            Message::WealthButtonPressed => {
+                self.menu_option = MenuOpts::Wealth;
+                Command::None
+            }
+            Message::AttacksButtonPressed => {
+                self.menu_option = MenuOpts::Attacks;
+                Command::None
+            }
+            Message::SkillsButtonPressed => {
+                self.menu_option = MenuOpts::Skills;
+                Command::None
+            }
+            Message::DiceButtonPressed => {
+                self.menu_option = MenuOpts::Dice;
+                Command::None
+            }
+            Message::RaceSelected(race) => {
+                if self.draft.race.as_ref() != Some(&race.id) {
+                    self.draft.subrace = None;
+                }
+                self.draft.race = Some(race.id);
+                Command::None
+            }
+            Message::SubraceSelected(subrace) => {
+                self.draft.subrace = Some(subrace.id);
+                Command::None
+            }
+            Message::ClassSelected(class) => {
+                if self.draft.multiclasses.iter().any(|m| m.class == class.id) {
+                    self.set_status(format!(
+                        "The character already has levels in {} from multiclassing.",
+                        class.name
+                    ));
+                    return Command::None;
+                }
+                if self.draft.class.as_ref() != Some(&class.id) {
+                    self.draft.subclass = None;
+                }
+                self.draft.class = Some(class.id);
+                Command::None
+            }
+            Message::SubclassSelected(subclass) => {
+                self.draft.subclass = Some(subclass.id);
+                Command::None
+            }
+            Message::LevelSelected(level) => {
+                self.draft.set_level(level);
+                Command::None
+            }
+            Message::BackgroundSelected(background) => {
+                if self.draft.background.as_ref() != Some(&background.id) {
+                    self.draft.personality = Personality::default();
+                }
+                self.draft.background = Some(background.id);
+                Command::None
+            }
+            Message::PersonalitySelected(table, entry) => {
+                self.draft.personality.select(table, entry);
+                Command::None
+            }
+            Message::PersonalityRollButtonPressed(table) => {
+                self.draft
+                    .roll_personality(&self.content, table, &mut self.roller);
+                Command::None
+            }
+            Message::NameChanged(name) => {
+                self.draft.name = name;
+                Command::None
+            }
+            Message::SaveButtonPressed => Command::Save(Box::new(self.draft.clone())),
+            Message::AbilityMethodSelected(method) => {
+                self.draft.abilities.set_method(method);
+                Command::None
+            }
+            Message::AbilityAssigned(ability, value) => {
+                self.draft.abilities.assign(ability, value.index);
+                Command::None
+            }
+            Message::AbilityIncreased(ability) => {
+                self.draft.abilities.increase(ability);
+                Command::None
+            }
+            Message::AbilityDecreased(ability) => {
+                self.draft.abilities.decrease(ability);
+                Command::None
+            }
+            Message::AbilityRollButtonPressed => {
+                self.draft.abilities.roll(&mut self.roller);
+                Command::None
+            }
+            Message::FeatureOptionToggled(feature, option) => {
+                self.draft
+                    .toggle_feature_option(&self.content, &feature, &option);
+                Command::None
+            }
+            Message::ImprovementChanged(level, ability, increase) => {
+                self.draft
+                    .change_improvement(&self.content, level, ability, increase);
+                Command::None
+            }
+            Message::FeatSelected(level, feat) => {
+                self.draft.choose_feat(&self.content, level, &feat.id);
+                Command::None
+            }
+            Message::FeatAbilitySelected(level, ability) => {
+                self.draft
+                    .choose_feat_ability(&self.content, level, ability);
+                Command::None
+            }
+            Message::FeatCleared(level) => {
+                self.draft.clear_feat(level);
+                Command::None
+            }
+            Message::FeatsToggled(feats) => {
+                self.draft.campaign.feats = feats;
+                Command::None
+            }
+            Message::RacialIncreaseToggled(ability) => {
+                self.draft.toggle_racial_increase(&self.content, ability);
+                Command::None
+            }
+            Message::SpellToggled(class, list, spell) => {
+                self.draft.toggle_spell(&self.content, &class, list, &spell);
+                Command::None
+            }
+            Message::SpellSlotExpended(level) => {
+                self.draft.expend_slot(&self.content, level);
+                Command::None
+            }
+            Message::PactSlotExpended => {
+                self.draft.expend_pact_slot(&self.content);
+                Command::None
+            }
+            Message::ShortRestButtonPressed => {
+                self.draft.short_rest();
+                Command::None
+            }
+            Message::LongRestButtonPressed => {
+                self.draft.long_rest();
+                Command::None
+            }
+            Message::HitPointsAverageSelected(level) => {
+                self.draft.set_hit_point_gain(level, HitPointGain::Average);
+                Command::None
+            }
+            Message::HitPointsRollButtonPressed(level) => {
+                self.draft
+                    .roll_hit_points(&self.content, level, &mut self.roller);
+                Command::None
+            }
+            Message::CurrentHitPointsChanged(hit_points) => {
+                self.draft.set_current_hit_points(&self.content, hit_points);
+                Command::None
+            }
+            Message::ProficiencyChosen(key, selected) => {
+                utils::select(&mut self.draft.proficiency_selections, key, selected);
+                Command::None
+            }
+            Message::StartingWealthSelected(gold) => {
+                self.draft.set_starting_wealth(&self.content, gold);
+                Command::None
+            }
+            Message::StartingGoldRollButtonPressed => {
+                self.draft
+                    .roll_starting_gold(&self.content, &mut self.roller);
+                Command::None
+            }
+            Message::EquipmentAlternativeChosen(key, index) => {
+                self.draft.equipment.choose(&key, index);
+                Command::None
+            }
+            Message::EquipmentItemPicked(key, item) => {
+                self.draft.equipment.items.insert(key, item.id);
+                Command::None
+            }
+            Message::EncumbranceRuleSelected(rule) => {
+                self.draft.campaign.encumbrance = rule;
+                Command::None
+            }
+            Message::InventoryResetButtonPressed => {
+                self.draft.inventory = None;
+                Command::None
+            }
+            Message::InventoryItemAdded(item) => {
+                self.draft
+                    .inventory_mut(&self.content)
+                    .add(&item.id, 1, None);
+                Command::None
+            }
+            Message::InventoryQuantityChanged(id, quantity) => {
+                self.draft
+                    .inventory_mut(&self.content)
+                    .set_quantity(id, quantity);
+                Command::None
+            }
+            Message::InventoryItemRemoved(id) => {
+                self.draft.inventory_mut(&self.content).remove(id);
+                Command::None
+            }
+            Message::InventoryItemEquipped(id, equipped) => {
+                if let Some(entry) = self.draft.inventory_mut(&self.content).entry_mut(id) {
+                    entry.equipped = equipped;
+                }
+                Command::None
+            }
+            Message::InventoryItemAttuned(id, attuned) => {
+                if let Some(entry) = self.draft.inventory_mut(&self.content).entry_mut(id) {
+                    entry.attuned = attuned;
+                }
+                Command::None
+            }
+            Message::InventoryItemMoved(id, container) => {
+                self.draft
+                    .inventory_mut(&self.content)
+                    .move_to(id, container);
+                Command::None
+            }
+            Message::CoinAmountChanged(amount) => {
+                self.coin_amount = amount;
+                Command::None
+            }
+            Message::CoinSelected(coin) => {
+                self.coin = coin;
+                Command::None
+            }
+            Message::CoinsReceiveButtonPressed => {
+                if let Ok(amount) = self.coin_amount.trim().parse() {
+                    (self.draft.wealth_mut(&self.content)).receive(self.coin, amount, "Received");
+                    self.wealth_status = None;
+                }
+                Command::None
+            }
+            Message::CoinsPayButtonPressed => {
+                let price = (self.coin_amount.trim().parse::<u32>().ok())
+                    .map(|amount| amount as u64 * self.coin.value() as u64);
+                if let Some(price) = price {
+                    self.wealth_status = (self.draft.wealth_mut(&self.content))
+                        .pay(price, "Paid")
+                        .err()
+                        .map(|error| error.to_string());
+                }
+                Command::None
+            }
+            Message::CoinBreakButtonPressed(coin) => {
+                if let Some(smaller) = currency::smaller(coin) {
+                    let purse = &mut self.draft.wealth_mut(&self.content).purse;
+                    self.wealth_status =
+                        (purse.exchange(coin, smaller, 1).err()).map(|error| error.to_string());
+                }
+                Command::None
+            }
+            Message::CoinsConsolidateButtonPressed => {
+                self.draft.wealth_mut(&self.content).purse.consolidate();
+                Command::None
+            }
+            Message::ItemBought(item) => {
+                self.wealth_status = (self.draft.buy(&self.content, &item.id).err())
+                    .map(|error| format!("{}: {error}", item.name));
+                Command::None
+            }
+            Message::WealthResetButtonPressed => {
+                self.draft.wealth = None;
+                self.wealth_status = None;
+                Command::None
+            }
+            Message::ArmorWorn(armor) => {
+                self.draft.worn.armor = armor;
+                Command::None
+            }
+            Message::ShieldWielded(shield) => {
+                self.draft.worn.shield = shield;
+                Command::None
+            }
+            Message::MageArmorToggled(active) => {
+                self.draft.worn.mage_armor = active;
+                Command::None
+            }
+            Message::WeaponWielded(item) => {
+                self.draft.wield(&self.content, &item);
+                Command::None
+            }
+            Message::WeaponDropped(i) => {
+                if i < self.draft.wielded.len() {
+                    self.draft.wielded.remove(i);
+                }
+                Command::None
+            }
+            Message::WeaponTwoHandedToggled(i, two_handed) => {
+                if let Some(weapon) = self.draft.wielded.get_mut(i) {
+                    weapon.two_handed = two_handed;
+                }
+                Command::None
+            }
+            Message::WeaponOffHandToggled(i, off_hand) => {
+                if let Some(weapon) = self.draft.wielded.get_mut(i) {
+                    weapon.off_hand = off_hand;
+                }
+                Command::None
+            }
+            Message::WeaponMagicBonusChanged(i, bonus) => {
+                if let Some(weapon) = self.draft.wielded.get_mut(i) {
+                    weapon.magic_bonus = bonus;
+                }
+                Command::None
+            }
+            Message::WeaponFinesseSelected(i, ability) => {
+                if let Some(weapon) = self.draft.wielded.get_mut(i) {
+                    weapon.finesse = Some(ability);
+                }
+                Command::None
+            }
+            Message::AttackRollButtonPressed(expression) => {
+                if let Ok(roll) = self.roller.roll_str(&expression) {
+                    self.dice_rolls.push(roll);
+                }
+                Command::None
+            }
+            Message::ProficiencyReplaced(slot, replacement) => {
+                self.draft
+                    .proficiency_replacements
+                    .insert(slot, replacement);
+                Command::None
+            }
+            Message::ExpertiseToggled(proficiency) => {
+                self.draft.toggle_expertise(&self.content, proficiency);
+                Command::None
+            }
+            Message::DiceExpressionChanged(expression) => {
+                self.dice_expression = expression;
+                Command::None
+            }
+            Message::DiceRollButtonPressed => {
+                if let Ok(expression) = self.dice_expression.trim().parse() {
+                    self.dice_rolls.push(self.roller.roll(&expression));
+                }
+                Command::None
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let pane_grid = PaneGrid::new(&self.panes, |_pane, pane_state, _is_maximized| {
+            pane_grid::Content::new(match pane_state {
+                // The navigation menu pane
+                Pane::Menu => column![
+                    self.name_input(),
+                    self.menu_pane_button("Race", Message::RaceButtonPressed),
+                    self.menu_pane_button("Class", Message::ClassButtonPressed),
+                    self.menu_pane_button("Background", Message::BackgroundButtonPressed),
+                    self.menu_pane_button("Abilities", Message::AbilitiesButtonPressed),
+                    self.menu_pane_button("Features", Message::FeaturesButtonPressed),
+                    self.menu_pane_button("Spells", Message::SpellsButtonPressed),
+                    self.menu_pane_button("Hit Points", Message::HitPointsButtonPressed),
+                    self.menu_pane_button("Proficiencies", Message::ProficienciesButtonPressed),
+                    self.menu_pane_button("Equipment", Message::EquipmentButtonPressed),
+                    self.menu_pane_button("Inventory", Message::InventoryButtonPressed),
+                    self.menu_pane_button("Wealth", Message::WealthButtonPressed),
+                    self.menu_pane_button("Attacks", Message::AttacksButtonPressed),
+                    self.menu_pane_button("Skills", Message::SkillsButtonPressed),
+                    self.menu_pane_button("Dice", Message::DiceButtonPressed),
+                    self.save_button(),
+                ],
+
+                // The content pane
+                Pane::Info => column![self.view_info_pane()],
+            })
+            .style(styles::panes)
+        });
+        pane_grid.into()
+    }
+}
+
+impl NewCharacterPage {
+    /// Creates a button in the menu pane.
+    fn menu_pane_button<'a>(&'a self, name: &'a str, on_press: Message) -> Element<'a, Message> {
+        let style = if self.menu_option == on_press.clone().into() {
+            styles::selected_menu_button
+        } else {
+            styles::menu_button
+        };
+
+        container(container(
+            button(Text::new(name).width(Length::Fill).center())
+                .style(style)
+                .on_press(on_press)
+                .padding(10)
+                .width(Length::Fill),
+        ))
+        .padding(5.0)
+        .center_x(Length::Fill)
+        .into()
+    }
+
+    /// Creates the input for the character's name.
+    fn name_input(&self) -> Element<'_, Message> {
+        container(
+            text_input("Character name", &self.draft.name)
+                .on_input(Message::NameChanged)
+                .padding(10),
+        )
+        .padding(5.0)
+        .into()
+    }
+
+    /// Creates the button that saves the character, along with the status of the last save.
+    fn save_button(&self) -> Element<'_, Message> {
+        let save = button(Text::new("Save").width(Length::Fill).center())
+            .style(styles::menu_button)
+            .padding(10)
+            .width(Length::Fill)
+            .on_press_maybe(
+                (!self.draft.name.trim().is_empty()).then_some(Message::SaveButtonPressed),
+            );
+
+        let mut content = column![save];
+        if let Some(status) = &self.status {
+            content = content.push(Text::new(status));
+        }
+
+        let missing = self.draft.missing(&self.content);
+        if !missing.is_empty() {
+            content = content.push(Text::new("Incomplete:").font(utils_styles::bold_font()));
+            for item in missing {
+                content = content.push(Text::new(format!("• {item}")).style(text::secondary));
+            }
+        }
+
+        container(content)
+            .padding(5.0)
+            .center_x(Length::Fill)
+            .into()
+    }
+
+    /// Displays the info pane.
+    fn view_info_pane(&self) -> Element<'_, Message> {
+        match self.menu_option {
+            MenuOpts::Race => column![self.races_list(), self.race_info()].into(),
+            MenuOpts::Class => column![self.classes_list(), self.class_info()].into(),
+            MenuOpts::Background => column![self.backgrounds_list(), self.background_info()].into(),
+            MenuOpts::Abilities => scrollable(self.abilities_info()).into(),
+            MenuOpts::Features => scrollable(self.features_info()).into(),
+            MenuOpts::Spells => scrollable(self.spells_info()).into(),
+            MenuOpts::HitPoints => scrollable(self.hit_points_info()).into(),
+            MenuOpts::Proficiencies => scrollable(self.proficiencies_info()).into(),
+            MenuOpts::Equipment => scrollable(self.equipment_info()).into(),
+            MenuOpts::Inventory => scrollable(self.inventory_info()).into(),
+            MenuOpts::Wealth => scrollable(self.wealth_info()).into(),
+            MenuOpts::Attacks => scrollable(self.attacks_info()).into(),
+            MenuOpts::Skills => scrollable(self.skills_info()).into(),
+            MenuOpts::Dice => scrollable(self.dice_info()).into(),
+        }
+    }
+
+    /// Creates a dropdown list of races.
+    fn races_list(&self) -> Element<'_, Message> {
+        let races = self
+            .content
+            .races()
+            .map(|race| ContentRef {
+                id: race.id.clone(),
+                name: race.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        let selected = races
+            .iter()
+            .find(|race| self.draft.race.as_ref() == Some(&race.id))
+            .cloned();
+        let races = pick_list(races, selected, Message::RaceSelected)
+            .style(styles::dropdown)
+            .menu_style(styles::dropdown_item)
+            .placeholder("Select your race:");
+
+        container(scrollable(column![races]))
+            .padding(5)
+            .center_x(Length::Fill)
+            .into()
+    }
+
+    /// Displays the race info.
+    fn race_info(&self) -> Element<'_, Message> {
+        let Some(race) = &self.draft.race else {
+            return container(column![]).into();
+        };
+
+        match self.content.race(race) {
+            Ok(race) => {
+                let selected_subrace = self
+                    .draft
+                    .subrace
+                    .as_ref()
+                    .and_then(|subrace| self.content.subrace(subrace).ok());
+                container(race.clone().view(
+                    &|subrace| Message::SubraceSelected(subrace),
+                    selected_subrace,
+                    &self.draft.proficiency_selections,
+                    &Message::ProficiencyChosen,
+                ))
+                .into()
+            }
+            Err(err) => container(Text::new(err.to_string()).style(text::danger))
+                .padding(5)
+                .into(),
+        }
+    }
+
+    /// Creates a dropdown list of classes.
+    fn classes_list(&self) -> Element<'_, Message> {
+        let classes = self
+            .content
+            .classes()
+            .map(|class| ContentRef {
+                id: class.id.clone(),
+                name: class.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        let selected = classes
+            .iter()
+            .find(|class| self.draft.class.as_ref() == Some(&class.id))
+            .cloned();
+        let classes = pick_list(classes, selected, Message::ClassSelected)
+            .style(styles::dropdown)
+            .menu_style(styles::dropdown_item)
+            .placeholder("Select your class:");
+        let levels = pick_list(
+            (1..=MAX_LEVEL).collect::<Vec<_>>(),
+            Some(self.draft.level),
+            Message::LevelSelected,
+        )
+        .style(styles::dropdown)
+        .menu_style(styles::dropdown_item);
+
+        container(scrollable(
+            row![classes, Text::new("Level:"), levels]
+                .spacing(10)
+                .align_y(Alignment::Center),
+        ))
+        .padding(5)
+        .center_x(Length::Fill)
+        .into()
+    }
+
+    /// Displays the class info.
+    fn class_info(&self) -> Element<'_, Message> {
+        let Some(class) = &self.draft.class else {
+            return container(column![]).into();
+        };
+
+        match self.content.class(class) {
+            Ok(class) => {
+                let selected_subclass = self
+                    .draft
+                    .subclass
+                    .as_ref()
+                    .and_then(|subclass| self.content.subclass(subclass).ok());
+                container(class.clone().view(
+                    self.draft.class_level(&class.id),
+                    &|subclass| Message::SubclassSelected(subclass),
+                    selected_subclass,
+                    &self.draft.proficiency_selections,
+                    &Message::ProficiencyChosen,
+                ))
+                .into()
+            }
+            Err(err) => container(Text::new(err.to_string()).style(text::danger))
+                .padding(5)
+                .into(),
+        }
+    }
+
+    /// Creates a dropdown list of backgrounds.
+    fn backgrounds_list(&self) -> Element<'_, Message> {
+        let backgrounds = self
+            .content
+            .backgrounds()
+            .map(|background| ContentRef {
+                id: background.id.clone(),
+                name: background.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        let selected = backgrounds
+            .iter()
+            .find(|background| self.draft.background.as_ref() == Some(&background.id))
+            .cloned();
+        let backgrounds = pick_list(backgrounds, selected, Message::BackgroundSelected)
+            .style(styles::dropdown)
+            .menu_style(styles::dropdown_item)
+            .placeholder("Select your background:");
+
+        container(scrollable(column![backgrounds]))
+            .padding(5)
+            .center_x(Length::Fill)
+            .into()
+    }
+
+    /// Displays the background info.
+    fn background_info(&self) -> Element<'_, Message> {
+        let Some(background) = &self.draft.background else {
+            return container(column![]).into();
+        };
+
+        match self.content.background(background) {
+            Ok(background) => container(background.clone().view(
+                &self.draft.proficiency_selections,
+                &Message::ProficiencyChosen,
+                &self.draft.personality,
+                &Message::PersonalitySelected,
+                &Message::PersonalityRollButtonPressed,
+                |id| self.content.name_of(ContentKind::Item, id),
+            ))
+            .into(),
+            Err(err) => container(Text::new(err.to_string()).style(text::danger))
+                .padding(5)
+                .into(),
+        }
+    }
+}
+
+mod styles {
+    use iced::{
+        overlay,
+        theme::palette,
+        widget::{
+            button::{self, Status},
+            container, pick_list,
+        },
+        Background, Border, Color, Theme,
+    };
+
+    /// Style for the menu and info panes.
+    pub fn panes(theme: &Theme) -> container::Style {
+        let palette = theme.extended_palette();
+
+        container::Style {
+            background: Some(Background::Color(palette.background.base.color)),
+            border: Border {
+                color: Color::from_rgb8(0, 0, 0),
+                width: 2.0,
+                ..Border::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn dropdown(theme: &Theme, status: pick_list::Status) -> pick_list::Style {
+        let palette = theme.extended_palette();
+
+        match status {
+            pick_list::Status::Active | pick_list::Status::Opened => pick_list::Style {
+                border: Border {
+                    radius: 3.0.into(),
+                    ..Default::default()
+                },
+                text_color: palette.background.base.text,
+                placeholder_color: palette.background.weak.text,
+                handle_color: palette.primary.base.color,
+                background: Background::Color(palette.background.weak.color),
+            },
+            pick_list::Status::Hovered => pick_list::Style {
+                border: Border {
+                    radius: 3.0.into(),
+                    ..Default::default()
+                },
+                text_color: palette.background.base.text,
+                placeholder_color: palette.background.weak.text,
+                handle_color: palette.primary.base.color,
+                background: Background::Color(palette.primary.weak.color),
+            },
+        }
+    }
+
+    pub fn dropdown_item(theme: &Theme) -> overlay::menu::Style {
+        let palette = theme.extended_palette();
+
+        overlay::menu::Style {
+            background: Background::Color(palette.background.weak.color),
+            border: Border {
+                radius: 1.5.into(),
+                ..Default::default()
+            },
+            text_color: palette.background.base.text,
+            selected_text_color: palette.background.strong.text,
+            selected_background: Background::Color(palette.primary.weak.color),
+        }
+    }
+
+    /// Style for the menu buttons.
+    pub fn menu_button(theme: &Theme, status: Status) -> button::Style {
+        let palette = theme.extended_palette();
+        let base = base_button(palette.primary.strong);
+
+        match status {
+            Status::Active | Status::Pressed => base,
+            Status::Hovered => button::Style {
+                background: Some(Background::Color(palette.success.strong.color)),
+                ..base
+            },
+            Status::Disabled => disabled_button(base),
+        }
+    }
+
+    /// Style for the currently selected menu button.
+    pub fn selected_menu_button(theme: &Theme, _: Status) -> button::Style {
+        let palette = theme.extended_palette();
+        let base = base_button(palette.primary.strong);
+        button::Style {
+            background: Some(Background::Color(palette.success.strong.color)),
+            ..base
+        }
+    }
+
+    /// The base style shared by all buttons.
+    fn base_button(pair: palette::Pair) -> button::Style {
+        button::Style {
+            background: Some(Background::Color(pair.color)),
+            text_color: pair.text,
+            border: Border {
+                color: Color::from_rgb8(0, 0, 0),
+                width: 0.5,
+                ..Border::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// The styling for disabled buttons.
+    fn disabled_button(style: button::Style) -> button::Style {
+        const ALPHA_SCALE_FACTOR: f32 = 0.5;
+        button::Style {
+            background: style
+                .background
+                .map(|bg| bg.scale_alpha(ALPHA_SCALE_FACTOR)),
+            text_color: style.text_color.scale_alpha(ALPHA_SCALE_FACTOR),
+            ..style
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use iced::{
+    widget::{column, container, pick_list, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::proficiencies,
+    frontend::utils::{proficiency::Proficiency, styles as utils_styles},
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    /// Displays the proficiencies granted to the character, and lets duplicate grants be
+    /// replaced.
+    pub(super) fn proficiencies_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Proficiencies").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let grants = self.draft.proficiency_grants(&self.content);
+        let duplicates = proficiencies::duplicates(&grants);
+        let current = self.draft.proficiencies(&self.content);
+
+        let mut sources = column![Text::new("Granted: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        if grants.is_empty() {
+            sources = sources.push(
+                container(Text::new(
+                    "Select a race and class to see their proficiencies.",
+                ))
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        let mut names = grants.iter().map(|g| &g.source).collect::<Vec<_>>();
+        names.dedup();
+        for source in names {
+            let items = grants
+                .iter()
+                .filter(|g| &g.source == source)
+                .map(|g| g.proficiency.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            sources = sources.push(
+                container(row![
+                    Text::new(format!("{}: ", source)).font(utils_styles::bold_font()),
+                    Text::new(items),
+                ])
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+
+        for choice in self.draft.unresolved_choices(&self.content) {
+            sources = sources.push(
+                container(
+                    Text::new(format!(
+                        "{}: {}",
+                        choice.source,
+                        choice.choices.text("Choose")
+                    ))
+                    .style(text::secondary),
+                )
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+
+        let duplicates = if !duplicates.is_empty() {
+            let mut content = column![Text::new("Duplicates: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE)];
+
+            for duplicate in duplicates {
+                content = content.push(
+                    container(Text::new(format!(
+                        "{} is granted by {}.",
+                        duplicate.proficiency,
+                        duplicate.sources.join(" and ")
+                    )))
+                    .padding(utils_styles::indented_padding()),
+                );
+
+                if !duplicate.replaceable() {
+                    content = content.push(
+                        container(
+                            Text::new(format!(
+                                "Only duplicate skill and tool proficiencies can be replaced, so \
+                                 the extra {} proficiency is lost.",
+                                duplicate.proficiency.kind()
+                            ))
+                            .style(text::secondary),
+                        )
+                        .padding(utils_styles::indented_padding()),
+                    );
+                    continue;
+                }
+
+                for slot in duplicate.slots() {
+                    let selected = self.draft.proficiency_replacements.get(&slot).cloned();
+                    let options = Proficiency::replacements(duplicate.proficiency.kind())
+                        .into_iter()
+                        .filter(|p| !current.contains(p) || Some(p) == selected.as_ref())
+                        .collect::<Vec<_>>();
+                    let picker = pick_list(options, selected, move |replacement| {
+                        Message::ProficiencyReplaced(slot.clone(), replacement)
+                    })
+                    .style(styles::dropdown)
+                    .menu_style(styles::dropdown_item)
+                    .placeholder("Pick a replacement");
+
+                    content = content.push(
+                        container(
+                            row![Text::new("Replacement: "), picker].align_y(Alignment::Center),
+                        )
+                        .padding(utils_styles::indented_padding()),
+                    );
+                }
+            }
+
+            container(content).padding(utils_styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        let total = if !current.is_empty() {
+            let items = current
+                .iter()
+                .map(|p| p.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            container(column![
+                Text::new("All Proficiencies: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(Text::new(items)).padding(utils_styles::indented_padding()),
+            ])
+            .padding(utils_styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        let languages = self.draft.languages(&self.content);
+        let languages = if !languages.is_empty() {
+            container(column![
+                Text::new("Languages: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(Text::new(languages.join(", ")))
+                    .padding(utils_styles::indented_padding()),
+            ])
+            .padding(utils_styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        container(column![
+            title,
+            container(sources).padding(utils_styles::BASE_PADDING),
+            duplicates,
+            total,
+            languages,
+        ])
+        .into()
+    }
+}
@@ -0,0 +1,220 @@
+use iced::{
+    widget::{checkbox, column, container, row, text, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::skills::{Training, PASSIVE_SKILLS},
+    frontend::utils::styles as utils_styles,
+};
+
+use super::{Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    const SKILL_COLUMN_WIDTH: f32 = 160.0;
+    const MODIFIER_COLUMN_WIDTH: f32 = 60.0;
+
+    /// Displays the modifiers of the character's saving throws and skills, and lets expertise be
+    /// picked.
+    pub(super) fn skills_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Skills").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let Some(checks) = self.draft.checks(&self.content) else {
+            return container(column![
+                title,
+                container(Text::new(
+                    "Assign every ability score to see the skill and saving throw modifiers."
+                ))
+                .padding(utils_styles::BASE_PADDING),
+            ])
+            .into();
+        };
+
+        let bonus = container(row![
+            Text::new("Proficiency Bonus: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE),
+            Text::new(format!("{:+}", checks.proficiency_bonus))
+                .size(utils_styles::SECTION_FONT_SIZE),
+        ])
+        .padding(utils_styles::BASE_PADDING);
+
+        let mut saves = column![Text::new("Saving Throws: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        for save in checks.saving_throws() {
+            let training = if save.proficient {
+                Training::Proficient
+            } else {
+                Training::Untrained
+            };
+            saves = saves.push(
+                container(Self::check_row(
+                    training,
+                    save.ability.to_string(),
+                    save.modifier,
+                    &[],
+                ))
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        let advantages = self.draft.save_advantages(&self.content);
+        if !advantages.is_empty() {
+            saves = saves.push(
+                container(Text::new(format!(
+                    "Advantage on saving throws against {}",
+                    advantages.join(", ")
+                )))
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        let resistances = self.draft.resistances(&self.content);
+        if !resistances.is_empty() {
+            let resistances = resistances
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>();
+            saves = saves.push(
+                container(Text::new(format!(
+                    "Resistant to {} damage",
+                    resistances.join(", ").to_lowercase()
+                )))
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+
+        let mut skills = column![Text::new("Skills: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        for check in checks.skills() {
+            skills = skills.push(
+                container(Self::check_row(
+                    check.training,
+                    format!("{} ({})", check.skill, check.skill.ability().abbreviation()),
+                    check.modifier,
+                    &check.notes,
+                ))
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+        skills = skills.push(
+            container(
+                Text::new("● proficient, ◆ expertise")
+                    .style(text::secondary)
+                    .size(12),
+            )
+            .padding(utils_styles::indented_padding()),
+        );
+
+        let mut passives = column![Text::new("Passive Scores: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+        for skill in PASSIVE_SKILLS {
+            passives = passives.push(
+                container(row![
+                    Text::new(format!("Passive {}", skill)).width(Self::SKILL_COLUMN_WIDTH),
+                    Text::new(checks.passive(skill).to_string()),
+                ])
+                .padding(utils_styles::indented_padding()),
+            );
+        }
+
+        let senses = self.draft.senses(&self.content);
+        let senses = if !senses.is_empty() {
+            let senses = senses
+                .iter()
+                .map(|sense| sense.to_string())
+                .collect::<Vec<_>>();
+            container(column![
+                Text::new("Senses: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(Text::new(senses.join(", "))).padding(utils_styles::indented_padding()),
+            ])
+            .padding(utils_styles::BASE_PADDING)
+        } else {
+            container(column![])
+        };
+
+        container(column![
+            title,
+            bonus,
+            container(saves).padding(utils_styles::BASE_PADDING),
+            container(skills).padding(utils_styles::BASE_PADDING),
+            container(passives).padding(utils_styles::BASE_PADDING),
+            senses,
+            self.expertise_picker(),
+        ])
+        .into()
+    }
+
+    /// Creates the checkboxes used to pick the proficiencies the character has expertise in.
+    fn expertise_picker(&self) -> Element<'_, Message> {
+        let slots = self.draft.expertise_slots(&self.content);
+        if slots == 0 {
+            return container(column![]).into();
+        }
+
+        let picked = self.draft.expertise(&self.content);
+        let mut content = column![
+            Text::new("Expertise: ")
+                .font(utils_styles::bold_font())
+                .size(utils_styles::SECTION_FONT_SIZE),
+            container(
+                Text::new(format!(
+                    "Choose {} of your proficiencies to double your proficiency bonus for. \
+                     ({} selected)",
+                    slots,
+                    picked.len()
+                ))
+                .style(text::secondary)
+            )
+            .padding(utils_styles::indented_padding()),
+        ];
+        for option in self.draft.expertise_options(&self.content) {
+            let checked = picked.contains(&option);
+            let toggle = (checked || picked.len() < slots).then(|| {
+                let option = option.clone();
+                move |_| Message::ExpertiseToggled(option.clone())
+            });
+            content = content.push(
+                container(checkbox(option.to_string(), checked).on_toggle_maybe(toggle))
+                    .padding(utils_styles::radio_padding()),
+            );
+        }
+        container(content)
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Creates a row showing the training, name, and modifier of a skill or saving throw, followed
+    /// by any situational notes.
+    fn check_row<'a>(
+        training: Training,
+        name: String,
+        modifier: i8,
+        notes: &[String],
+    ) -> Element<'a, Message> {
+        let marker = match training {
+            Training::Untrained => "○",
+            Training::Proficient => "●",
+            Training::Expertise => "◆",
+        };
+        let mut content = row![
+            Text::new(marker).width(20),
+            Text::new(name).width(Self::SKILL_COLUMN_WIDTH),
+            Text::new(format!("{:+}", modifier)).width(Self::MODIFIER_COLUMN_WIDTH),
+        ]
+        .align_y(Alignment::Center);
+        for note in notes {
+            content = content.push(Text::new(note.clone()).style(text::secondary));
+        }
+        content.into()
+    }
+}
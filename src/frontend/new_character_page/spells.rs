@@ -0,0 +1,126 @@
+use iced::{
+    widget::{button, column, container, row, Text},
+    Alignment, Element, Length,
+};
+
+use crate::frontend::{advancement, class::ordinal, utils::styles as utils_styles};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    /// Displays the spellcasting of each of the character's spellcasting classes, with the
+    /// cantrips and spells to pick for it.
+    pub(super) fn spells_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Spells").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        let spellcasting = self.draft.spellcasting(&self.content);
+        if spellcasting.is_empty() {
+            return container(column![
+                title,
+                container(Text::new(
+                    "None of the character's classes can cast spells at this level."
+                ))
+                .padding(utils_styles::BASE_PADDING),
+            ])
+            .into();
+        }
+
+        let scores = self.draft.final_ability_scores(&self.content);
+        let mut content = column![
+            title,
+            container(column![
+                Text::new("Spell Slots: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                advancement::spell_slots_view(&self.draft.spell_slots(&self.content)),
+                self.expended_slots_view(),
+            ])
+            .padding(utils_styles::BASE_PADDING),
+        ];
+        for casting in spellcasting {
+            let class = &casting.class.id;
+            let mut section = column![
+                Text::new(format!("{}: ", casting.class.name))
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                advancement::spellcasting_view(
+                    &casting,
+                    scores.as_ref(),
+                    self.draft.proficiency_bonus()
+                ),
+            ];
+            for list in casting.lists() {
+                section = section.push(advancement::spell_list_view(
+                    &casting,
+                    list,
+                    &self.draft.spell_options(&self.content, class, list),
+                    &self.draft.spell_selection(&self.content, class, list),
+                    self.draft.spell_count(&self.content, class, list),
+                    &[],
+                    &Message::SpellToggled,
+                ));
+            }
+            content = content.push(container(section).padding(utils_styles::BASE_PADDING));
+        }
+        content.into()
+    }
+
+    /// Displays the spell slots left until the next rest, with buttons to expend them and to
+    /// take a short or long rest.
+    fn expended_slots_view(&self) -> Element<'_, Message> {
+        let slots = self.draft.spell_slots(&self.content);
+        let remaining = self.draft.remaining_slots(&self.content);
+        let mut content = column![].spacing(utils_styles::COLUMN_SPACING);
+        for (i, (&count, &left)) in slots.slots.iter().zip(&remaining.slots).enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let level = i as u8 + 1;
+            content = content.push(
+                row![
+                    Text::new(format!("{} level: {left}/{count} left", ordinal(level))),
+                    button(Text::new("Expend"))
+                        .style(styles::menu_button)
+                        .on_press_maybe((left > 0).then_some(Message::SpellSlotExpended(level))),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        if let (Some((count, level)), Some((left, _))) = (slots.pact_magic, remaining.pact_magic) {
+            content = content.push(
+                row![
+                    Text::new(format!(
+                        "{}-level Pact Magic: {left}/{count} left",
+                        ordinal(level)
+                    )),
+                    button(Text::new("Expend"))
+                        .style(styles::menu_button)
+                        .on_press_maybe((left > 0).then_some(Message::PactSlotExpended)),
+                ]
+                .spacing(10)
+                .align_y(Alignment::Center),
+            );
+        }
+        content = content.push(
+            row![
+                button(Text::new("Short Rest"))
+                    .style(styles::menu_button)
+                    .on_press(Message::ShortRestButtonPressed),
+                button(Text::new("Long Rest"))
+                    .style(styles::menu_button)
+                    .on_press(Message::LongRestButtonPressed),
+            ]
+            .spacing(10),
+        );
+        container(content)
+            .padding(utils_styles::indented_padding())
+            .into()
+    }
+}
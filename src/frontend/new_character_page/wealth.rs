@@ -0,0 +1,208 @@
+use iced::{
+    widget::{button, column, container, pick_list, row, text, text_input, Text},
+    Alignment, Element, Length,
+};
+
+use crate::{
+    backend::{
+        content::ContentRef,
+        currency::{self, COINS},
+        equipment::StartingWealth,
+    },
+    frontend::{item, utils::styles as utils_styles},
+};
+
+use super::{styles, Message, NewCharacterPage};
+
+impl NewCharacterPage {
+    const COIN_COLUMN_WIDTH: f32 = 100.0;
+
+    /// The number of transactions shown, newest first.
+    const RECENT_TRANSACTIONS: usize = 20;
+
+    /// Displays the coins the character carries, with controls to receive, pay, and exchange
+    /// them and to buy items, and the log of its transactions.
+    pub(super) fn wealth_info(&self) -> Element<'_, Message> {
+        let title = container(
+            container(Text::new("Wealth").size(utils_styles::TITLE_FONT_SIZE))
+                .center_x(Length::Fill)
+                .padding(utils_styles::TITLE_INNER_PAD)
+                .style(utils_styles::title),
+        )
+        .padding(utils_styles::TITLE_OUTER_PAD);
+
+        column![
+            title,
+            self.purse_view(),
+            self.coins_controls(),
+            self.shop_view(),
+            self.transactions_view()
+        ]
+        .into()
+    }
+
+    /// Displays how many of each coin the purse holds, with buttons to break them into smaller
+    /// coins.
+    fn purse_view(&self) -> Element<'_, Message> {
+        let mut content = column![Text::new("Purse: ")
+            .font(utils_styles::bold_font())
+            .size(utils_styles::SECTION_FONT_SIZE)];
+
+        let wealth = self.draft.wealth(&self.content);
+        let purse = wealth.purse;
+        let mut coins = column![].spacing(utils_styles::COLUMN_SPACING);
+        for coin in COINS {
+            let smaller = currency::smaller(coin);
+            let mut line = row![container(Text::new(format!("{} {coin}", purse.get(coin))))
+                .width(Self::COIN_COLUMN_WIDTH)]
+            .spacing(10)
+            .align_y(Alignment::Center);
+            if let Some(smaller) = smaller {
+                line = line.push(
+                    button(Text::new(format!("Break into {smaller}")))
+                        .style(styles::menu_button)
+                        .on_press_maybe(
+                            (purse.get(coin) > 0).then_some(Message::CoinBreakButtonPressed(coin)),
+                        ),
+                );
+            }
+            coins = coins.push(line);
+        }
+        content = content.push(container(coins).padding(utils_styles::indented_padding()));
+
+        let mut details = column![
+            Text::new(format!(
+                "Worth {} • Weighs {}",
+                currency::describe_copper(purse.total()),
+                item::pounds(purse.weight())
+            )),
+            row![
+                button(Text::new("Consolidate"))
+                    .style(styles::menu_button)
+                    .on_press(Message::CoinsConsolidateButtonPressed),
+                button(Text::new("Reset to starting gold"))
+                    .style(styles::menu_button)
+                    .on_press_maybe(
+                        (self.draft.wealth.is_some()).then_some(Message::WealthResetButtonPressed),
+                    ),
+            ]
+            .spacing(10),
+        ]
+        .spacing(utils_styles::COLUMN_SPACING);
+        if self.draft.equipment.wealth == StartingWealth::Gold(None) {
+            details = details.push(
+                Text::new("Roll the starting gold of your class in the Equipment step.")
+                    .style(text::secondary),
+            );
+        }
+        content = content.push(container(details).padding(utils_styles::indented_padding()));
+
+        container(content.spacing(utils_styles::COLUMN_SPACING))
+            .padding(utils_styles::BASE_PADDING)
+            .into()
+    }
+
+    /// Displays controls to receive coins or pay them out of the purse, making change as
+    /// needed.
+    fn coins_controls(&self) -> Element<'_, Message> {
+        let amount = self.coin_amount.trim().parse::<u32>().ok();
+        let total = self.draft.wealth(&self.content).purse.total();
+        let payable =
+            amount.is_some_and(|amount| amount as u64 * self.coin.value() as u64 <= total);
+
+        let controls = row![
+            text_input("Amount", &self.coin_amount)
+                .on_input(Message::CoinAmountChanged)
+                .width(Self::COIN_COLUMN_WIDTH),
+            pick_list(COINS, Some(self.coin), Message::CoinSelected)
+                .style(styles::dropdown)
+                .menu_style(styles::dropdown_item),
+            button(Text::new("Receive"))
+                .style(styles::menu_button)
+                .on_press_maybe(
+                    amount
+                        .is_some_and(|amount| amount > 0)
+                        .then_some(Message::CoinsReceiveButtonPressed),
+                ),
+            button(Text::new("Pay"))
+                .style(styles::menu_button)
+                .on_press_maybe(
+                    (payable && amount.is_some_and(|amount| amount > 0))
+                        .then_some(Message::CoinsPayButtonPressed),
+                ),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center);
+
+        container(
+            column![
+                Text::new("Receive or Pay: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(controls).padding(utils_styles::indented_padding()),
+            ]
+            .spacing(utils_styles::COLUMN_SPACING),
+        )
+        .padding(utils_styles::BASE_PADDING)
+        .into()
+    }
+
+    /// Displays a dropdown list of the items the character can afford, to buy one of them.
+    fn shop_view(&self) -> Element<'_, Message> {
+        let total = self.draft.wealth(&self.content).purse.total();
+        let items = (self.content.items())
+            .filter(|item| item.cost.in_copper() > 0 && item.cost.in_copper() <= total)
+            .map(|item| ContentRef {
+                id: item.id.clone(),
+                name: match item.bundle {
+                    1 => format!("{} ({})", item.name, item.cost),
+                    bundle => format!("{} × {bundle} ({})", item.name, item.cost),
+                },
+            })
+            .collect::<Vec<_>>();
+        let mut shop = column![pick_list(items, None::<ContentRef>, Message::ItemBought)
+            .style(styles::dropdown)
+            .menu_style(styles::dropdown_item)
+            .placeholder("Buy an item you can afford:")]
+        .spacing(utils_styles::COLUMN_SPACING);
+        if let Some(status) = &self.wealth_status {
+            shop = shop.push(Text::new(status).style(text::danger));
+        }
+
+        container(
+            column![
+                Text::new("Buy: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(shop).padding(utils_styles::indented_padding()),
+            ]
+            .spacing(utils_styles::COLUMN_SPACING),
+        )
+        .padding(utils_styles::BASE_PADDING)
+        .into()
+    }
+
+    /// Displays the latest coins the character received and spent, newest first.
+    fn transactions_view(&self) -> Element<'_, Message> {
+        let wealth = self.draft.wealth(&self.content);
+        let mut log = column![].spacing(utils_styles::COLUMN_SPACING);
+        if wealth.log.is_empty() {
+            log = log.push(Text::new("No transactions yet.").style(text::secondary));
+        }
+        for transaction in wealth.log.iter().rev().take(Self::RECENT_TRANSACTIONS) {
+            log = log.push(Text::new(transaction.to_string()));
+        }
+
+        container(
+            column![
+                Text::new("Transactions: ")
+                    .font(utils_styles::bold_font())
+                    .size(utils_styles::SECTION_FONT_SIZE),
+                container(log).padding(utils_styles::indented_padding()),
+            ]
+            .spacing(utils_styles::COLUMN_SPACING),
+        )
+        .padding(utils_styles::BASE_PADDING)
+        .into()
+    }
+}
@@ -1,19 +1,21 @@
 // TODO: Make all `view` functions take in padding info instead of hardcoding!
-use std::fmt::Display;
-
 use iced::{
     widget::{column, container, horizontal_rule, radio, row, scrollable, Text},
     Element, Length, Padding,
 };
+use serde::{Deserialize, Serialize};
 
-use super::{
-    races,
-    utils::{styles, Action, Attribute, Choices, Language, Range, SizeCategory, Speed, Summary},
+use super::utils::{
+    choice_key, proficiency::Proficiency, styles, Action, Attribute, Choices, Effect, Language,
+    Range, Selections, SizeCategory, Speed, Summary,
 };
 
 /// Represents a race a character can be.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Race {
+    /// The stable id of the race.
+    pub id: String,
+
     /// The name of the race.
     pub name: String,
 
@@ -39,7 +41,7 @@ pub struct Race {
     pub languages: Vec<Language>,
 
     /// The proficiencies the race provides.
-    pub proficiencies: Vec<Choices<String>>, // TODO: Replace `String` w/ `Proficiency` struct
+    pub proficiencies: Vec<Choices<Proficiency>>,
 
     /// Subraces that a character may choose.
     pub subraces: Vec<Subrace>,
@@ -53,6 +55,8 @@ impl Race {
         self,
         on_subrace_selected: &'a dyn Fn(Subrace) -> Msg,
         selected_subrace: Option<&Subrace>,
+        selections: &Selections<Proficiency>,
+        on_choice_selected: &'a dyn Fn(String, Vec<Proficiency>) -> Msg,
     ) -> Element<'a, Msg> {
         let line = container(horizontal_rule(1.0)).padding(styles::HORIZONTAL_LINE_PADDING);
 
@@ -70,7 +74,7 @@ impl Race {
             styles::SUMMARY_SUBSECTION_PADDING,
         );
 
-        let asi = if self.asi.len() > 0 {
+        let asi = if !self.asi.is_empty() {
             let mut content = row![Text::new("Ability Score Increase: ")
                 .font(styles::bold_font())
                 .size(styles::SECTION_FONT_SIZE)];
@@ -103,7 +107,7 @@ impl Race {
 
         let size = self.size.view(&self.name_plural, styles::BASE_PADDING);
 
-        let speed = if self.speed.len() > 0 {
+        let speed = if !self.speed.is_empty() {
             let mut content = row![Text::new("Speed: ")
                 .font(styles::bold_font())
                 .size(styles::SECTION_FONT_SIZE)];
@@ -120,15 +124,19 @@ impl Race {
             container(row![])
         };
 
-        let proficiencies = if self.proficiencies.len() > 0 {
+        let proficiencies = if !self.proficiencies.is_empty() {
             let mut content = column![Text::new("Proficiencies: ")
                 .font(styles::bold_font())
                 .size(styles::SECTION_FONT_SIZE)];
 
-            for proficiency_list in &self.proficiencies {
+            for (i, proficiency_list) in self.proficiencies.into_iter().enumerate() {
+                let key = choice_key(&format!("race:{}", self.id), "proficiencies", i);
                 content = content.push(
-                    container(Text::new(
-                        proficiency_list.text("You gain proficiency with"),
+                    container(proficiency_list.view(
+                        "You gain proficiency with",
+                        key,
+                        selections,
+                        on_choice_selected,
                     ))
                     .padding(styles::indented_padding()),
                 );
@@ -139,7 +147,7 @@ impl Race {
             container(column![])
         };
 
-        let racial_traits = if self.traits.len() > 0 {
+        let racial_traits = if !self.traits.is_empty() {
             let mut content = column![];
             for racial_trait in self.traits {
                 content = content.push(racial_trait.view())
@@ -149,7 +157,7 @@ impl Race {
             container(column![])
         };
 
-        let languages = if self.languages.len() > 0 {
+        let languages = if !self.languages.is_empty() {
             let mut languages_txt = String::with_capacity(30);
             let num_languages = self.languages.len();
             for (i, language) in self.languages.iter().enumerate() {
@@ -176,7 +184,7 @@ impl Race {
             container(row![])
         };
 
-        let subraces = if self.subraces.len() > 0 {
+        let subraces = if !self.subraces.is_empty() {
             let mut content = column![];
 
             let subrace_list = {
@@ -196,7 +204,11 @@ impl Race {
 
                 // Display subrace info
                 if let Some(selected_subrace) = selected_subrace {
-                    subrace_content = subrace_content.push(selected_subrace.clone().view());
+                    subrace_content = subrace_content.push(
+                        selected_subrace
+                            .clone()
+                            .view(selections, on_choice_selected),
+                    );
                 }
 
                 subrace_content
@@ -229,50 +241,12 @@ impl Race {
     }
 }
 
-// TODO: Update with all races
-//
-/// All of the possible races.
-#[derive(Debug, Clone, PartialEq)]
-pub enum RaceName {
-    Dwarf,
-    _Count,
-}
-
-impl RaceName {
-    // NOTE: Keep synced with `Races` enum
-    pub const ALL: [RaceName; RaceName::_Count as usize] = [RaceName::Dwarf];
-}
-
-impl Display for RaceName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RaceName::Dwarf => f.write_str("Dwarf"),
-            RaceName::_Count => unreachable!("Invalid race"),
-        }
-    }
-}
-
-impl From<Race> for &RaceName {
-    fn from(value: Race) -> Self {
-        match value.name.as_str() {
-            "Dwarf" => &RaceName::Dwarf,
-            _ => unreachable!("Invalid race"),
-        }
-    }
-}
-
-impl Into<Race> for &RaceName {
-    fn into(self) -> Race {
-        match self {
-            RaceName::Dwarf => races::dwarf::dwarf(),
-            RaceName::_Count => unreachable!("`_Count` is not a valid race"),
-        }
-    }
-}
-
 /// Represents a trait provided by a race.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RacialTrait {
+    /// The stable id of the trait.
+    pub id: String,
+
     /// The name of the trait.
     pub name: String,
 
@@ -281,6 +255,10 @@ pub struct RacialTrait {
 
     /// The type of action of the trait.
     pub action_type: Option<Action>,
+
+    /// The mechanical effects of the trait.
+    #[serde(default)]
+    pub effects: Vec<Effect>,
 }
 
 impl RacialTrait {
@@ -301,8 +279,11 @@ impl RacialTrait {
 }
 
 /// Represents a subrace of a race.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Subrace {
+    /// The stable id of the subrace.
+    pub id: String,
+
     /// The name of the race.
     pub name: String,
 
@@ -316,14 +297,18 @@ pub struct Subrace {
     pub languages: Vec<Language>,
 
     /// The proficiencies the race provides.
-    pub proficiencies: Vec<Choices<String>>,
+    pub proficiencies: Vec<Choices<Proficiency>>,
 
     /// A list of traits provided by the subrace.
     pub traits: Vec<RacialTrait>,
 }
 
 impl Subrace {
-    pub fn view<'a, Msg: 'a>(self) -> Element<'a, Msg> {
+    pub fn view<'a, Msg: 'a + Clone>(
+        self,
+        selections: &Selections<Proficiency>,
+        on_choice_selected: &'a dyn Fn(String, Vec<Proficiency>) -> Msg,
+    ) -> Element<'a, Msg> {
         let line = container(horizontal_rule(1.0));
 
         let title = container(
@@ -343,7 +328,7 @@ impl Subrace {
             Padding::default(),
         );
 
-        let asi = if self.asi.len() > 0 {
+        let asi = if !self.asi.is_empty() {
             let mut content = row![Text::new("Ability Score Increase: ")
                 .font(styles::bold_font())
                 .size(styles::SECTION_FONT_SIZE)];
@@ -360,15 +345,19 @@ impl Subrace {
             container(row![])
         };
 
-        let proficiencies = if self.proficiencies.len() > 0 {
+        let proficiencies = if !self.proficiencies.is_empty() {
             let mut content = column![Text::new("Proficiencies: ")
                 .font(styles::bold_font())
                 .size(styles::SECTION_FONT_SIZE)];
 
-            for proficiency_list in &self.proficiencies {
+            for (i, proficiency_list) in self.proficiencies.into_iter().enumerate() {
+                let key = choice_key(&format!("subrace:{}", self.id), "proficiencies", i);
                 content = content.push(
-                    container(Text::new(
-                        proficiency_list.text("You gain proficiency with"),
+                    container(proficiency_list.view(
+                        "You gain proficiency with",
+                        key,
+                        selections,
+                        on_choice_selected,
                     ))
                     .padding(styles::indented_padding()),
                 );
@@ -379,7 +368,7 @@ impl Subrace {
             container(column![])
         };
 
-        let racial_traits = if self.traits.len() > 0 {
+        let racial_traits = if !self.traits.is_empty() {
             let mut content = column![];
             for racial_trait in self.traits {
                 content = content.push(racial_trait.view())
@@ -389,7 +378,7 @@ impl Subrace {
             container(column![])
         };
 
-        let languages = if self.languages.len() > 0 {
+        let languages = if !self.languages.is_empty() {
             let mut languages_txt = String::with_capacity(30);
             let num_languages = self.languages.len();
             for (i, language) in self.languages.iter().enumerate() {
@@ -429,7 +418,7 @@ impl Subrace {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Age {
     /// The age at which a character is considered an adult.
     pub adult: u16,
@@ -439,7 +428,7 @@ pub struct Age {
 }
 
 /// Represents the size info for a character.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Size {
     /// The size category.
     pub category: SizeCategory,
@@ -505,7 +494,7 @@ impl Size {
 }
 
 /// Represents the height of a character in feet and inches.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Height {
     pub feet: f32,
     pub inches: f32,
@@ -0,0 +1,177 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{checkbox, column, container, text, Text},
+    Element,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{class::ordinal, utils::styles};
+
+/// Represents a spell a character can learn or prepare.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Spell {
+    /// The stable id of the spell.
+    pub id: String,
+
+    /// The name of the spell.
+    pub name: String,
+
+    /// The level of the spell, with 0 for cantrips.
+    pub level: u8,
+
+    /// The school of magic the spell belongs to.
+    pub school: School,
+
+    /// How long casting the spell takes, such as `1 action`.
+    pub casting_time: String,
+
+    /// How far away the spell can reach, such as `120 feet` or `Self (15-foot cone)`.
+    pub range: String,
+
+    /// What casting the spell requires.
+    pub components: Components,
+
+    /// How long the spell lasts, such as `Instantaneous` or `1 minute`.
+    pub duration: String,
+
+    /// Whether the spell requires concentration for its duration.
+    #[serde(default)]
+    pub concentration: bool,
+
+    /// Whether the spell can be cast as a ritual.
+    #[serde(default)]
+    pub ritual: bool,
+
+    /// The ids of the classes whose spell list includes the spell.
+    pub classes: Vec<String>,
+
+    /// The spell's description.
+    pub summary: String,
+}
+
+impl Spell {
+    /// The highest level a spell can have.
+    pub const MAX_LEVEL: u8 = 9;
+
+    /// Returns `true` if the spell is a cantrip.
+    pub fn is_cantrip(&self) -> bool {
+        self.level == 0
+    }
+
+    /// Describes the level and school of the spell, such as `1st-level evocation` or
+    /// `Conjuration cantrip`.
+    pub fn kind(&self) -> String {
+        let mut kind = if self.is_cantrip() {
+            format!("{} cantrip", self.school)
+        } else {
+            format!(
+                "{}-level {}",
+                ordinal(self.level),
+                self.school.to_string().to_lowercase()
+            )
+        };
+        if self.ritual {
+            kind.push_str(" (ritual)");
+        }
+        kind
+    }
+
+    /// Describes the casting time, range, components, and duration of the spell.
+    pub fn details(&self) -> String {
+        let duration = if self.concentration {
+            format!("Concentration, up to {}", self.duration)
+        } else {
+            self.duration.clone()
+        };
+        format!(
+            "{} • {} • {} • {}",
+            self.casting_time, self.range, self.components, duration
+        )
+    }
+
+    /// Displays the spell with a checkbox to pick it, enabled if `on_toggle` is given.
+    pub fn view<'a, Msg: 'a + Clone>(
+        &self,
+        checked: bool,
+        on_toggle: Option<Msg>,
+    ) -> Element<'a, Msg> {
+        container(column![
+            checkbox(format!("{} ({})", self.name, self.kind()), checked)
+                .on_toggle_maybe(on_toggle.map(|msg| move |_| msg.clone())),
+            container(column![
+                Text::new(self.details()).style(text::secondary),
+                Text::new(self.summary.clone()).style(text::secondary),
+            ])
+            .padding(styles::indented_padding()),
+        ])
+        .padding(styles::radio_padding())
+        .into()
+    }
+}
+
+impl Display for Spell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// The eight schools of magic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum School {
+    Abjuration,
+    Conjuration,
+    Divination,
+    Enchantment,
+    Evocation,
+    Illusion,
+    Necromancy,
+    Transmutation,
+}
+
+impl Display for School {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            School::Abjuration => f.write_str("Abjuration"),
+            School::Conjuration => f.write_str("Conjuration"),
+            School::Divination => f.write_str("Divination"),
+            School::Enchantment => f.write_str("Enchantment"),
+            School::Evocation => f.write_str("Evocation"),
+            School::Illusion => f.write_str("Illusion"),
+            School::Necromancy => f.write_str("Necromancy"),
+            School::Transmutation => f.write_str("Transmutation"),
+        }
+    }
+}
+
+/// The components needed to cast a spell.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Components {
+    /// Whether the spell has a verbal component.
+    #[serde(default)]
+    pub verbal: bool,
+
+    /// Whether the spell has a somatic component.
+    #[serde(default)]
+    pub somatic: bool,
+
+    /// The material component of the spell, if it has one.
+    #[serde(default)]
+    pub material: Option<String>,
+}
+
+impl Display for Components {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut components = vec![];
+        if self.verbal {
+            components.push("V".to_string());
+        }
+        if self.somatic {
+            components.push("S".to_string());
+        }
+        if let Some(material) = &self.material {
+            components.push(format!("M ({material})"));
+        }
+        f.write_str(&components.join(", "))
+    }
+}
@@ -1,14 +1,21 @@
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
 use iced::{
-    widget::{column, container, Text},
+    widget::{checkbox, column, container, radio, Text},
     Element, Padding,
 };
+use serde::{Deserialize, Serialize};
+
+use crate::backend::abilities::Ability;
 
 use super::race::Height;
 
+use proficiency::{Proficiency, Skill};
+
+pub mod proficiency;
+
 /// Represents an attribute of a character.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Attribute {
     Strength(u8),
     Dexterity(u8),
@@ -43,8 +50,34 @@ impl Display for Attribute {
     }
 }
 
+impl Attribute {
+    /// The ability the attribute applies to.
+    pub fn ability(&self) -> Ability {
+        match self {
+            Attribute::Strength(_) => Ability::Strength,
+            Attribute::Dexterity(_) => Ability::Dexterity,
+            Attribute::Constitution(_) => Ability::Constitution,
+            Attribute::Intelligence(_) => Ability::Intelligence,
+            Attribute::Wisdom(_) => Ability::Wisdom,
+            Attribute::Charisma(_) => Ability::Charisma,
+        }
+    }
+
+    /// The amount of the attribute.
+    pub fn amount(&self) -> u8 {
+        match self {
+            Attribute::Strength(amount)
+            | Attribute::Dexterity(amount)
+            | Attribute::Constitution(amount)
+            | Attribute::Intelligence(amount)
+            | Attribute::Wisdom(amount)
+            | Attribute::Charisma(amount) => *amount,
+        }
+    }
+}
+
 /// Represents a range of possible values.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Range<T> {
     pub start: T,
     pub end: T,
@@ -105,7 +138,7 @@ impl Range<f32> {
 }
 
 /// Represents the size category of a character.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SizeCategory {
     Tiny,
     Small,
@@ -115,7 +148,7 @@ pub enum SizeCategory {
 }
 
 /// Represents a speed of a character.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Speed {
     Walking(u16),
     Flying(u16),
@@ -140,8 +173,35 @@ impl Display for Speed {
     }
 }
 
+impl Speed {
+    /// The speed in feet.
+    pub fn amount(&self) -> u16 {
+        match self {
+            Speed::Walking(amount)
+            | Speed::Flying(amount)
+            | Speed::Swimming(amount)
+            | Speed::Climbing(amount) => *amount,
+        }
+    }
+
+    /// Returns a mutable reference to the speed in feet.
+    pub fn amount_mut(&mut self) -> &mut u16 {
+        match self {
+            Speed::Walking(amount)
+            | Speed::Flying(amount)
+            | Speed::Swimming(amount)
+            | Speed::Climbing(amount) => amount,
+        }
+    }
+
+    /// Returns `true` if both speeds are the same kind of movement.
+    pub fn same_kind(&self, other: &Speed) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
 /// Represents a language a character knows.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Language {
     pub name: String,
     pub levels: Vec<LanguageLevel>,
@@ -162,7 +222,7 @@ impl Display for Language {
 }
 
 /// Represents the various levels of proficiency in a language.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LanguageLevel {
     Speak,
     Read,
@@ -181,41 +241,509 @@ impl LanguageLevel {
     }
 }
 
+/// Items that can be offered as a choice of anything in a category with [`Choices::Any`].
+pub trait Categorized: Sized {
+    /// The categories the items are grouped into.
+    type Category;
+
+    /// Every item in `category`.
+    fn in_category(category: &Self::Category) -> Vec<Self>;
+}
+
 /// Represents various choices a character can make.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Choices<T> {
+///
+/// The selection made for choices is stored in [`Selections`] under the key of the choices. Each
+/// alternative of [`Choices::Either`] stores its selection under its [`alternative_key`], and
+/// the alternative with a selection is the one that was chosen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Choices<T: Categorized> {
     /// A list of choices out of which only one can be selected.
     One(Vec<T>),
 
     /// A list of choices from which all are selected.
     All(Vec<T>),
+
+    /// A list of choices out of which the given number can be selected, such as two skills from
+    /// a class' skill list.
+    Pick(u8, Vec<T>),
+
+    /// The given number of choices out of everything in a category, such as any one language.
+    Any(u8, T::Category),
+
+    /// Alternative groups of choices, only one of which can be selected, such as a martial
+    /// weapon or a shield.
+    Either(Vec<Choices<T>>),
+}
+
+impl<T: Categorized> Choices<T> {
+    /// Returns the items that are granted without requiring a selection.
+    pub fn granted(&self) -> &[T] {
+        match self {
+            Choices::All(items) => items,
+            Choices::One(_) | Choices::Pick(..) | Choices::Any(..) | Choices::Either(_) => &[],
+        }
+    }
+
+    /// The number of items that have to be selected, or `None` if the choices don't take a list
+    /// of items as their selection.
+    pub fn count(&self) -> Option<usize> {
+        match self {
+            Choices::One(_) => Some(1),
+            Choices::Pick(count, _) | Choices::Any(count, _) => Some(*count as usize),
+            Choices::All(_) | Choices::Either(_) => None,
+        }
+    }
+}
+
+/// The selections made for [`Choices`], keyed by [`choice_key`].
+pub type Selections<T> = BTreeMap<String, Vec<T>>;
+
+/// Returns the key the selection for the choices at `index` of `field` in `source` is stored
+/// under, such as `race:dwarf/proficiencies[0]`.
+pub fn choice_key(source: &str, field: &str, index: usize) -> String {
+    format!("{source}/{field}[{index}]")
+}
+
+/// Returns the key the selection for the alternative at `index` of the [`Choices::Either`]
+/// stored under `key` is stored under, such as `class:bard/proficiencies[2].1`.
+pub fn alternative_key(key: &str, index: usize) -> String {
+    format!("{key}.{index}")
 }
 
-impl Choices<String> {
+/// Stores `items` as the selection made under `key`.
+///
+/// If `key` belongs to an alternative of [`Choices::Either`], that alternative (and every
+/// alternative enclosing it) becomes the chosen one, and the selections made for the others are
+/// discarded.
+pub fn select<T>(selections: &mut Selections<T>, key: String, items: Vec<T>) {
+    let mut alternative = key.clone();
+    selections.insert(key, items);
+    while let Some((parent, index)) = alternative.rsplit_once('.') {
+        if index.parse::<usize>().is_err() {
+            break;
+        }
+        let parent = parent.to_string();
+        let siblings = format!("{parent}.");
+        let nested = format!("{alternative}.");
+        selections.retain(|key, _| {
+            !key.starts_with(&siblings) || *key == alternative || key.starts_with(&nested)
+        });
+        selections.entry(alternative).or_default();
+        alternative = parent;
+    }
+}
+
+impl<T: Categorized + Clone + PartialEq> Choices<T> {
+    /// Every item that can be selected, or an empty list for [`Choices::Either`], whose
+    /// alternatives offer their own items.
+    pub fn options(&self) -> Vec<T> {
+        match self {
+            Choices::One(items) | Choices::All(items) | Choices::Pick(_, items) => items.clone(),
+            Choices::Any(_, category) => T::in_category(category),
+            Choices::Either(_) => vec![],
+        }
+    }
+
+    /// Returns the index of the chosen alternative of [`Choices::Either`], if one has been
+    /// chosen.
+    pub fn chosen(&self, key: &str, selections: &Selections<T>) -> Option<usize> {
+        match self {
+            Choices::Either(alternatives) => {
+                (0..alternatives.len()).find(|&i| selections.contains_key(&alternative_key(key, i)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a complete selection has been made in `selections` for the choices
+    /// stored under `key`.
+    pub fn is_resolved(&self, key: &str, selections: &Selections<T>) -> bool {
+        match self {
+            Choices::All(_) => true,
+            Choices::Either(alternatives) => match self.chosen(key, selections) {
+                Some(i) => alternatives[i].is_resolved(&alternative_key(key, i), selections),
+                None => false,
+            },
+            _ => {
+                let count = self.count().unwrap_or_default();
+                let selected = selections.get(key).map(Vec::as_slice).unwrap_or_default();
+                let options = self.options();
+                selected.len() == count
+                    && selected
+                        .iter()
+                        .enumerate()
+                        .all(|(i, item)| options.contains(item) && !selected[..i].contains(item))
+            }
+        }
+    }
+
+    /// Returns the items granted by the choices stored under `key`, given the selections made.
+    ///
+    /// Selected items that aren't part of the choices, and any beyond the number that can be
+    /// selected, are ignored.
+    pub fn resolve(&self, key: &str, selections: &Selections<T>) -> Vec<T> {
+        match self {
+            Choices::All(items) => items.clone(),
+            Choices::Either(alternatives) => match self.chosen(key, selections) {
+                Some(i) => alternatives[i].resolve(&alternative_key(key, i), selections),
+                None => vec![],
+            },
+            _ => {
+                let options = self.options();
+                let mut resolved: Vec<T> = vec![];
+                for item in selections.get(key).into_iter().flatten() {
+                    if options.contains(item) && !resolved.contains(item) {
+                        resolved.push(item.clone());
+                    }
+                }
+                resolved.truncate(self.count().unwrap_or_default());
+                resolved
+            }
+        }
+    }
+}
+
+impl<T> Choices<T>
+where
+    T: Categorized + Display + Clone + PartialEq + 'static,
+    T::Category: Display + Clone,
+{
+    /// Displays the choices, with controls to make the selection if one is required.
+    ///
+    /// `on_select` is called with the key of the selection that changed and the newly selected
+    /// items; the selection should be stored with [`select`].
+    pub fn view<'a, Msg: 'a + Clone>(
+        self,
+        header: &str,
+        key: String,
+        selections: &Selections<T>,
+        on_select: &'a dyn Fn(String, Vec<T>) -> Msg,
+    ) -> Element<'a, Msg> {
+        match self {
+            Choices::All(_) => Text::new(self.text(header)).into(),
+            Choices::One(ref items) => {
+                let selected = selections
+                    .get(&key)
+                    .and_then(|selected| selected.first())
+                    .and_then(|selected| items.iter().position(|item| item == selected));
+
+                let mut content = column![Text::new(format!(
+                    "{} one of the following of your choice:",
+                    header
+                ))];
+                for (i, item) in items.iter().enumerate() {
+                    let item = item.clone();
+                    let key = key.clone();
+                    content = content.push(
+                        container(radio(item.to_string(), i, selected, move |_| {
+                            on_select(key, vec![item])
+                        }))
+                        .padding(styles::radio_padding()),
+                    );
+                }
+                content.into()
+            }
+            Choices::Pick(count, _) | Choices::Any(count, _) => {
+                let selected = self.resolve(&key, selections);
+                let full = selected.len() >= count as usize;
+
+                let prompt = match &self {
+                    Choices::Any(_, category) => format!(
+                        "{} any {} of the {} of your choice ({} selected):",
+                        header,
+                        number_word(count),
+                        category,
+                        selected.len()
+                    ),
+                    _ => format!(
+                        "{} {} of the following of your choice ({} selected):",
+                        header,
+                        number_word(count),
+                        selected.len()
+                    ),
+                };
+                let mut content = column![Text::new(prompt)];
+                for item in self.options() {
+                    let checked = selected.contains(&item);
+                    let toggle = (checked || !full).then(|| {
+                        let key = key.clone();
+                        let selected = selected.clone();
+                        let item = item.clone();
+                        move |checked: bool| {
+                            let mut selected = selected.clone();
+                            if checked {
+                                selected.push(item.clone());
+                            } else {
+                                selected.retain(|s| *s != item);
+                            }
+                            on_select(key.clone(), selected)
+                        }
+                    });
+                    content = content.push(
+                        container(checkbox(item.to_string(), checked).on_toggle_maybe(toggle))
+                            .padding(styles::radio_padding()),
+                    );
+                }
+                content.into()
+            }
+            Choices::Either(ref alternatives) => {
+                let chosen = self.chosen(&key, selections);
+
+                let mut content = column![Text::new(format!(
+                    "{} one of the following of your choice:",
+                    header
+                ))];
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    let alternative_key = alternative_key(&key, i);
+                    let current = selections
+                        .get(&alternative_key)
+                        .cloned()
+                        .unwrap_or_default();
+                    let radio_key = alternative_key.clone();
+                    content = content.push(
+                        container(radio(alternative.label(), i, chosen, move |_| {
+                            on_select(radio_key, current)
+                        }))
+                        .padding(styles::radio_padding()),
+                    );
+                    if chosen == Some(i) && !matches!(alternative, Choices::All(_)) {
+                        content = content.push(
+                            container(alternative.clone().view(
+                                "Choose",
+                                alternative_key,
+                                selections,
+                                on_select,
+                            ))
+                            .padding(styles::indented_padding()),
+                        );
+                    }
+                }
+                content.into()
+            }
+        }
+    }
+}
+
+impl<T> Choices<T>
+where
+    T: Categorized + Display,
+    T::Category: Display,
+{
     pub fn text(&self, header: &str) -> String {
         match self {
             Choices::One(items) => format!(
                 "{} one of the following of your choice: {}.",
                 header,
-                items.join(", ")
+                join(items)
             ),
             Choices::All(items) => {
-                format!("{} all of the following: {}.", header, items.join(", "))
+                format!("{} all of the following: {}.", header, join(items))
+            }
+            Choices::Pick(count, items) => format!(
+                "{} {} of the following of your choice: {}.",
+                header,
+                number_word(*count),
+                join(items)
+            ),
+            Choices::Any(count, category) => format!(
+                "{} any {} of the {} of your choice.",
+                header,
+                number_word(*count),
+                category
+            ),
+            Choices::Either(_) => format!(
+                "{} one of the following of your choice: {}.",
+                header,
+                self.label()
+            ),
+        }
+    }
+
+    /// A short description of the choices, without a header, such as `two of Arcana, History`.
+    pub fn label(&self) -> String {
+        match self {
+            Choices::One(items) => format!("one of {}", join(items)),
+            Choices::All(items) => join(items),
+            Choices::Pick(count, items) => format!("{} of {}", number_word(*count), join(items)),
+            Choices::Any(count, category) => {
+                format!("any {} of the {}", number_word(*count), category)
             }
+            Choices::Either(alternatives) => alternatives
+                .iter()
+                .map(|alternative| alternative.label())
+                .collect::<Vec<_>>()
+                .join("; or "),
         }
     }
 }
 
+/// Joins the display names of `items` with commas.
+fn join<T: Display>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Spells out small numbers, such as `two`.
+pub fn number_word(number: u8) -> String {
+    const WORDS: [&str; 11] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    WORDS
+        .get(number as usize)
+        .map(|word| word.to_string())
+        .unwrap_or_else(|| number.to_string())
+}
+
 /// Types of actions.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Action {
     Action,
     BonusAction,
     Reaction,
 }
 
+/// A mechanical effect of a trait, applied automatically to a character.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effect {
+    /// Increases the hit point maximum by the amount for every character level.
+    HitPointsPerLevel(u8),
+
+    /// Grants proficiency with each of the items.
+    Proficiencies(Vec<Proficiency>),
+
+    /// Grants resistance to a type of damage.
+    Resistance(DamageType),
+
+    /// Grants advantage on saving throws against effects with the tag, such as `poison`.
+    SaveAdvantage(String),
+
+    /// Grants a special sense.
+    Sense(Sense),
+
+    /// Increases a speed by the amount, or grants it if the character doesn't have that speed.
+    SpeedBonus(Speed),
+
+    /// The character's speed is not reduced by wearing heavy armor.
+    IgnoreHeavyArmorSpeedPenalty,
+
+    /// A bonus that only applies to some checks made with a skill, shown as a note next to it.
+    Situational(SituationalModifier),
+
+    /// Increases the given number of different ability scores of the character's choice by 1.
+    AbilityScoreChoices(u8),
+
+    /// Grants a feat of the character's choice, unless the campaign doesn't use feats.
+    Feat,
+
+    /// Grants a way of computing the Armor Class while not wearing armor, such as Unarmored
+    /// Defense.
+    ArmorClass(ArmorClassFormula),
+}
+
+/// A way of computing the Armor Class while not wearing armor: the base plus the Dexterity
+/// modifier and the modifiers of the listed abilities.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArmorClassFormula {
+    /// The base Armor Class, such as 10 for Unarmored Defense.
+    pub base: u8,
+
+    /// The abilities whose modifiers are added along with the Dexterity modifier.
+    #[serde(default)]
+    pub abilities: Vec<Ability>,
+
+    /// Whether a shield can be used along with the formula.
+    pub shield: bool,
+}
+
+/// A bonus to a skill that only applies in some situations, such as Stonecunning's bonus to
+/// History checks related to the origin of stonework.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SituationalModifier {
+    /// The skill the bonus applies to.
+    pub skill: Skill,
+
+    /// When the bonus applies and what it is.
+    pub note: String,
+}
+
+/// Types of damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    Acid,
+    Bludgeoning,
+    Cold,
+    Fire,
+    Force,
+    Lightning,
+    Necrotic,
+    Piercing,
+    Poison,
+    Psychic,
+    Radiant,
+    Slashing,
+    Thunder,
+}
+
+impl Display for DamageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DamageType::Acid => "Acid",
+            DamageType::Bludgeoning => "Bludgeoning",
+            DamageType::Cold => "Cold",
+            DamageType::Fire => "Fire",
+            DamageType::Force => "Force",
+            DamageType::Lightning => "Lightning",
+            DamageType::Necrotic => "Necrotic",
+            DamageType::Piercing => "Piercing",
+            DamageType::Poison => "Poison",
+            DamageType::Psychic => "Psychic",
+            DamageType::Radiant => "Radiant",
+            DamageType::Slashing => "Slashing",
+            DamageType::Thunder => "Thunder",
+        })
+    }
+}
+
+/// Represents a special sense and its range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sense {
+    pub kind: SenseKind,
+
+    /// The range of the sense in feet.
+    pub range: u16,
+}
+
+impl Display for Sense {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} {} ft.", self.kind, self.range))
+    }
+}
+
+/// Kinds of special senses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SenseKind {
+    Blindsight,
+    Darkvision,
+    Tremorsense,
+    Truesight,
+}
+
+impl Display for SenseKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SenseKind::Blindsight => "Blindsight",
+            SenseKind::Darkvision => "Darkvision",
+            SenseKind::Tremorsense => "Tremorsense",
+            SenseKind::Truesight => "Truesight",
+        })
+    }
+}
+
 /// Represents a summary/description.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Summary {
     pub main: String,
     pub subsections: Vec<(String, String)>,
@@ -0,0 +1,493 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backend::abilities::Ability;
+
+use super::Categorized;
+
+/// Something a character can be proficient in.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Proficiency {
+    Skill(Skill),
+
+    /// A tool, identified by the id of one of the [`TOOLS`].
+    Tool(String),
+
+    Weapon(WeaponProficiency),
+    Armor(ArmorProficiency),
+    SavingThrow(Ability),
+
+    /// A language, identified by its name.
+    Language(String),
+}
+
+impl Proficiency {
+    /// The stable id of the proficiency, such as `skill:athletics` or `tool:smiths-tools`.
+    pub fn id(&self) -> String {
+        match self {
+            Proficiency::Skill(skill) => format!("skill:{}", skill.id()),
+            Proficiency::Tool(id) => format!("tool:{id}"),
+            Proficiency::Weapon(weapon) => format!("weapon:{}", weapon.id()),
+            Proficiency::Armor(armor) => format!("armor:{}", armor.id()),
+            Proficiency::SavingThrow(ability) => {
+                format!("saving-throw:{}", ability.to_string().to_lowercase())
+            }
+            Proficiency::Language(name) => format!(
+                "language:{}",
+                name.to_lowercase()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join("-")
+            ),
+        }
+    }
+
+    /// The kind of the proficiency.
+    pub fn kind(&self) -> ProficiencyKind {
+        match self {
+            Proficiency::Skill(_) => ProficiencyKind::Skill,
+            Proficiency::Tool(_) => ProficiencyKind::Tool,
+            Proficiency::Weapon(_) => ProficiencyKind::Weapon,
+            Proficiency::Armor(_) => ProficiencyKind::Armor,
+            Proficiency::SavingThrow(_) => ProficiencyKind::SavingThrow,
+            Proficiency::Language(_) => ProficiencyKind::Language,
+        }
+    }
+
+    /// Every proficiency of `kind` that can be picked as a replacement, or an empty list if
+    /// proficiencies of that kind can't be replaced.
+    ///
+    /// Only skills and tools can be replaced when the same proficiency is granted twice.
+    pub fn replacements(kind: ProficiencyKind) -> Vec<Proficiency> {
+        match kind {
+            ProficiencyKind::Skill => Skill::ALL.into_iter().map(Proficiency::Skill).collect(),
+            ProficiencyKind::Tool => TOOLS
+                .iter()
+                .map(|tool| Proficiency::Tool(tool.id.into()))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl Display for Proficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Proficiency::Skill(skill) => skill.fmt(f),
+            Proficiency::Tool(id) => match tool(id) {
+                Some(tool) => f.write_str(tool.name),
+                None => f.write_str(id),
+            },
+            Proficiency::Weapon(weapon) => weapon.fmt(f),
+            Proficiency::Armor(armor) => armor.fmt(f),
+            Proficiency::SavingThrow(ability) => {
+                f.write_fmt(format_args!("{} saving throws", ability))
+            }
+            Proficiency::Language(name) => f.write_str(name),
+        }
+    }
+}
+
+impl Categorized for Proficiency {
+    type Category = ProficiencyCategory;
+
+    fn in_category(category: &ProficiencyCategory) -> Vec<Proficiency> {
+        match category {
+            ProficiencyCategory::Skills => Skill::ALL.into_iter().map(Proficiency::Skill).collect(),
+            ProficiencyCategory::Tools(kind) => TOOLS
+                .iter()
+                .filter(|tool| kind.is_none_or(|kind| tool.category == kind))
+                .map(|tool| Proficiency::Tool(tool.id.into()))
+                .collect(),
+            ProficiencyCategory::Languages => LANGUAGES
+                .into_iter()
+                .map(|name| Proficiency::Language(name.into()))
+                .collect(),
+        }
+    }
+}
+
+/// The categories a proficiency can be chosen from with [`Choices::Any`](super::Choices::Any).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProficiencyCategory {
+    /// Every skill.
+    Skills,
+
+    /// Every tool, or only the tools of the given category.
+    Tools(Option<ToolCategory>),
+
+    /// Every one of the [`LANGUAGES`].
+    Languages,
+}
+
+impl Display for ProficiencyCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProficiencyCategory::Skills => f.write_str("skills"),
+            ProficiencyCategory::Tools(None) => f.write_str("tools"),
+            ProficiencyCategory::Tools(Some(category)) => {
+                f.write_str(&category.to_string().to_lowercase())
+            }
+            ProficiencyCategory::Languages => f.write_str("languages"),
+        }
+    }
+}
+
+/// The kinds of proficiencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProficiencyKind {
+    Skill,
+    Tool,
+    Weapon,
+    Armor,
+    SavingThrow,
+    Language,
+}
+
+impl Display for ProficiencyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProficiencyKind::Skill => f.write_str("skill"),
+            ProficiencyKind::Tool => f.write_str("tool"),
+            ProficiencyKind::Weapon => f.write_str("weapon"),
+            ProficiencyKind::Armor => f.write_str("armor"),
+            ProficiencyKind::SavingThrow => f.write_str("saving throw"),
+            ProficiencyKind::Language => f.write_str("language"),
+        }
+    }
+}
+
+/// The eighteen skills.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Skill {
+    Acrobatics,
+    AnimalHandling,
+    Arcana,
+    Athletics,
+    Deception,
+    History,
+    Insight,
+    Intimidation,
+    Investigation,
+    Medicine,
+    Nature,
+    Perception,
+    Performance,
+    Persuasion,
+    Religion,
+    SleightOfHand,
+    Stealth,
+    Survival,
+}
+
+impl Skill {
+    pub const ALL: [Skill; 18] = [
+        Skill::Acrobatics,
+        Skill::AnimalHandling,
+        Skill::Arcana,
+        Skill::Athletics,
+        Skill::Deception,
+        Skill::History,
+        Skill::Insight,
+        Skill::Intimidation,
+        Skill::Investigation,
+        Skill::Medicine,
+        Skill::Nature,
+        Skill::Perception,
+        Skill::Performance,
+        Skill::Persuasion,
+        Skill::Religion,
+        Skill::SleightOfHand,
+        Skill::Stealth,
+        Skill::Survival,
+    ];
+
+    /// The ability checks of the skill use.
+    pub fn ability(&self) -> Ability {
+        match self {
+            Skill::Athletics => Ability::Strength,
+            Skill::Acrobatics | Skill::SleightOfHand | Skill::Stealth => Ability::Dexterity,
+            Skill::Arcana
+            | Skill::History
+            | Skill::Investigation
+            | Skill::Nature
+            | Skill::Religion => Ability::Intelligence,
+            Skill::AnimalHandling
+            | Skill::Insight
+            | Skill::Medicine
+            | Skill::Perception
+            | Skill::Survival => Ability::Wisdom,
+            Skill::Deception | Skill::Intimidation | Skill::Performance | Skill::Persuasion => {
+                Ability::Charisma
+            }
+        }
+    }
+
+    /// The stable id of the skill.
+    pub fn id(&self) -> &'static str {
+        match self {
+            Skill::Acrobatics => "acrobatics",
+            Skill::AnimalHandling => "animal-handling",
+            Skill::Arcana => "arcana",
+            Skill::Athletics => "athletics",
+            Skill::Deception => "deception",
+            Skill::History => "history",
+            Skill::Insight => "insight",
+            Skill::Intimidation => "intimidation",
+            Skill::Investigation => "investigation",
+            Skill::Medicine => "medicine",
+            Skill::Nature => "nature",
+            Skill::Perception => "perception",
+            Skill::Performance => "performance",
+            Skill::Persuasion => "persuasion",
+            Skill::Religion => "religion",
+            Skill::SleightOfHand => "sleight-of-hand",
+            Skill::Stealth => "stealth",
+            Skill::Survival => "survival",
+        }
+    }
+}
+
+impl Display for Skill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Skill::Acrobatics => f.write_str("Acrobatics"),
+            Skill::AnimalHandling => f.write_str("Animal Handling"),
+            Skill::Arcana => f.write_str("Arcana"),
+            Skill::Athletics => f.write_str("Athletics"),
+            Skill::Deception => f.write_str("Deception"),
+            Skill::History => f.write_str("History"),
+            Skill::Insight => f.write_str("Insight"),
+            Skill::Intimidation => f.write_str("Intimidation"),
+            Skill::Investigation => f.write_str("Investigation"),
+            Skill::Medicine => f.write_str("Medicine"),
+            Skill::Nature => f.write_str("Nature"),
+            Skill::Perception => f.write_str("Perception"),
+            Skill::Performance => f.write_str("Performance"),
+            Skill::Persuasion => f.write_str("Persuasion"),
+            Skill::Religion => f.write_str("Religion"),
+            Skill::SleightOfHand => f.write_str("Sleight of Hand"),
+            Skill::Stealth => f.write_str("Stealth"),
+            Skill::Survival => f.write_str("Survival"),
+        }
+    }
+}
+
+/// Weapon proficiencies.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WeaponProficiency {
+    /// All simple weapons.
+    Simple,
+
+    /// All martial weapons.
+    Martial,
+
+    /// A specific weapon, identified by its id (e.g. `longsword`).
+    Specific(String),
+}
+
+impl WeaponProficiency {
+    /// The stable id of the proficiency.
+    pub fn id(&self) -> &str {
+        match self {
+            WeaponProficiency::Simple => "simple",
+            WeaponProficiency::Martial => "martial",
+            WeaponProficiency::Specific(id) => id,
+        }
+    }
+}
+
+impl Display for WeaponProficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WeaponProficiency::Simple => f.write_str("Simple weapons"),
+            WeaponProficiency::Martial => f.write_str("Martial weapons"),
+            WeaponProficiency::Specific(id) => {
+                let name = id.replace('-', " ");
+                let mut chars = name.chars();
+                match chars.next() {
+                    Some(first) => {
+                        f.write_fmt(format_args!("{}{}", first.to_uppercase(), chars.as_str()))
+                    }
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+/// Armor proficiencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ArmorProficiency {
+    Light,
+    Medium,
+    Heavy,
+    Shields,
+}
+
+impl ArmorProficiency {
+    /// The stable id of the proficiency.
+    pub fn id(&self) -> &'static str {
+        match self {
+            ArmorProficiency::Light => "light",
+            ArmorProficiency::Medium => "medium",
+            ArmorProficiency::Heavy => "heavy",
+            ArmorProficiency::Shields => "shields",
+        }
+    }
+}
+
+impl Display for ArmorProficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArmorProficiency::Light => f.write_str("Light armor"),
+            ArmorProficiency::Medium => f.write_str("Medium armor"),
+            ArmorProficiency::Heavy => f.write_str("Heavy armor"),
+            ArmorProficiency::Shields => f.write_str("Shields"),
+        }
+    }
+}
+
+/// The categories tools are grouped into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ToolCategory {
+    /// Artisan's tools, such as smith's tools.
+    Artisan,
+
+    /// Gaming sets, such as a dice set.
+    Gaming,
+
+    /// Musical instruments, such as a lute.
+    Instrument,
+
+    /// Tools that don't belong to a category, such as thieves' tools.
+    Other,
+}
+
+impl Display for ToolCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToolCategory::Artisan => f.write_str("Artisan's tools"),
+            ToolCategory::Gaming => f.write_str("Gaming sets"),
+            ToolCategory::Instrument => f.write_str("Musical instruments"),
+            ToolCategory::Other => f.write_str("Other tools"),
+        }
+    }
+}
+
+/// A tool a character can be proficient with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToolInfo {
+    /// The stable id of the tool.
+    pub id: &'static str,
+
+    /// The name of the tool.
+    pub name: &'static str,
+
+    pub category: ToolCategory,
+}
+
+const fn tool_info(id: &'static str, name: &'static str, category: ToolCategory) -> ToolInfo {
+    ToolInfo { id, name, category }
+}
+
+/// Every tool a character can be proficient with.
+pub const TOOLS: [ToolInfo; 35] = [
+    tool_info(
+        "alchemists-supplies",
+        "Alchemist's supplies",
+        ToolCategory::Artisan,
+    ),
+    tool_info(
+        "brewers-supplies",
+        "Brewer's supplies",
+        ToolCategory::Artisan,
+    ),
+    tool_info(
+        "calligraphers-supplies",
+        "Calligrapher's supplies",
+        ToolCategory::Artisan,
+    ),
+    tool_info(
+        "carpenters-tools",
+        "Carpenter's tools",
+        ToolCategory::Artisan,
+    ),
+    tool_info(
+        "cartographers-tools",
+        "Cartographer's tools",
+        ToolCategory::Artisan,
+    ),
+    tool_info("cobblers-tools", "Cobbler's tools", ToolCategory::Artisan),
+    tool_info("cooks-utensils", "Cook's utensils", ToolCategory::Artisan),
+    tool_info(
+        "glassblowers-tools",
+        "Glassblower's tools",
+        ToolCategory::Artisan,
+    ),
+    tool_info("jewelers-tools", "Jeweler's tools", ToolCategory::Artisan),
+    tool_info(
+        "leatherworkers-tools",
+        "Leatherworker's tools",
+        ToolCategory::Artisan,
+    ),
+    tool_info("masons-tools", "Mason's tools", ToolCategory::Artisan),
+    tool_info(
+        "painters-supplies",
+        "Painter's supplies",
+        ToolCategory::Artisan,
+    ),
+    tool_info("potters-tools", "Potter's tools", ToolCategory::Artisan),
+    tool_info("smiths-tools", "Smith's tools", ToolCategory::Artisan),
+    tool_info("tinkers-tools", "Tinker's tools", ToolCategory::Artisan),
+    tool_info("weavers-tools", "Weaver's tools", ToolCategory::Artisan),
+    tool_info(
+        "woodcarvers-tools",
+        "Woodcarver's tools",
+        ToolCategory::Artisan,
+    ),
+    tool_info("dice-set", "Dice set", ToolCategory::Gaming),
+    tool_info("dragonchess-set", "Dragonchess set", ToolCategory::Gaming),
+    tool_info("playing-card-set", "Playing card set", ToolCategory::Gaming),
+    tool_info("bagpipes", "Bagpipes", ToolCategory::Instrument),
+    tool_info("drum", "Drum", ToolCategory::Instrument),
+    tool_info("dulcimer", "Dulcimer", ToolCategory::Instrument),
+    tool_info("flute", "Flute", ToolCategory::Instrument),
+    tool_info("lute", "Lute", ToolCategory::Instrument),
+    tool_info("lyre", "Lyre", ToolCategory::Instrument),
+    tool_info("horn", "Horn", ToolCategory::Instrument),
+    tool_info("pan-flute", "Pan flute", ToolCategory::Instrument),
+    tool_info("shawm", "Shawm", ToolCategory::Instrument),
+    tool_info("viol", "Viol", ToolCategory::Instrument),
+    tool_info("disguise-kit", "Disguise kit", ToolCategory::Other),
+    tool_info("forgery-kit", "Forgery kit", ToolCategory::Other),
+    tool_info("herbalism-kit", "Herbalism kit", ToolCategory::Other),
+    tool_info("navigators-tools", "Navigator's tools", ToolCategory::Other),
+    tool_info("thieves-tools", "Thieves' tools", ToolCategory::Other),
+];
+
+/// Returns the tool with the given id.
+pub fn tool(id: &str) -> Option<&'static ToolInfo> {
+    TOOLS.iter().find(|tool| tool.id == id)
+}
+
+/// The standard and exotic languages a character can learn.
+pub const LANGUAGES: [&str; 16] = [
+    "Common",
+    "Dwarvish",
+    "Elvish",
+    "Giant",
+    "Gnomish",
+    "Goblin",
+    "Halfling",
+    "Orc",
+    "Abyssal",
+    "Celestial",
+    "Draconic",
+    "Deep Speech",
+    "Infernal",
+    "Primordial",
+    "Sylvan",
+    "Undercommon",
+];
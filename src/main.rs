@@ -1,6 +1,6 @@
 use dregg::app::App;
+use iced::Task;
 
 fn main() -> iced::Result {
-    // TODO: Use `iced::application` instead?
-    iced::run(App::title, App::update, App::view)
+    iced::application(App::title, App::update, App::view).run_with(|| (App::new(), Task::none()))
 }